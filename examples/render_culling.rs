@@ -0,0 +1,98 @@
+//! A crowded page for comparing frame rates with and without render culling.
+//!
+//! 用于对比启用与禁用渲染剔除时帧率的拥挤页面。
+//!
+//! Run `cargo run --release --example render_culling` and then the same command with
+//! `-- --cull`, the top left corner shows the fps and how many resources were culled.
+//!
+//! 先运行`cargo run --release --example render_culling`，再附加`-- --cull`运行同一命令，
+//! 左上角会显示帧率以及被剔除的资源数量。
+use rust_constructor::{
+    BasicFrontResourceConfig, PositionSizeConfig,
+    app::App,
+    background::PageData,
+    basic_front::{CustomRect, Text},
+    build_id,
+};
+
+/// Number of rectangles placed below the visible area.
+///
+/// 放置在可见区域下方的矩形数量。
+const OFFSCREEN_RESOURCES: usize = 5000;
+
+struct RcApp {
+    inner: App,
+}
+
+fn main() {
+    let render_culling = std::env::args().any(|x| x == "--cull");
+    eframe::run_native(
+        "Render Culling",
+        eframe::NativeOptions::default(),
+        Box::new(move |_| {
+            Ok(Box::new(RcApp {
+                inner: App::default()
+                    .current_page("Bench")
+                    .render_culling(render_culling),
+            }))
+        }),
+    )
+    .unwrap();
+}
+
+impl eframe::App for RcApp {
+    fn ui(&mut self, ui: &mut eframe::egui::Ui, _frame: &mut eframe::Frame) {
+        if self
+            .inner
+            .check_resource_exists(&build_id("Bench", "PageData"))
+            .is_none()
+        {
+            self.inner
+                .add_resource("Bench", PageData::default().forced_update(true))
+                .unwrap();
+        };
+        // 所有矩形都位于窗口下方，启用剔除后均不会被绘制
+        for index in 0..OFFSCREEN_RESOURCES {
+            self.inner
+                .quick_place(
+                    &format!("Rect{index}"),
+                    CustomRect::default()
+                        .basic_front_resource_config(
+                            &BasicFrontResourceConfig::default().position_size_config(
+                                PositionSizeConfig::default()
+                                    .origin_position(
+                                        (index % 50) as f32 * 20_f32,
+                                        5000_f32 + (index / 50) as f32 * 20_f32,
+                                    )
+                                    .origin_size(16_f32, 16_f32),
+                            ),
+                        )
+                        .color(90, 160, 255),
+                    None,
+                    ui,
+                )
+                .unwrap();
+        }
+        let stats = format!(
+            "render_culling: {}\nfps: {:.1}\nculled: {}",
+            self.inner.render_culling,
+            self.inner.current_fps(),
+            self.inner.culled_resources
+        );
+        self.inner
+            .quick_place(
+                "Stats",
+                Text::default().content(&stats).font_size(20_f32),
+                None,
+                ui,
+            )
+            .unwrap();
+        self.inner
+            .get_resource_mut::<Text>(&build_id("Stats", "Text"))
+            .unwrap()
+            .content = stats;
+        self.inner
+            .use_resource(&build_id("Bench", "PageData"), None, ui)
+            .unwrap();
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! 此文件包含高级前端资源，高级前端资源可以用于处理复杂的任务。
 use crate::{
-    BasicFrontResource, Config, FrontResource, RustConstructorId, RustConstructorResource,
+    BasicFrontResource, Config, ConnectorStyle, FrontResource, LineEndpoint, RustConstructorId,
+    RustConstructorResource,
     basic_front::{CustomRectConfig, ImageConfig, TextConfig},
 };
 #[cfg(feature = "rc_bevy")]
@@ -15,6 +16,7 @@ use std::any::Any;
 ///
 /// 控制Background选择的基础前端资源类型。
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BackgroundType {
     /// Use an image as the background.
     ///
@@ -387,6 +389,22 @@ pub enum CustomPanelLayout {
     Id(RustConstructorId, PanelLayout),
 }
 
+/// Resources stacked top to bottom inside a panel.
+///
+/// 在面板内从上到下堆叠的资源。
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+pub struct VerticalList {
+    /// Resources in the list, from top to bottom.
+    ///
+    /// 列表中的资源，从上到下排列。
+    pub items: Vec<RustConstructorId>,
+
+    /// Vertical gap between two items.
+    ///
+    /// 两个项目之间的垂直间距。
+    pub spacing: f32,
+}
+
 /// Storage structure for panel resource metadata.
 ///
 /// 面板资源元数据的存储结构。
@@ -538,6 +556,11 @@ pub struct ResourcePanelConfig {
     /// 是否在点击时将面板前置。
     pub raise_on_focus: Option<bool>,
 
+    /// Resources laid out as a vertical list, they override other layouts.
+    ///
+    /// 以垂直列表排列的资源，会覆盖其他布局。
+    pub vertical_list: Option<Option<VerticalList>>,
+
     /// Key-value pairs for categorization and metadata.
     ///
     /// 用于分类和元数据的键值对标签。
@@ -585,6 +608,7 @@ impl ResourcePanelConfig {
             reverse_scroll_direction: Some(resource.reverse_scroll_direction),
             inner_margin: Some(resource.inner_margin),
             raise_on_focus: Some(resource.raise_on_focus),
+            vertical_list: Some(resource.vertical_list.clone()),
             tags: Some(resource.tags.clone()),
         }
     }
@@ -691,6 +715,12 @@ impl ResourcePanelConfig {
         self
     }
 
+    #[inline]
+    pub fn vertical_list(mut self, vertical_list: Option<Option<VerticalList>>) -> Self {
+        self.vertical_list = vertical_list;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
         self.tags = tags;
@@ -826,6 +856,11 @@ pub struct ResourcePanel {
     /// 用于确保不同对齐方式的资源都能正确显示。
     pub overall_offset: [f32; 2],
 
+    /// Resources laid out as a vertical list, they override other layouts.
+    ///
+    /// 以垂直列表排列的资源，会覆盖其他布局。
+    pub vertical_list: Option<VerticalList>,
+
     /// Key-value pairs for categorization and metadata.
     ///
     /// 用于分类和元数据的键值对标签。
@@ -958,6 +993,7 @@ impl Default for ResourcePanel {
             scroll_bar_alpha: [0, 0],
             resource_storage: Vec::new(),
             overall_offset: [0_f32, 0_f32],
+            vertical_list: None,
             tags: Vec::new(),
         }
     }
@@ -1013,6 +1049,9 @@ impl ResourcePanel {
         if let Some(raise_on_focus) = config.raise_on_focus {
             self.raise_on_focus = raise_on_focus;
         };
+        if let Some(ref vertical_list) = config.vertical_list {
+            self.vertical_list = vertical_list.clone();
+        };
         if let Some(ref tags) = config.tags {
             self.tags = tags.clone();
         };
@@ -1122,6 +1161,12 @@ impl ResourcePanel {
         self
     }
 
+    #[inline]
+    pub fn vertical_list(mut self, vertical_list: Option<VerticalList>) -> Self {
+        self.vertical_list = vertical_list;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
         if replace {
@@ -1142,6 +1187,11 @@ impl ResourcePanel {
 ///
 /// 开关资源的外观配置。
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct SwitchAppearanceConfig {
     /// Config for the background element.
     ///
@@ -1163,6 +1213,7 @@ pub struct SwitchAppearanceConfig {
 ///
 /// 开关资源的点击配置。
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwitchClickConfig {
     /// Mouse button used to trigger the switch.
     ///
@@ -1250,6 +1301,21 @@ pub struct SwitchConfig {
     /// 开关是否启用（disabled会显示，但无法交互）。
     pub enable: Option<bool>,
 
+    /// Whether the switch is greyed out, it ignores hover and clicks and hides its hint text.
+    ///
+    /// 开关是否显示为禁用状态，禁用时忽略悬停和点击并隐藏提示文本。
+    pub disabled: Option<bool>,
+
+    /// Appearance used while disabled, None dims the current appearance by `disabled_alpha`.
+    ///
+    /// 禁用时使用的外观，为None时按`disabled_alpha`调暗当前外观。
+    pub disabled_appearance: Option<Option<SwitchAppearanceConfig>>,
+
+    /// Opacity scale (0-255) applied to the current appearance while disabled without a dedicated appearance.
+    ///
+    /// 没有专用外观时，禁用状态下应用于当前外观的不透明度比例（0-255）。
+    pub disabled_alpha: Option<u8>,
+
     /// Key-value pairs for categorization and metadata.
     ///
     /// 用于分类和元数据的键值对标签。
@@ -1290,6 +1356,9 @@ impl SwitchConfig {
             click_method: Some(resource.click_method.clone()),
             radio_group: Some(resource.radio_group.clone()),
             enable: Some(resource.enable),
+            disabled: Some(resource.disabled),
+            disabled_appearance: Some(resource.disabled_appearance.clone()),
+            disabled_alpha: Some(resource.disabled_alpha),
             tags: Some(resource.tags.clone()),
         }
     }
@@ -1348,6 +1417,27 @@ impl SwitchConfig {
         self
     }
 
+    #[inline]
+    pub fn disabled(mut self, disabled: Option<bool>) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    #[inline]
+    pub fn disabled_appearance(
+        mut self,
+        disabled_appearance: Option<Option<SwitchAppearanceConfig>>,
+    ) -> Self {
+        self.disabled_appearance = disabled_appearance;
+        self
+    }
+
+    #[inline]
+    pub fn disabled_alpha(mut self, disabled_alpha: Option<u8>) -> Self {
+        self.disabled_alpha = disabled_alpha;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
         self.tags = tags;
@@ -1359,6 +1449,11 @@ impl SwitchConfig {
 ///
 /// 用于可切换UI元素的开关资源。
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Switch {
     /// Appearance configs for each state combination.
     ///
@@ -1417,18 +1512,36 @@ pub struct Switch {
     /// Whether the mouse was hovering in the previous frame.
     ///
     /// 鼠标是否在前一帧中悬停。
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub last_frame_hovered: bool,
 
     /// Click method from the previous frame, if any.
     ///
     /// 前一帧中的单击方法（如果有的话）。
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub last_frame_clicked: Option<usize>,
 
     /// Whether the switch was toggled.
     ///
     /// 开关是否被切换。
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub switched: bool,
 
+    /// Whether the switch is greyed out, it ignores hover and clicks and hides its hint text.
+    ///
+    /// 开关是否显示为禁用状态，禁用时忽略悬停和点击并隐藏提示文本。
+    pub disabled: bool,
+
+    /// Appearance used while disabled, None dims the current appearance by `disabled_alpha`.
+    ///
+    /// 禁用时使用的外观，为None时按`disabled_alpha`调暗当前外观。
+    pub disabled_appearance: Option<SwitchAppearanceConfig>,
+
+    /// Opacity scale (0-255) applied to the current appearance while disabled without a dedicated appearance.
+    ///
+    /// 没有专用外观时，禁用状态下应用于当前外观的不透明度比例（0-255）。
+    pub disabled_alpha: u8,
+
     /// Key-value pairs for categorization and metadata.
     ///
     /// 用于分类和元数据的键值对标签。
@@ -1540,6 +1653,9 @@ impl Default for Switch {
             last_frame_hovered: false,
             last_frame_clicked: None,
             switched: false,
+            disabled: false,
+            disabled_appearance: None,
+            disabled_alpha: 100,
             tags: Vec::new(),
         }
     }
@@ -1574,6 +1690,15 @@ impl Switch {
         if let Some(enable) = config.enable {
             self.enable = enable;
         };
+        if let Some(disabled) = config.disabled {
+            self.disabled = disabled;
+        };
+        if let Some(ref disabled_appearance) = config.disabled_appearance {
+            self.disabled_appearance = disabled_appearance.clone();
+        };
+        if let Some(disabled_alpha) = config.disabled_alpha {
+            self.disabled_alpha = disabled_alpha;
+        };
         if let Some(ref tags) = config.tags {
             self.tags = tags.clone();
         };
@@ -1634,6 +1759,27 @@ impl Switch {
         self
     }
 
+    #[inline]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    #[inline]
+    pub fn disabled_appearance(
+        mut self,
+        disabled_appearance: Option<SwitchAppearanceConfig>,
+    ) -> Self {
+        self.disabled_appearance = disabled_appearance;
+        self
+    }
+
+    #[inline]
+    pub fn disabled_alpha(mut self, disabled_alpha: u8) -> Self {
+        self.disabled_alpha = disabled_alpha;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
         if replace {
@@ -1649,3 +1795,2145 @@ impl Switch {
         self
     }
 }
+
+/// Data structure for reading the state of a text input.
+///
+/// 用于读取文本输入框状态的数据结构。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TextInputData {
+    /// Current content of the input.
+    ///
+    /// 输入框的当前内容。
+    pub content: String,
+
+    /// Whether the content was changed during the last frame.
+    ///
+    /// 内容是否在上一帧中被修改。
+    pub changed: bool,
+
+    /// Whether the input currently has keyboard focus.
+    ///
+    /// 输入框当前是否拥有键盘焦点。
+    pub focused: bool,
+}
+
+/// Config options for text input resources.
+///
+/// 文本输入框资源的配置选项。
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextInputConfig {
+    /// Config for the text that displays the content.
+    ///
+    /// 显示内容的文本的配置。
+    pub text_config: Option<TextConfig>,
+
+    /// Text shown while the input is empty and not focused.
+    ///
+    /// 输入框为空且未获得焦点时显示的文本。
+    pub placeholder: Option<String>,
+
+    /// If true, every character is displayed as a mask glyph.
+    ///
+    /// 如果为true，每个字符都会显示为掩码字符。
+    pub password: Option<bool>,
+
+    /// Maximum number of characters, None means unlimited.
+    ///
+    /// 最大字符数，为None时不限制。
+    pub max_length: Option<Option<usize>>,
+
+    /// Whether the input accepts focus and typing.
+    ///
+    /// 输入框是否接受焦点和输入。
+    pub enable: Option<bool>,
+
+    /// Length of one caret blink cycle in seconds.
+    ///
+    /// 光标一次闪烁周期的秒数。
+    pub caret_blink_period: Option<f32>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Option<Vec<[String; 2]>>,
+}
+
+impl Config for TextInputConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn convert_to_resource(&self) -> Box<dyn FrontResource> {
+        Box::new(TextInput::default().from_config(self))
+    }
+
+    fn convert_from_resource(&self, resource: Box<dyn FrontResource>) -> Option<Box<dyn Config>> {
+        if let Some(resource) = resource.as_any().downcast_ref::<TextInput>() {
+            Some(Box::new(TextInputConfig::from_resource(resource)))
+        } else {
+            None
+        }
+    }
+}
+
+impl TextInputConfig {
+    pub fn from_resource(resource: &TextInput) -> Self {
+        Self {
+            text_config: Some(resource.text_config.clone()),
+            placeholder: Some(resource.placeholder.clone()),
+            password: Some(resource.password),
+            max_length: Some(resource.max_length),
+            enable: Some(resource.enable),
+            caret_blink_period: Some(resource.caret_blink_period),
+            tags: Some(resource.tags.clone()),
+        }
+    }
+
+    #[inline]
+    pub fn text_config(mut self, text_config: Option<TextConfig>) -> Self {
+        self.text_config = text_config;
+        self
+    }
+
+    #[inline]
+    pub fn placeholder(mut self, placeholder: Option<String>) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    #[inline]
+    pub fn password(mut self, password: Option<bool>) -> Self {
+        self.password = password;
+        self
+    }
+
+    #[inline]
+    pub fn max_length(mut self, max_length: Option<Option<usize>>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    #[inline]
+    pub fn enable(mut self, enable: Option<bool>) -> Self {
+        self.enable = enable;
+        self
+    }
+
+    #[inline]
+    pub fn caret_blink_period(mut self, caret_blink_period: Option<f32>) -> Self {
+        self.caret_blink_period = caret_blink_period;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+/// Editable single text field built on a text resource.
+///
+/// 基于文本资源构建的可编辑文本框。
+///
+/// The content is laid out by the `{name}Text` resource, so wrapping, fonts and positioning
+/// follow the text config. Clicking the text focuses the input, clicking elsewhere or
+/// pressing Escape releases it.
+///
+/// 内容由`{name}Text`资源排版，因此换行、字体和定位都遵循文本配置。点击文本会使输入框获得焦点，
+/// 点击其他位置或按下Escape会释放焦点。
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextInput {
+    /// Config for the text that displays the content.
+    ///
+    /// 显示内容的文本的配置。
+    pub text_config: TextConfig,
+
+    /// Text shown while the input is empty and not focused.
+    ///
+    /// 输入框为空且未获得焦点时显示的文本。
+    pub placeholder: String,
+
+    /// If true, every character is displayed as a mask glyph.
+    ///
+    /// 如果为true，每个字符都会显示为掩码字符。
+    pub password: bool,
+
+    /// Maximum number of characters, None means unlimited.
+    ///
+    /// 最大字符数，为None时不限制。
+    pub max_length: Option<usize>,
+
+    /// Whether the input accepts focus and typing.
+    ///
+    /// 输入框是否接受焦点和输入。
+    pub enable: bool,
+
+    /// Current content of the input.
+    ///
+    /// 输入框的当前内容。
+    pub content: String,
+
+    /// Caret position as a character index.
+    ///
+    /// 以字符索引表示的光标位置。
+    pub caret: usize,
+
+    /// Whether the input currently has keyboard focus.
+    ///
+    /// 输入框当前是否拥有键盘焦点。
+    pub focused: bool,
+
+    /// Whether the content was changed during the last frame.
+    ///
+    /// 内容是否在上一帧中被修改。
+    pub changed: bool,
+
+    /// Length of one caret blink cycle in seconds, 0 keeps the caret visible. The cycle starts
+    /// over with the caret visible after every edit or caret move.
+    ///
+    /// 光标一次闪烁周期的秒数，为0时光标始终可见。每次编辑或移动光标后周期会从光标可见时重新开始。
+    pub caret_blink_period: f32,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for TextInput {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl FrontResource for TextInput {
+    fn convert_to_config(&self) -> Box<dyn Config> {
+        Box::new(TextInputConfig::from_resource(self))
+    }
+
+    fn convert_from_config(&mut self, config: Box<dyn Config>) -> Option<Box<dyn FrontResource>> {
+        if let Some(config) = config.as_any().downcast_ref::<TextInputConfig>() {
+            Some(Box::new(self.clone().from_config(config)))
+        } else {
+            None
+        }
+    }
+
+    fn convert_to_original(&self) -> Box<dyn RustConstructorResource> {
+        Box::new(self.clone())
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_original_dyn(&self) -> &dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_original_dyn_mut(&mut self) -> &mut dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            text_config: TextConfig::default(),
+            placeholder: String::new(),
+            password: false,
+            max_length: None,
+            enable: true,
+            content: String::new(),
+            caret: 0,
+            focused: false,
+            changed: false,
+            caret_blink_period: 1_f32,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl TextInput {
+    pub fn from_config(mut self, config: &TextInputConfig) -> Self {
+        if let Some(ref text_config) = config.text_config {
+            self.text_config = text_config.clone();
+        };
+        if let Some(ref placeholder) = config.placeholder {
+            self.placeholder = placeholder.clone();
+        };
+        if let Some(password) = config.password {
+            self.password = password;
+        };
+        if let Some(max_length) = config.max_length {
+            self.max_length = max_length;
+        };
+        if let Some(enable) = config.enable {
+            self.enable = enable;
+        };
+        if let Some(caret_blink_period) = config.caret_blink_period {
+            self.caret_blink_period = caret_blink_period;
+        };
+        if let Some(ref tags) = config.tags {
+            self.tags = tags.clone();
+        };
+        self
+    }
+
+    #[inline]
+    pub fn text_config(mut self, text_config: &TextConfig) -> Self {
+        self.text_config = text_config.clone();
+        self
+    }
+
+    #[inline]
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
+
+    #[inline]
+    pub fn max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    #[inline]
+    pub fn enable(mut self, enable: bool) -> Self {
+        self.enable = enable;
+        self
+    }
+
+    #[inline]
+    pub fn caret_blink_period(mut self, caret_blink_period: f32) -> Self {
+        self.caret_blink_period = caret_blink_period;
+        self
+    }
+
+    #[inline]
+    pub fn content(mut self, content: &str) -> Self {
+        self.content = content.to_string();
+        self.caret = content.chars().count();
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Direction a bar-like resource runs in.
+///
+/// 条状资源的延伸方向。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Orientation {
+    /// Runs from left to right.
+    ///
+    /// 从左向右延伸。
+    #[default]
+    Horizontal,
+    /// Runs from bottom to top.
+    ///
+    /// 从下向上延伸。
+    Vertical,
+}
+
+/// Config options for progress bar resources.
+///
+/// 进度条资源的配置选项。
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProgressBarConfig {
+    /// Config for the track, the fill shares its position, rounding and clip rect.
+    ///
+    /// 轨道的配置，填充部分共用其位置、圆角和裁剪矩形。
+    pub track_config: Option<CustomRectConfig>,
+
+    /// Color of the fill as [R, G, B].
+    ///
+    /// 填充部分的颜色，为[R, G, B]。
+    pub fill_color: Option<[u8; 3]>,
+
+    /// Opacity of the fill.
+    ///
+    /// 填充部分的不透明度。
+    pub fill_alpha: Option<u8>,
+
+    /// Progress from 0 to 1.
+    ///
+    /// 进度，范围为0到1。
+    pub value: Option<f32>,
+
+    /// Direction the bar fills in.
+    ///
+    /// 进度条的填充方向。
+    pub orientation: Option<Orientation>,
+
+    /// If true, a highlight sweeps along the track instead of showing the value.
+    ///
+    /// 如果为true，高亮会沿轨道循环移动，而不是显示进度值。
+    pub indeterminate: Option<bool>,
+
+    /// Config for the percentage text centered on the track, None shows no text.
+    ///
+    /// 居中显示在轨道上的百分比文本的配置，为None时不显示文本。
+    pub text_config: Option<Option<TextConfig>>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Option<Vec<[String; 2]>>,
+}
+
+impl Config for ProgressBarConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn convert_to_resource(&self) -> Box<dyn FrontResource> {
+        Box::new(ProgressBar::default().from_config(self))
+    }
+
+    fn convert_from_resource(&self, resource: Box<dyn FrontResource>) -> Option<Box<dyn Config>> {
+        if let Some(resource) = resource.as_any().downcast_ref::<ProgressBar>() {
+            Some(Box::new(ProgressBarConfig::from_resource(resource)))
+        } else {
+            None
+        }
+    }
+}
+
+impl ProgressBarConfig {
+    pub fn from_resource(resource: &ProgressBar) -> Self {
+        Self {
+            track_config: Some(resource.track_config.clone()),
+            fill_color: Some(resource.fill_color),
+            fill_alpha: Some(resource.fill_alpha),
+            value: Some(resource.value),
+            orientation: Some(resource.orientation),
+            indeterminate: Some(resource.indeterminate),
+            text_config: Some(resource.text_config.clone()),
+            tags: Some(resource.tags.clone()),
+        }
+    }
+
+    #[inline]
+    pub fn track_config(mut self, track_config: Option<CustomRectConfig>) -> Self {
+        self.track_config = track_config;
+        self
+    }
+
+    #[inline]
+    pub fn fill_color(mut self, fill_color: Option<[u8; 3]>) -> Self {
+        self.fill_color = fill_color;
+        self
+    }
+
+    #[inline]
+    pub fn fill_alpha(mut self, fill_alpha: Option<u8>) -> Self {
+        self.fill_alpha = fill_alpha;
+        self
+    }
+
+    #[inline]
+    pub fn value(mut self, value: Option<f32>) -> Self {
+        self.value = value;
+        self
+    }
+
+    #[inline]
+    pub fn orientation(mut self, orientation: Option<Orientation>) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    #[inline]
+    pub fn indeterminate(mut self, indeterminate: Option<bool>) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    #[inline]
+    pub fn text_config(mut self, text_config: Option<Option<TextConfig>>) -> Self {
+        self.text_config = text_config;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+/// Progress bar made of a track and a fill, with an optional percentage text.
+///
+/// 由轨道和填充部分组成的进度条，可选显示百分比文本。
+///
+/// The track is the `{name}Track` rectangle, the fill is the `{name}Fill` rectangle drawn over
+/// it and the percentage is the `{name}Text` text.
+///
+/// 轨道为`{name}Track`矩形，填充部分为绘制在其上方的`{name}Fill`矩形，百分比为`{name}Text`文本。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressBar {
+    /// Config for the track, the fill shares its position, rounding and clip rect.
+    ///
+    /// 轨道的配置，填充部分共用其位置、圆角和裁剪矩形。
+    pub track_config: CustomRectConfig,
+
+    /// Color of the fill as [R, G, B].
+    ///
+    /// 填充部分的颜色，为[R, G, B]。
+    pub fill_color: [u8; 3],
+
+    /// Opacity of the fill.
+    ///
+    /// 填充部分的不透明度。
+    pub fill_alpha: u8,
+
+    /// Progress from 0 to 1.
+    ///
+    /// 进度，范围为0到1。
+    pub value: f32,
+
+    /// Direction the bar fills in.
+    ///
+    /// 进度条的填充方向。
+    pub orientation: Orientation,
+
+    /// If true, a highlight sweeps along the track instead of showing the value.
+    ///
+    /// 如果为true，高亮会沿轨道循环移动，而不是显示进度值。
+    pub indeterminate: bool,
+
+    /// Config for the percentage text centered on the track, None shows no text.
+    ///
+    /// 居中显示在轨道上的百分比文本的配置，为None时不显示文本。
+    pub text_config: Option<TextConfig>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for ProgressBar {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl FrontResource for ProgressBar {
+    fn convert_to_config(&self) -> Box<dyn Config> {
+        Box::new(ProgressBarConfig::from_resource(self))
+    }
+
+    fn convert_from_config(&mut self, config: Box<dyn Config>) -> Option<Box<dyn FrontResource>> {
+        if let Some(config) = config.as_any().downcast_ref::<ProgressBarConfig>() {
+            Some(Box::new(self.clone().from_config(config)))
+        } else {
+            None
+        }
+    }
+
+    fn convert_to_original(&self) -> Box<dyn RustConstructorResource> {
+        Box::new(self.clone())
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_original_dyn(&self) -> &dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_original_dyn_mut(&mut self) -> &mut dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self {
+            track_config: CustomRectConfig::default()
+                .color(Some([60, 60, 60]))
+                .rounding(Some(4_f32)),
+            fill_color: [80, 160, 255],
+            fill_alpha: 255,
+            value: 0_f32,
+            orientation: Orientation::default(),
+            indeterminate: false,
+            text_config: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl ProgressBar {
+    pub fn from_config(mut self, config: &ProgressBarConfig) -> Self {
+        if let Some(ref track_config) = config.track_config {
+            self.track_config = track_config.clone();
+        };
+        if let Some(fill_color) = config.fill_color {
+            self.fill_color = fill_color;
+        };
+        if let Some(fill_alpha) = config.fill_alpha {
+            self.fill_alpha = fill_alpha;
+        };
+        if let Some(value) = config.value {
+            self.value = value.clamp(0_f32, 1_f32);
+        };
+        if let Some(orientation) = config.orientation {
+            self.orientation = orientation;
+        };
+        if let Some(indeterminate) = config.indeterminate {
+            self.indeterminate = indeterminate;
+        };
+        if let Some(ref text_config) = config.text_config {
+            self.text_config = text_config.clone();
+        };
+        if let Some(ref tags) = config.tags {
+            self.tags = tags.clone();
+        };
+        self
+    }
+
+    #[inline]
+    pub fn track_config(mut self, track_config: &CustomRectConfig) -> Self {
+        self.track_config = track_config.clone();
+        self
+    }
+
+    #[inline]
+    pub fn fill_color(mut self, r: u8, g: u8, b: u8) -> Self {
+        self.fill_color = [r, g, b];
+        self
+    }
+
+    #[inline]
+    pub fn fill_alpha(mut self, fill_alpha: u8) -> Self {
+        self.fill_alpha = fill_alpha;
+        self
+    }
+
+    #[inline]
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value.clamp(0_f32, 1_f32);
+        self
+    }
+
+    #[inline]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    #[inline]
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    #[inline]
+    pub fn text_config(mut self, text_config: Option<TextConfig>) -> Self {
+        self.text_config = text_config;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Data structure for reading the state of a slider.
+///
+/// 用于读取滑块状态的数据结构。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SliderData {
+    /// Current value of the slider.
+    ///
+    /// 滑块的当前值。
+    pub value: f32,
+
+    /// Whether the value was changed by the user during the last frame.
+    ///
+    /// 值是否在上一帧中被用户修改。
+    pub changed: bool,
+
+    /// Whether the handle is being dragged.
+    ///
+    /// 滑块手柄是否正在被拖动。
+    pub dragging: bool,
+}
+
+/// Config options for slider resources.
+///
+/// 滑块资源的配置选项。
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SliderConfig {
+    /// Config for the track, the fill shares its position, rounding and clip rect.
+    ///
+    /// 轨道的配置，填充部分共用其位置、圆角和裁剪矩形。
+    pub track_config: Option<CustomRectConfig>,
+
+    /// Color of the filled part of the track as [R, G, B].
+    ///
+    /// 轨道已填充部分的颜色，为[R, G, B]。
+    pub fill_color: Option<[u8; 3]>,
+
+    /// Opacity of the filled part of the track.
+    ///
+    /// 轨道已填充部分的不透明度。
+    pub fill_alpha: Option<u8>,
+
+    /// Config for the handle, its position is set by the slider.
+    ///
+    /// 手柄的配置，其位置由滑块设置。
+    pub handle_config: Option<CustomRectConfig>,
+
+    /// Size of the handle as [width, height].
+    ///
+    /// 手柄的尺寸，为[宽, 高]。
+    pub handle_size: Option<[f32; 2]>,
+
+    /// Range of the value as [min, max].
+    ///
+    /// 值的范围，为[min, max]。
+    pub range: Option<[f32; 2]>,
+
+    /// Step the value snaps to, None for a continuous value.
+    ///
+    /// 值吸附的步长，为None时值连续变化。
+    pub step: Option<Option<f32>>,
+
+    /// Current value.
+    ///
+    /// 当前值。
+    pub value: Option<f32>,
+
+    /// Direction the slider runs in.
+    ///
+    /// 滑块的延伸方向。
+    pub orientation: Option<Orientation>,
+
+    /// Name of an `f32` variable kept in sync with the value, None for no binding.
+    ///
+    /// 与值保持同步的`f32`变量的名称，为None时不绑定。
+    pub variable: Option<Option<String>>,
+
+    /// Whether the slider accepts input.
+    ///
+    /// 滑块是否接受输入。
+    pub enable: Option<bool>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Option<Vec<[String; 2]>>,
+}
+
+impl Config for SliderConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn convert_to_resource(&self) -> Box<dyn FrontResource> {
+        Box::new(Slider::default().from_config(self))
+    }
+
+    fn convert_from_resource(&self, resource: Box<dyn FrontResource>) -> Option<Box<dyn Config>> {
+        if let Some(resource) = resource.as_any().downcast_ref::<Slider>() {
+            Some(Box::new(SliderConfig::from_resource(resource)))
+        } else {
+            None
+        }
+    }
+}
+
+impl SliderConfig {
+    pub fn from_resource(resource: &Slider) -> Self {
+        Self {
+            track_config: Some(resource.track_config.clone()),
+            fill_color: Some(resource.fill_color),
+            fill_alpha: Some(resource.fill_alpha),
+            handle_config: Some(resource.handle_config.clone()),
+            handle_size: Some(resource.handle_size),
+            range: Some(resource.range),
+            step: Some(resource.step),
+            value: Some(resource.value),
+            orientation: Some(resource.orientation),
+            variable: Some(resource.variable.clone()),
+            enable: Some(resource.enable),
+            tags: Some(resource.tags.clone()),
+        }
+    }
+
+    #[inline]
+    pub fn track_config(mut self, track_config: Option<CustomRectConfig>) -> Self {
+        self.track_config = track_config;
+        self
+    }
+
+    #[inline]
+    pub fn fill_color(mut self, fill_color: Option<[u8; 3]>) -> Self {
+        self.fill_color = fill_color;
+        self
+    }
+
+    #[inline]
+    pub fn fill_alpha(mut self, fill_alpha: Option<u8>) -> Self {
+        self.fill_alpha = fill_alpha;
+        self
+    }
+
+    #[inline]
+    pub fn handle_config(mut self, handle_config: Option<CustomRectConfig>) -> Self {
+        self.handle_config = handle_config;
+        self
+    }
+
+    #[inline]
+    pub fn handle_size(mut self, handle_size: Option<[f32; 2]>) -> Self {
+        self.handle_size = handle_size;
+        self
+    }
+
+    #[inline]
+    pub fn range(mut self, range: Option<[f32; 2]>) -> Self {
+        self.range = range;
+        self
+    }
+
+    #[inline]
+    pub fn step(mut self, step: Option<Option<f32>>) -> Self {
+        self.step = step;
+        self
+    }
+
+    #[inline]
+    pub fn value(mut self, value: Option<f32>) -> Self {
+        self.value = value;
+        self
+    }
+
+    #[inline]
+    pub fn orientation(mut self, orientation: Option<Orientation>) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    #[inline]
+    pub fn variable(mut self, variable: Option<Option<String>>) -> Self {
+        self.variable = variable;
+        self
+    }
+
+    #[inline]
+    pub fn enable(mut self, enable: Option<bool>) -> Self {
+        self.enable = enable;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+/// Slider that picks a value in a range by dragging a handle along a track.
+///
+/// 通过沿轨道拖动手柄在范围内选取值的滑块。
+///
+/// The track is the `{name}Track` rectangle, the filled part is `{name}Fill` and the handle is
+/// `{name}Handle`. Pressing the track jumps the handle to the pointer and starts a drag, the
+/// drag continues while the button is held and stops when it is released.
+///
+/// 轨道为`{name}Track`矩形，已填充部分为`{name}Fill`，手柄为`{name}Handle`。按下轨道会使手柄跳到指针处
+/// 并开始拖动，按住按键时拖动持续，松开时停止。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slider {
+    /// Config for the track, the fill shares its position, rounding and clip rect.
+    ///
+    /// 轨道的配置，填充部分共用其位置、圆角和裁剪矩形。
+    pub track_config: CustomRectConfig,
+
+    /// Color of the filled part of the track as [R, G, B].
+    ///
+    /// 轨道已填充部分的颜色，为[R, G, B]。
+    pub fill_color: [u8; 3],
+
+    /// Opacity of the filled part of the track.
+    ///
+    /// 轨道已填充部分的不透明度。
+    pub fill_alpha: u8,
+
+    /// Config for the handle, its position is set by the slider.
+    ///
+    /// 手柄的配置，其位置由滑块设置。
+    pub handle_config: CustomRectConfig,
+
+    /// Size of the handle as [width, height].
+    ///
+    /// 手柄的尺寸，为[宽, 高]。
+    pub handle_size: [f32; 2],
+
+    /// Range of the value as [min, max].
+    ///
+    /// 值的范围，为[min, max]。
+    pub range: [f32; 2],
+
+    /// Step the value snaps to, None for a continuous value.
+    ///
+    /// 值吸附的步长，为None时值连续变化。
+    pub step: Option<f32>,
+
+    /// Current value.
+    ///
+    /// 当前值。
+    pub value: f32,
+
+    /// Direction the slider runs in.
+    ///
+    /// 滑块的延伸方向。
+    pub orientation: Orientation,
+
+    /// Name of an `f32` variable kept in sync with the value, None for no binding.
+    ///
+    /// 与值保持同步的`f32`变量的名称，为None时不绑定。
+    pub variable: Option<String>,
+
+    /// Whether the slider accepts input.
+    ///
+    /// 滑块是否接受输入。
+    pub enable: bool,
+
+    /// Whether the handle is being dragged.
+    ///
+    /// 手柄是否正在被拖动。
+    pub dragging: bool,
+
+    /// Whether the value was changed by the user during the last frame.
+    ///
+    /// 值是否在上一帧中被用户修改。
+    pub changed: bool,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for Slider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl FrontResource for Slider {
+    fn convert_to_config(&self) -> Box<dyn Config> {
+        Box::new(SliderConfig::from_resource(self))
+    }
+
+    fn convert_from_config(&mut self, config: Box<dyn Config>) -> Option<Box<dyn FrontResource>> {
+        if let Some(config) = config.as_any().downcast_ref::<SliderConfig>() {
+            Some(Box::new(self.clone().from_config(config)))
+        } else {
+            None
+        }
+    }
+
+    fn convert_to_original(&self) -> Box<dyn RustConstructorResource> {
+        Box::new(self.clone())
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_original_dyn(&self) -> &dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_original_dyn_mut(&mut self) -> &mut dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self {
+            track_config: CustomRectConfig::default()
+                .color(Some([60, 60, 60]))
+                .rounding(Some(3_f32)),
+            fill_color: [80, 160, 255],
+            fill_alpha: 255,
+            handle_config: CustomRectConfig::default()
+                .color(Some([240, 240, 240]))
+                .rounding(Some(8_f32)),
+            handle_size: [16_f32, 16_f32],
+            range: [0_f32, 1_f32],
+            step: None,
+            value: 0_f32,
+            orientation: Orientation::default(),
+            variable: None,
+            enable: true,
+            dragging: false,
+            changed: false,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl Slider {
+    pub fn from_config(mut self, config: &SliderConfig) -> Self {
+        if let Some(ref track_config) = config.track_config {
+            self.track_config = track_config.clone();
+        };
+        if let Some(fill_color) = config.fill_color {
+            self.fill_color = fill_color;
+        };
+        if let Some(fill_alpha) = config.fill_alpha {
+            self.fill_alpha = fill_alpha;
+        };
+        if let Some(ref handle_config) = config.handle_config {
+            self.handle_config = handle_config.clone();
+        };
+        if let Some(handle_size) = config.handle_size {
+            self.handle_size = handle_size;
+        };
+        if let Some(range) = config.range {
+            self.range = range;
+        };
+        if let Some(step) = config.step {
+            self.step = step;
+        };
+        if let Some(value) = config.value {
+            self.value = value;
+        };
+        if let Some(orientation) = config.orientation {
+            self.orientation = orientation;
+        };
+        if let Some(ref variable) = config.variable {
+            self.variable = variable.clone();
+        };
+        if let Some(enable) = config.enable {
+            self.enable = enable;
+        };
+        if let Some(ref tags) = config.tags {
+            self.tags = tags.clone();
+        };
+        self.value = self.snap(self.value);
+        self
+    }
+
+    #[inline]
+    pub fn track_config(mut self, track_config: &CustomRectConfig) -> Self {
+        self.track_config = track_config.clone();
+        self
+    }
+
+    #[inline]
+    pub fn fill_color(mut self, r: u8, g: u8, b: u8) -> Self {
+        self.fill_color = [r, g, b];
+        self
+    }
+
+    #[inline]
+    pub fn fill_alpha(mut self, fill_alpha: u8) -> Self {
+        self.fill_alpha = fill_alpha;
+        self
+    }
+
+    #[inline]
+    pub fn handle_config(mut self, handle_config: &CustomRectConfig) -> Self {
+        self.handle_config = handle_config.clone();
+        self
+    }
+
+    #[inline]
+    pub fn handle_size(mut self, width: f32, height: f32) -> Self {
+        self.handle_size = [width, height];
+        self
+    }
+
+    #[inline]
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.range = [min, max];
+        self.value = self.snap(self.value);
+        self
+    }
+
+    #[inline]
+    pub fn step(mut self, step: Option<f32>) -> Self {
+        self.step = step;
+        self.value = self.snap(self.value);
+        self
+    }
+
+    #[inline]
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = self.snap(value);
+        self
+    }
+
+    #[inline]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    #[inline]
+    pub fn variable(mut self, variable: Option<&str>) -> Self {
+        self.variable = variable.map(|x| x.to_string());
+        self
+    }
+
+    #[inline]
+    pub fn enable(mut self, enable: bool) -> Self {
+        self.enable = enable;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+
+    /// Clamps a value into the range and snaps it to the step.
+    ///
+    /// 将值限制在范围内并吸附到步长。
+    pub fn snap(&self, value: f32) -> f32 {
+        let [min, max] = [
+            self.range[0].min(self.range[1]),
+            self.range[0].max(self.range[1]),
+        ];
+        let value = match self.step {
+            Some(step) if step > 0_f32 => min + ((value - min) / step).round() * step,
+            _ => value,
+        };
+        value.clamp(min, max)
+    }
+
+    /// Returns the position of the value within the range, from 0 to 1.
+    ///
+    /// 返回值在范围内的位置，范围为0到1。
+    pub fn ratio(&self) -> f32 {
+        let span = self.range[1] - self.range[0];
+        if span == 0_f32 {
+            0_f32
+        } else {
+            ((self.value - self.range[0]) / span).clamp(0_f32, 1_f32)
+        }
+    }
+}
+
+/// Config options for line resources.
+///
+/// 线条资源的配置选项。
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+pub struct LineConfig {
+    /// Where the line starts.
+    ///
+    /// 线条的起点。
+    pub start: Option<LineEndpoint>,
+
+    /// Where the line ends, the arrowhead is drawn here.
+    ///
+    /// 线条的终点，箭头绘制在此处。
+    pub end: Option<LineEndpoint>,
+
+    /// Path shape, color, width and arrowhead of the line.
+    ///
+    /// 线条的路径形状、颜色、宽度和箭头。
+    pub style: Option<ConnectorStyle>,
+
+    /// Controls whether the line is visible or hidden.
+    ///
+    /// 控制线条是否可见或隐藏。
+    pub hidden: Option<bool>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Option<Vec<[String; 2]>>,
+}
+
+impl Config for LineConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn convert_to_resource(&self) -> Box<dyn FrontResource> {
+        Box::new(Line::default().from_config(self))
+    }
+
+    fn convert_from_resource(&self, resource: Box<dyn FrontResource>) -> Option<Box<dyn Config>> {
+        if let Some(resource) = resource.as_any().downcast_ref::<Line>() {
+            Some(Box::new(LineConfig::from_resource(resource)))
+        } else {
+            None
+        }
+    }
+}
+
+impl LineConfig {
+    pub fn from_resource(resource: &Line) -> Self {
+        Self {
+            start: Some(resource.start.clone()),
+            end: Some(resource.end.clone()),
+            style: Some(resource.style),
+            hidden: Some(resource.hidden),
+            tags: Some(resource.tags.clone()),
+        }
+    }
+
+    #[inline]
+    pub fn start(mut self, start: Option<LineEndpoint>) -> Self {
+        self.start = start;
+        self
+    }
+
+    #[inline]
+    pub fn end(mut self, end: Option<LineEndpoint>) -> Self {
+        self.end = end;
+        self
+    }
+
+    #[inline]
+    pub fn style(mut self, style: Option<ConnectorStyle>) -> Self {
+        self.style = style;
+        self
+    }
+
+    #[inline]
+    pub fn hidden(mut self, hidden: Option<bool>) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+/// Line drawn between two points or resources, optionally with an arrowhead.
+///
+/// 在两个点或资源之间绘制的线条，可以带有箭头。
+///
+/// Endpoints attached to resources are resolved from their rects every time the line is used,
+/// so the line follows the resources as they move. The line is painted when it is used, so
+/// resources drawn by the render queue afterwards appear on top of it.
+///
+/// 连接到资源的端点会在每次使用线条时根据资源的矩形重新计算，因此线条会跟随资源移动。
+/// 线条在使用时绘制，因此之后由渲染队列绘制的资源会显示在其上方。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Line {
+    /// Where the line starts.
+    ///
+    /// 线条的起点。
+    pub start: LineEndpoint,
+
+    /// Where the line ends, the arrowhead is drawn here.
+    ///
+    /// 线条的终点，箭头绘制在此处。
+    pub end: LineEndpoint,
+
+    /// Path shape, color, width and arrowhead of the line.
+    ///
+    /// 线条的路径形状、颜色、宽度和箭头。
+    pub style: ConnectorStyle,
+
+    /// Controls whether the line is visible or hidden.
+    ///
+    /// 控制线条是否可见或隐藏。
+    pub hidden: bool,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for Line {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl FrontResource for Line {
+    fn convert_to_config(&self) -> Box<dyn Config> {
+        Box::new(LineConfig::from_resource(self))
+    }
+
+    fn convert_from_config(&mut self, config: Box<dyn Config>) -> Option<Box<dyn FrontResource>> {
+        if let Some(config) = config.as_any().downcast_ref::<LineConfig>() {
+            Some(Box::new(self.clone().from_config(config)))
+        } else {
+            None
+        }
+    }
+
+    fn convert_to_original(&self) -> Box<dyn RustConstructorResource> {
+        Box::new(self.clone())
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_original_dyn(&self) -> &dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_original_dyn_mut(&mut self) -> &mut dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl Default for Line {
+    fn default() -> Self {
+        Self {
+            start: LineEndpoint::default(),
+            end: LineEndpoint::default(),
+            style: ConnectorStyle::default(),
+            hidden: false,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl Line {
+    pub fn from_config(mut self, config: &LineConfig) -> Self {
+        if let Some(ref start) = config.start {
+            self.start = start.clone();
+        };
+        if let Some(ref end) = config.end {
+            self.end = end.clone();
+        };
+        if let Some(style) = config.style {
+            self.style = style;
+        };
+        if let Some(hidden) = config.hidden {
+            self.hidden = hidden;
+        };
+        if let Some(ref tags) = config.tags {
+            self.tags = tags.clone();
+        };
+        self
+    }
+
+    #[inline]
+    pub fn start(mut self, start: LineEndpoint) -> Self {
+        self.start = start;
+        self
+    }
+
+    #[inline]
+    pub fn end(mut self, end: LineEndpoint) -> Self {
+        self.end = end;
+        self
+    }
+
+    #[inline]
+    pub fn style(mut self, style: ConnectorStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    #[inline]
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Menu that pops up at the pointer when an attached resource is right-clicked.
+///
+/// 右键点击所附加的资源时在指针处弹出的菜单。
+///
+/// The menu is painted above all resources by the page pipeline. Choosing an item or clicking
+/// elsewhere closes it, and the chosen item can be read with `consume_context_menu_choice`.
+///
+/// 菜单由页面流程绘制在所有资源上方。选择菜单项或点击其他位置会关闭菜单，
+/// 被选中的菜单项可以通过`consume_context_menu_choice`读取。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct ContextMenu {
+    /// Items of the menu as (label, item id).
+    ///
+    /// 菜单项，为(标签, 菜单项id)。
+    pub items: Vec<(String, String)>,
+
+    /// Background color as [R, G, B, A].
+    ///
+    /// 背景颜色，为[R, G, B, A]。
+    pub background_color: [u8; 4],
+
+    /// Text color as [R, G, B, A].
+    ///
+    /// 文本颜色，为[R, G, B, A]。
+    pub text_color: [u8; 4],
+
+    /// Color of the hovered item's highlight as [R, G, B, A].
+    ///
+    /// 悬停菜单项的高亮颜色，为[R, G, B, A]。
+    pub hover_color: [u8; 4],
+
+    /// Font size of the items.
+    ///
+    /// 菜单项的字体大小。
+    pub font_size: f32,
+
+    /// Corner rounding of the background.
+    ///
+    /// 背景的圆角。
+    pub rounding: f32,
+
+    /// Space between the items and the edge of their rows.
+    ///
+    /// 菜单项与其所在行边缘之间的间距。
+    pub padding: f32,
+
+    /// Whether the menu is open.
+    ///
+    /// 菜单是否已打开。
+    pub open: bool,
+
+    /// Pointer position the menu was opened at as [x, y].
+    ///
+    /// 打开菜单时的指针位置，为[x, y]。
+    pub position: [f32; 2],
+
+    /// Fade progress of the menu (0.0-1.0).
+    ///
+    /// 菜单的淡入淡出进度（0.0-1.0）。
+    pub opacity: f32,
+
+    /// Index of the item under the pointer.
+    ///
+    /// 指针下方菜单项的索引。
+    pub hovered_item: Option<usize>,
+
+    /// Id of the chosen item that has not been consumed yet.
+    ///
+    /// 尚未被消费的已选中菜单项id。
+    pub chosen: Option<String>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for ContextMenu {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        None
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl Default for ContextMenu {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            background_color: [40, 40, 40, 240],
+            text_color: [255, 255, 255, 255],
+            hover_color: [80, 80, 80, 255],
+            font_size: 14_f32,
+            rounding: 4_f32,
+            padding: 6_f32,
+            open: false,
+            position: [0_f32, 0_f32],
+            opacity: 0_f32,
+            hovered_item: None,
+            chosen: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl ContextMenu {
+    #[inline]
+    pub fn item(mut self, label: &str, item_id: &str) -> Self {
+        self.items.push((label.to_string(), item_id.to_string()));
+        self
+    }
+
+    #[inline]
+    pub fn items(mut self, items: &[(String, String)]) -> Self {
+        self.items = items.to_owned();
+        self
+    }
+
+    #[inline]
+    pub fn background_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.background_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn text_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.text_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn hover_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.hover_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[inline]
+    pub fn rounding(mut self, rounding: f32) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    #[inline]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Button a modal dialog was closed with.
+///
+/// 关闭模态对话框时所用的按钮。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ModalResult {
+    /// The OK button was pressed, or Enter while it had focus.
+    ///
+    /// 按下了确定按钮，或在其获得焦点时按下了Enter。
+    Ok,
+
+    /// The cancel button was pressed, or Escape.
+    ///
+    /// 按下了取消按钮或Escape。
+    Cancel,
+}
+
+/// Centered dialog that dims the window and blocks the resources behind it while open.
+///
+/// 打开时会使窗口变暗并阻挡其后方资源的居中对话框。
+///
+/// While a modal is open `resource_get_focus` returns false for every resource, so switches,
+/// clickables, tooltips and context menus stop reacting. Tab moves the keyboard focus between
+/// the modal's buttons only.
+///
+/// 模态对话框打开时`resource_get_focus`对所有资源都返回false，因此开关、可点击资源、工具提示
+/// 和右键菜单都不会响应。Tab键只会在对话框的按钮之间切换焦点。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Modal {
+    /// Title shown at the top of the dialog.
+    ///
+    /// 显示在对话框顶部的标题。
+    pub title: String,
+
+    /// Message shown below the title, wrapped to the dialog width.
+    ///
+    /// 显示在标题下方的消息，会按对话框宽度换行。
+    pub content: String,
+
+    /// Size of the content panel as [width, height].
+    ///
+    /// 内容面板的尺寸，为[width, height]。
+    pub size: [f32; 2],
+
+    /// Label of the OK button.
+    ///
+    /// 确定按钮的标签。
+    pub ok_label: String,
+
+    /// Label of the cancel button, None hides the button.
+    ///
+    /// 取消按钮的标签，为None时隐藏该按钮。
+    pub cancel_label: Option<String>,
+
+    /// Color of the full-window backdrop as [R, G, B, A].
+    ///
+    /// 覆盖整个窗口的背景遮罩颜色，为[R, G, B, A]。
+    pub backdrop_color: [u8; 4],
+
+    /// Background color of the content panel as [R, G, B, A].
+    ///
+    /// 内容面板的背景颜色，为[R, G, B, A]。
+    pub background_color: [u8; 4],
+
+    /// Text color as [R, G, B, A].
+    ///
+    /// 文本颜色，为[R, G, B, A]。
+    pub text_color: [u8; 4],
+
+    /// Button color as [R, G, B, A].
+    ///
+    /// 按钮颜色，为[R, G, B, A]。
+    pub button_color: [u8; 4],
+
+    /// Color of a hovered or focused button as [R, G, B, A].
+    ///
+    /// 悬停或获得焦点的按钮颜色，为[R, G, B, A]。
+    pub button_hover_color: [u8; 4],
+
+    /// Font size of the content and the buttons, the title is 1.25 times larger.
+    ///
+    /// 内容和按钮的字体大小，标题为其1.25倍。
+    pub font_size: f32,
+
+    /// Corner rounding of the panel and the buttons.
+    ///
+    /// 面板和按钮的圆角。
+    pub rounding: f32,
+
+    /// Whether the modal is open.
+    ///
+    /// 模态对话框是否已打开。
+    pub open: bool,
+
+    /// Fade progress of the modal (0.0-1.0).
+    ///
+    /// 模态对话框的淡入淡出进度（0.0-1.0）。
+    pub opacity: f32,
+
+    /// Index of the button with keyboard focus, 0 is OK and 1 is cancel.
+    ///
+    /// 拥有键盘焦点的按钮索引，0为确定，1为取消。
+    pub focused_button: usize,
+
+    /// Button the modal was last closed with and that has not been consumed yet.
+    ///
+    /// 上次关闭对话框所用且尚未被消费的按钮。
+    pub result: Option<ModalResult>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for Modal {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        None
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl Default for Modal {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            content: String::new(),
+            size: [360_f32, 180_f32],
+            ok_label: String::from("OK"),
+            cancel_label: Some(String::from("Cancel")),
+            backdrop_color: [0, 0, 0, 140],
+            background_color: [45, 45, 45, 255],
+            text_color: [255, 255, 255, 255],
+            button_color: [70, 70, 70, 255],
+            button_hover_color: [100, 100, 100, 255],
+            font_size: 16_f32,
+            rounding: 6_f32,
+            open: false,
+            opacity: 0_f32,
+            focused_button: 0,
+            result: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl Modal {
+    #[inline]
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn content(mut self, content: &str) -> Self {
+        self.content = content.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = [width, height];
+        self
+    }
+
+    #[inline]
+    pub fn ok_label(mut self, ok_label: &str) -> Self {
+        self.ok_label = ok_label.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn cancel_label(mut self, cancel_label: Option<&str>) -> Self {
+        self.cancel_label = cancel_label.map(|x| x.to_string());
+        self
+    }
+
+    #[inline]
+    pub fn backdrop_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.backdrop_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn background_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.background_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn text_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.text_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn button_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.button_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn button_hover_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.button_hover_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[inline]
+    pub fn rounding(mut self, rounding: f32) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slider_snaps_and_reports_its_ratio() {
+        let slider = Slider::default().range(0_f32, 10_f32).step(Some(2_f32));
+        assert_eq!(slider.snap(3.2), 4_f32);
+        assert_eq!(slider.snap(-5_f32), 0_f32);
+        assert_eq!(slider.snap(11_f32), 10_f32);
+        assert_eq!(slider.step(None).value(2.5).ratio(), 0.25);
+        assert_eq!(Slider::default().range(5_f32, 5_f32).ratio(), 0_f32);
+    }
+}
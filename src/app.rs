@@ -1,23 +1,38 @@
 //! Main application struct containing all GUI resources and state management.
 //!
 //! 程序主体，包含所有GUI资源和状态管理。
+#[cfg(feature = "gamepad")]
+use crate::GamepadAction;
+#[cfg(feature = "svg")]
+use crate::basic_front::SvgSource;
 use crate::{
-    BasicFrontResource, Config, DisplayInfo, HorizontalAlign, ListInfoDescribeMethod,
-    PositionSizeConfig, RenderConfig, RequestMethod, RequestType, RustConstructorError,
-    RustConstructorId, RustConstructorResource, RustConstructorResourceBox, Timer, VerticalAlign,
+    Anchor, AnchorEdge, AnimatedProperty, Animation, BasicFrontResource, Breakpoint, Clickable,
+    ClickableStyle, Config, ConnectorKind, ConnectorStyle, DisplayInfo, DragState, DropEvent,
+    Easing, EntrancePreset, HorizontalAlign, InputSummary, LineEndpoint, ListInfoDescribeMethod,
+    PageTransition, PositionSizeConfig, RenderConfig, RequestMethod, RequestType,
+    RustConstructorError, RustConstructorId, RustConstructorResource, RustConstructorResourceBox,
+    SecondaryWindow, Shortcut, Theme, ThemeColorTarget, Timer, Toast, ToastCorner, ToastStyle,
+    Tooltip, Transition, VerticalAlign,
     advance_front::{
-        Background, BackgroundConfig, BackgroundType, ClickAim, CustomPanelConfig,
-        CustomPanelLayout, PanelLocation, PanelMargin, PanelStorage, ResourcePanel,
-        ScrollBarDisplayMethod, ScrollLengthMethod, Switch, SwitchData,
+        Background, BackgroundConfig, BackgroundType, ClickAim, ContextMenu, CustomPanelConfig,
+        CustomPanelLayout, Line, Modal, ModalResult, Orientation, PanelLayout, PanelLocation,
+        PanelMargin, PanelStorage, ProgressBar, ResourcePanel, ScrollBarDisplayMethod,
+        ScrollLengthMethod, Slider, SliderData, Switch, SwitchAppearanceConfig, SwitchClickConfig,
+        SwitchData, TextInput, TextInputData, VerticalList,
+    },
+    background::{
+        ComputedVariable, Countdown, NamedTimer, PageData, ResourceGroup, SplitTime, SwitchGroup,
+        Variable, VariableWatcher,
     },
-    background::{PageData, SplitTime, Variable},
     background_type_discern,
     basic_front::{
-        BorderKind, CustomRect, DebugTextureHandle, HyperlinkSelectMethod, Image, ImageLoadMethod,
-        ImageLoader, LoadedImageData, Text,
+        BorderKind, BorderStyle, CustomRect, CustomRectConfig, DebugTextureHandle, GifAnimation,
+        HoverEffect, HyperlinkSelectMethod, Image, ImageLoadMethod, ImageLoadState, ImageLoader,
+        LoadedImageData, Polygon, PolygonConfig, RectFill, Text, TextAlign, TextConfig,
+        TextHighlight, TextLayoutCache, TextLayoutKey, TextStyle, TruncateMode,
     },
     build_id, downcast_resource, downcast_resource_mut, get_tag, position_size_processor,
-    type_processor,
+    position_size_processor_in, type_processor,
 };
 #[cfg(feature = "rc_bevy")]
 use bevy_asset::Asset;
@@ -25,18 +40,33 @@ use bevy_asset::Asset;
 use bevy_reflect::TypePath;
 #[cfg(feature = "rc_bevy")]
 use egui_bevy::{
-    Color32, ColorImage, CornerRadius, CursorIcon, FontData, FontDefinitions, FontFamily, FontId,
-    Galley, Id, Image as Img, ImageSource, Key, OpenUrl, Pos2, Rect, Sense, Stroke, StrokeKind, Ui,
-    Vec2, epaint::textures::TextureOptions, text::CCursor,
+    Align, Color32, ColorImage, CornerRadius, CursorIcon, Event, FontData, FontDefinitions,
+    FontFamily, FontId, Galley, Id, Image as Img, ImageSource, InputState, Key, LayerId, Mesh,
+    Modifiers, OpenUrl, Order, Painter, PointerButton, Pos2, Rect, Sense, Shape, Stroke,
+    StrokeKind, TextFormat, Ui, Vec2, ViewportBuilder, ViewportCommand, ViewportId,
+    emath::{Rot2, TSTransform},
+    epaint::CubicBezierShape,
+    text::{CCursor, LayoutJob},
 };
 #[cfg(feature = "rc_standard")]
 use egui_standard::{
-    Color32, ColorImage, CornerRadius, CursorIcon, FontData, FontDefinitions, FontFamily, FontId,
-    Galley, Id, Image as Img, ImageSource, Key, OpenUrl, Pos2, Rect, Sense, Stroke, StrokeKind, Ui,
-    Vec2, epaint::textures::TextureOptions, text::CCursor,
+    Align, Color32, ColorImage, CornerRadius, CursorIcon, Event, FontData, FontDefinitions,
+    FontFamily, FontId, Galley, Id, Image as Img, ImageSource, InputState, Key, LayerId, Mesh,
+    Modifiers, OpenUrl, Order, Painter, PointerButton, Pos2, Rect, Sense, Shape, Stroke,
+    StrokeKind, TextFormat, Ui, Vec2, ViewportBuilder, ViewportCommand, ViewportId,
+    emath::{Rot2, TSTransform},
+    epaint::CubicBezierShape,
+    text::{CCursor, LayoutJob},
 };
+#[cfg(feature = "gamepad")]
+use gilrs::{Button, EventType, Gilrs};
 use log::{error, info, warn};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+#[cfg(feature = "serde")]
+use serde_json::{Value, json};
 use std::{
+    any::Any,
     char,
     cmp::Ordering,
     collections::HashMap,
@@ -44,6 +74,7 @@ use std::{
     fs::read,
     sync::{Arc, Mutex},
     thread,
+    time::{Duration, SystemTime},
     vec::Vec,
 };
 
@@ -92,10 +123,10 @@ pub struct App {
     /// 此列表不应手动修改。
     pub basic_front_resource_list: Vec<String>,
 
-    /// Rendering layer information: (resource_id, [position, size], ignore_render_layer).
+    /// Rendering layer information: (resource_id, [position, size], ignore_render_layer, z_index).
     ///
-    /// 渲染层级信息：(资源ID, [位置, 尺寸], 是否忽略渲染层级)。
-    pub render_layer: Vec<(RustConstructorId, [[f32; 2]; 2], bool)>,
+    /// 渲染层级信息：(资源ID, [位置, 尺寸], 是否忽略渲染层级, 层级序号)。
+    pub render_layer: Vec<(RustConstructorId, [[f32; 2]; 2], bool, i32)>,
 
     /// List of currently active resources.
     ///
@@ -117,10 +148,305 @@ pub struct App {
     /// 列出正在加载的字体。
     pub loading_fonts: Vec<[String; 2]>,
 
+    /// Font names in the order missing glyphs fall through, used by `register_all_fonts`.
+    ///
+    /// 缺失字形时依次回退的字体名称，由`register_all_fonts`使用。
+    pub font_fallback: Vec<String>,
+
+    /// Bold and italic font variants that were requested but are not registered, each one is
+    /// warned about once.
+    ///
+    /// 被请求但未注册的粗体和斜体字体变体，每个只会警告一次。
+    pub missing_font_variants: Vec<String>,
+
+    /// Text layouts kept from the previous frame, reused while their inputs stay the same.
+    ///
+    /// 保留自上一帧的文本排版结果，在输入不变时复用。
+    pub text_layout_cache: Vec<TextLayoutCache>,
+
+    /// If true, basic front resources that lie entirely outside the visible area are not drawn.
+    ///
+    /// 如果为true，完全位于可见区域之外的基本前端资源不会被绘制。
+    pub render_culling: bool,
+
+    /// Number of resources skipped by render culling in the current frame.
+    ///
+    /// 当前帧中被渲染剔除跳过的资源数量。
+    pub culled_resources: usize,
+
+    /// Global UI scale, 1.0 is the original size.
+    ///
+    /// 全局界面缩放，1.0为原始大小。
+    pub ui_scale: f32,
+
+    /// Whether `ui_scale` changed and still has to be applied as egui's zoom factor.
+    ///
+    /// `ui_scale`是否已改变且尚未作为egui的缩放系数应用。
+    pub ui_scale_changed: bool,
+
+    /// Theme that theme-bound colors are resolved against.
+    ///
+    /// 用于解析绑定到主题的颜色的主题。
+    pub theme: Theme,
+
+    /// Resource colors bound to theme keys as (resource, property, key).
+    ///
+    /// 绑定到主题键的资源颜色，为(资源, 属性, 键)。
+    pub theme_bindings: Vec<(RustConstructorId, ThemeColorTarget, String)>,
+
+    /// In-memory snapshots of page resources taken by `snapshot`.
+    ///
+    /// 由`snapshot`保存在内存中的页面资源快照。
+    pub snapshots: Vec<(String, Vec<RustConstructorResourceBox>)>,
+
     /// Background image loading infrastructure.
     ///
     /// 后台图片加载基础设施。
     pub image_loader: ImageLoader,
+
+    /// If true, images loaded by path are reloaded when their file changes, debug builds only.
+    ///
+    /// 如果为true，通过路径加载的图像会在文件变化时重新加载，仅在调试构建中可用。
+    pub texture_hot_reload: bool,
+
+    /// Watched image files as (image, path, last modified time).
+    ///
+    /// 被监视的图像文件，为(图像, 路径, 上次修改时间)。
+    pub texture_mtimes: Vec<(RustConstructorId, String, SystemTime)>,
+
+    /// Time of the last texture file check in milliseconds.
+    ///
+    /// 上次检查纹理文件的时间（毫秒）。
+    pub last_texture_check: u128,
+
+    /// SVG data of images rasterized by `load_svg_texture`, kept for `resize_svg_texture`.
+    ///
+    /// 由`load_svg_texture`光栅化的图像的SVG数据，保留给`resize_svg_texture`使用。
+    #[cfg(feature = "svg")]
+    pub svg_sources: Vec<(RustConstructorId, Vec<u8>)>,
+
+    /// Bounds of the adaptive tick interval as [min, max] in milliseconds, None disables it.
+    ///
+    /// 自适应刷新间隔的范围[最小值, 最大值]（毫秒），为None时不启用。
+    pub adaptive_tick_interval: Option<[u128; 2]>,
+
+    /// Whether any animation was running during the current frame.
+    ///
+    /// 当前帧内是否有动画正在运行。
+    pub animation_active: bool,
+
+    /// If true, movable panels are kept inside the window while being dragged.
+    ///
+    /// 如果为true，可移动面板在拖动时会被限制在窗口内。
+    pub auto_constrain_draggables: bool,
+
+    /// Pixels of a draggable resource that must stay visible when it is constrained.
+    ///
+    /// 限制可拖动资源时必须保持可见的像素数。
+    pub constrain_margin: f32,
+
+    /// Hooks run right after the matching resource has been drawn.
+    ///
+    /// 在对应资源绘制完成后立即运行的钩子。
+    pub post_draw_hooks: Vec<(RustConstructorId, fn(&Painter, Rect))>,
+
+    /// Page whose name prefixes resources added or looked up inside `with_page_scope`.
+    ///
+    /// 在`with_page_scope`中添加或查找资源时用作名称前缀的页面。
+    pub page_scope: Option<String>,
+
+    /// Entrance animations as (resource, preset, duration in milliseconds).
+    ///
+    /// 入场动画列表，格式为(资源, 预设, 持续时间（毫秒）)。
+    pub entrance_animations: Vec<(RustConstructorId, EntrancePreset, u128)>,
+
+    /// Time (in milliseconds of total runtime) at which the pointer started hovering each resource.
+    ///
+    /// 指针开始悬停在各资源上的时间（总运行时间，毫秒）。
+    pub hover_start_time: Vec<(RustConstructorId, u128)>,
+
+    /// Explicit z-index of resources, resources without one use 0.
+    ///
+    /// 资源的显式层级序号，未设置的资源使用0。
+    pub z_index: Vec<(RustConstructorId, i32)>,
+
+    /// Variables derived from other variables, recomputed when a dependency is modified.
+    ///
+    /// 由其他变量派生的变量，在依赖被修改时重新计算。
+    pub computed_variables: Vec<ComputedVariable>,
+
+    /// Callbacks invoked when variables are modified through `modify_variable`.
+    ///
+    /// 变量通过`modify_variable`修改时调用的回调。
+    pub variable_watchers: Vec<VariableWatcher>,
+
+    /// Toast notifications waiting to be dismissed, oldest first.
+    ///
+    /// 等待消失的提示消息，最早的在前。
+    pub toasts: Vec<Toast>,
+
+    /// Toasts waiting for a free slot because `max_visible_toasts` was reached, oldest first.
+    ///
+    /// 因达到`max_visible_toasts`而等待空位的提示消息，最早的在前。
+    pub queued_toasts: Vec<Toast>,
+
+    /// Maximum number of toasts shown at once, None for no limit.
+    ///
+    /// 同时显示的提示消息的最大数量，为None时不限制。
+    pub max_visible_toasts: Option<usize>,
+
+    /// Id of the toast action that was clicked and has not been consumed yet.
+    ///
+    /// 已被点击且尚未被消费的提示消息操作id。
+    pub toast_action: Option<String>,
+
+    /// Named tween animations, finished ones are kept until replaced so they can be queried.
+    ///
+    /// 具名补间动画，已结束的动画会保留到被替换为止以便查询。
+    pub animations: Vec<(String, Animation)>,
+
+    /// Tooltips attached to resources.
+    ///
+    /// 附加到资源上的工具提示。
+    pub tooltips: Vec<Tooltip>,
+
+    /// Resources that can receive keyboard focus, in Tab order.
+    ///
+    /// 可以获得键盘焦点的资源，按Tab键顺序排列。
+    pub focus_order: Vec<RustConstructorId>,
+
+    /// Index of the focused resource in `focus_order`.
+    ///
+    /// 获得焦点的资源在`focus_order`中的索引。
+    pub focus_index: Option<usize>,
+
+    /// Resources positioned relative to other resources.
+    ///
+    /// 相对于其他资源定位的资源。
+    pub anchors: Vec<Anchor>,
+
+    /// Alternative layouts of resources keyed on window width.
+    ///
+    /// 按窗口宽度切换的资源替代布局。
+    pub breakpoints: Vec<Breakpoint>,
+
+    /// Resources laid out inside the rect of another resource as (child, parent).
+    ///
+    /// 在另一个资源的矩形内布局的资源，为(子资源, 父资源)。
+    pub relative_layouts: Vec<(RustConstructorId, RustConstructorId)>,
+
+    /// Tags whose resources stay disabled across frames, set by `set_tag_enabled`.
+    ///
+    /// 其资源在各帧之间保持禁用的标签，由`set_tag_enabled`设置。
+    pub disabled_tags: Vec<[String; 2]>,
+
+    /// Registered keyboard shortcuts.
+    ///
+    /// 已注册的键盘快捷键。
+    pub shortcuts: Vec<Shortcut>,
+
+    /// Most recent copy from a selectable text as (resource, [start, end], copied string).
+    ///
+    /// 最近一次从可选择文本复制的内容，格式为(资源, [起始, 结束], 复制的字符串)。
+    pub last_copied: Option<(RustConstructorId, [usize; 2], String)>,
+
+    /// Hooks run after text is copied from the matching resource.
+    ///
+    /// 从对应资源复制文本后运行的钩子。
+    pub copy_hooks: Vec<(RustConstructorId, fn(&str))>,
+
+    /// Clip rects of the enclosing `with_clip` calls, innermost last.
+    ///
+    /// 外层`with_clip`调用的裁剪矩形，最内层的在最后。
+    pub clip_stack: Vec<Rect>,
+
+    /// Clip rects inherited by resources used inside `with_clip`.
+    ///
+    /// 在`with_clip`中使用的资源继承的裁剪矩形。
+    pub inherited_clips: Vec<(RustConstructorId, Rect)>,
+
+    /// Resources behaving like lightweight buttons.
+    ///
+    /// 表现为轻量按钮的资源。
+    pub clickables: Vec<Clickable>,
+
+    /// Resources that can be dragged with the pointer.
+    ///
+    /// 可以用指针拖动的资源。
+    pub draggables: Vec<RustConstructorId>,
+
+    /// Resources that accept dropped resources.
+    ///
+    /// 接受放置资源的资源。
+    pub drop_targets: Vec<RustConstructorId>,
+
+    /// Drag currently held, None if no draggable resource is pressed.
+    ///
+    /// 当前按住的拖动，没有按下可拖动资源时为None。
+    pub drag_state: Option<DragState>,
+
+    /// Drop that happened this frame and was not consumed yet.
+    ///
+    /// 本帧发生且尚未被消费的放置。
+    pub drop_event: Option<DropEvent>,
+
+    /// Secondary windows, resources tagged with `["viewport", window_id]` are drawn in them.
+    ///
+    /// 次级窗口，带有`["viewport", 窗口ID]`标签的资源会绘制在其中。
+    pub secondary_windows: Vec<SecondaryWindow>,
+
+    /// Context menus attached to resources as (target, menu).
+    ///
+    /// 附加到资源上的右键菜单，为(目标, 菜单)。
+    pub context_menus: Vec<(RustConstructorId, RustConstructorId)>,
+
+    /// Modal dialog that is currently open and blocks the resources behind it.
+    ///
+    /// 当前打开并阻挡其后方资源的模态对话框。
+    pub active_modal: Option<RustConstructorId>,
+
+    /// Keyboard focus saved when a modal dialog opened, restored once it closes.
+    ///
+    /// 模态对话框打开时保存的键盘焦点，对话框关闭后恢复。
+    pub modal_saved_focus: Option<usize>,
+
+    /// Transition played by `switch_page` for pages without their own as (animation, duration in
+    /// milliseconds), None switches instantly.
+    ///
+    /// `switch_page`为没有自身过渡的页面播放的过渡，为(动画, 时长（毫秒）)，为None时立即切换。
+    pub page_transition: Option<(Transition, u128)>,
+
+    /// Page transition currently playing.
+    ///
+    /// 当前正在播放的页面过渡。
+    pub active_page_transition: Option<PageTransition>,
+
+    /// Pages left through `push_page`, the most recent last.
+    ///
+    /// 通过`push_page`离开的页面，最近的在最后。
+    pub page_history: Vec<String>,
+
+    /// Whether the debug overlay is drawn after each frame.
+    ///
+    /// 是否在每帧之后绘制调试浮层。
+    pub debug_overlay: bool,
+
+    /// Gamepad input context, None while gamepad navigation is disabled.
+    ///
+    /// 手柄输入上下文，手柄导航禁用时为None。
+    #[cfg(feature = "gamepad")]
+    pub gamepad: Option<Gilrs>,
+
+    /// Gamepad buttons and the navigation actions they trigger.
+    ///
+    /// 手柄按钮及其触发的导航操作。
+    #[cfg(feature = "gamepad")]
+    pub gamepad_mapping: Vec<(Button, GamepadAction)>,
+
+    /// Whether the gamepad activate action was triggered this frame.
+    ///
+    /// 本帧是否触发了手柄的激活操作。
+    pub gamepad_activated: bool,
 }
 
 unsafe impl Send for App {}
@@ -143,32 +469,296 @@ impl Default for App {
                 String::from("Image"),
                 String::from("Text"),
                 String::from("CustomRect"),
+                String::from("Polygon"),
             ],
             render_layer: Vec::new(),
             active_list: Vec::new(),
             render_list: Vec::new(),
             loaded_fonts: Vec::new(),
             loading_fonts: Vec::new(),
+            font_fallback: Vec::new(),
+            missing_font_variants: Vec::new(),
+            text_layout_cache: Vec::new(),
+            render_culling: false,
+            culled_resources: 0,
+            ui_scale: 1_f32,
+            ui_scale_changed: false,
+            theme: Theme::default(),
+            theme_bindings: Vec::new(),
+            snapshots: Vec::new(),
             image_loader: ImageLoader {
                 completed: Arc::new(Mutex::new(HashMap::new())),
+                failed: Arc::new(Mutex::new(HashMap::new())),
             },
+            texture_hot_reload: false,
+            texture_mtimes: Vec::new(),
+            last_texture_check: 0,
+            #[cfg(feature = "svg")]
+            svg_sources: Vec::new(),
+            adaptive_tick_interval: None,
+            animation_active: false,
+            auto_constrain_draggables: false,
+            constrain_margin: 20_f32,
+            post_draw_hooks: Vec::new(),
+            page_scope: None,
+            entrance_animations: Vec::new(),
+            hover_start_time: Vec::new(),
+            z_index: Vec::new(),
+            computed_variables: Vec::new(),
+            variable_watchers: Vec::new(),
+            toasts: Vec::new(),
+            queued_toasts: Vec::new(),
+            max_visible_toasts: None,
+            toast_action: None,
+            animations: Vec::new(),
+            tooltips: Vec::new(),
+            focus_order: Vec::new(),
+            focus_index: None,
+            anchors: Vec::new(),
+            breakpoints: Vec::new(),
+            relative_layouts: Vec::new(),
+            disabled_tags: Vec::new(),
+            shortcuts: Vec::new(),
+            last_copied: None,
+            copy_hooks: Vec::new(),
+            clip_stack: Vec::new(),
+            inherited_clips: Vec::new(),
+            clickables: Vec::new(),
+            draggables: Vec::new(),
+            drop_targets: Vec::new(),
+            drag_state: None,
+            drop_event: None,
+            secondary_windows: Vec::new(),
+            context_menus: Vec::new(),
+            active_modal: None,
+            modal_saved_focus: None,
+            page_transition: None,
+            active_page_transition: None,
+            page_history: Vec::new(),
+            debug_overlay: false,
+            #[cfg(feature = "gamepad")]
+            gamepad: None,
+            #[cfg(feature = "gamepad")]
+            gamepad_mapping: vec![
+                (Button::DPadUp, GamepadAction::Up),
+                (Button::DPadDown, GamepadAction::Down),
+                (Button::DPadLeft, GamepadAction::Left),
+                (Button::DPadRight, GamepadAction::Right),
+                (Button::South, GamepadAction::Activate),
+                (Button::East, GamepadAction::Back),
+            ],
+            gamepad_activated: false,
         }
     }
 }
 
 impl App {
+    /// Number of frame times kept for the frame statistics.
+    ///
+    /// 帧统计保留的帧时间数量。
+    pub const MAX_FRAME_SAMPLES: usize = 120;
+
     #[inline]
     pub fn tick_interval(mut self, tick_interval: u128) -> Self {
         self.tick_interval = tick_interval;
         self
     }
 
+    #[inline]
+    pub fn max_visible_toasts(mut self, max_visible_toasts: Option<usize>) -> Self {
+        self.max_visible_toasts = max_visible_toasts;
+        self
+    }
+
+    /// Enables or disables render culling.
+    ///
+    /// 启用或禁用渲染剔除。
+    ///
+    /// Culled resources still update their position and state, only painting is skipped. The
+    /// saving grows with the number of offscreen resources, compare `current_fps` with
+    /// `culled_resources` on a crowded page to measure it. The `render_culling` example places
+    /// 5000 offscreen rectangles, run it with and without `--cull` to compare.
+    ///
+    /// 被剔除的资源仍会更新位置和状态，只是跳过绘制。节省的开销随屏幕外资源的数量增长，
+    /// 可以在资源较多的页面上对比`current_fps`和`culled_resources`来衡量。`render_culling`示例
+    /// 放置了5000个屏幕外矩形，分别在附加与不附加`--cull`时运行即可对比。
+    #[inline]
+    pub fn render_culling(mut self, render_culling: bool) -> Self {
+        self.render_culling = render_culling;
+        self
+    }
+
+    #[inline]
+    pub fn page_transition(mut self, page_transition: Option<(Transition, u128)>) -> Self {
+        self.page_transition = page_transition;
+        self
+    }
+
+    #[inline]
+    pub fn debug_overlay(mut self, debug_overlay: bool) -> Self {
+        self.debug_overlay = debug_overlay;
+        self
+    }
+
     #[inline]
     pub fn current_page(mut self, current_page: &str) -> Self {
         self.current_page = current_page.to_string();
         self
     }
 
+    #[inline]
+    pub fn auto_constrain_draggables(
+        mut self,
+        auto_constrain_draggables: bool,
+        margin: f32,
+    ) -> Self {
+        self.auto_constrain_draggables = auto_constrain_draggables;
+        self.constrain_margin = margin;
+        self
+    }
+
+    /// Enables the adaptive tick interval.
+    ///
+    /// 启用自适应刷新间隔。
+    ///
+    /// While an animation is running the tick interval drops to `min` for smooth motion, once
+    /// everything is idle it grows to `max` and repaints are throttled to save power.
+    ///
+    /// 有动画运行时刷新间隔降至`min`以保证动画流畅，全部空闲时升至`max`并限制重绘频率以节省电量。
+    pub fn set_adaptive_tick(&mut self, min: u128, max: u128) {
+        self.adaptive_tick_interval = Some([min.min(max), min.max(max)]);
+    }
+
+    /// Replaces the theme, theme-bound colors follow it from the next frame on.
+    ///
+    /// 替换主题，绑定到主题的颜色从下一帧开始跟随新主题。
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Binds a color property of a basic front resource to a theme key, replacing its previous
+    /// binding.
+    ///
+    /// 将基本前端资源的颜色属性绑定到主题键，会替换其之前的绑定。
+    ///
+    /// The key is resolved each time the resource is drawn, keys missing from the theme leave
+    /// the color unchanged. Alpha values are never touched.
+    ///
+    /// 每次绘制资源时都会解析该键，主题中不存在的键不会改变颜色。不透明度不会被修改。
+    pub fn bind_theme_color(
+        &mut self,
+        id: &RustConstructorId,
+        target: ThemeColorTarget,
+        key: &str,
+    ) -> Result<(), RustConstructorError> {
+        let supported = matches!(
+            (&*id.discern_type, target),
+            (
+                "Text",
+                ThemeColorTarget::Color | ThemeColorTarget::Background
+            ) | (
+                "Image",
+                ThemeColorTarget::Background | ThemeColorTarget::Overlay
+            ) | (
+                "CustomRect",
+                ThemeColorTarget::Color | ThemeColorTarget::Border | ThemeColorTarget::Overlay
+            ) | (
+                "Polygon",
+                ThemeColorTarget::Color | ThemeColorTarget::Border
+            )
+        );
+        if !supported {
+            error!(
+                "[ResourceDowncastTypeMismatch]bind_theme_color: Resource '{}({})' has no {:?} color.",
+                id.name, id.discern_type, target
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' has no {:?} color.",
+                    id.name, id.discern_type, target
+                ),
+            });
+        };
+        self.get_basic_front_resource(id)?;
+        let id = self.resolved_id(id)?;
+        self.theme_bindings.retain(|x| x.0 != id || x.1 != target);
+        self.theme_bindings.push((id, target, key.to_string()));
+        Ok(())
+    }
+
+    /// Removes the theme binding of a color property, the property keeps its last color.
+    ///
+    /// 移除颜色属性的主题绑定，该属性保持最后的颜色。
+    pub fn unbind_theme_color(&mut self, id: &RustConstructorId, target: ThemeColorTarget) {
+        let id = match self.check_resource_exists(id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => id.clone(),
+        };
+        self.theme_bindings.retain(|x| x.0 != id || x.1 != target);
+    }
+
+    /// Sets the global UI scale, clamped to 0.5-3.0, it is applied by the page pipeline.
+    ///
+    /// 设置全局界面缩放，范围限制在0.5-3.0，由页面流程应用。
+    ///
+    /// The scale is applied as egui's zoom factor, so font sizes, resource sizes, positions and
+    /// offsets all grow together and texts are laid out again with the new size. Grid-based
+    /// layouts see a correspondingly smaller window.
+    ///
+    /// 缩放作为egui的缩放系数应用，因此字体大小、资源尺寸、位置和偏移会一起放大，文本也会按新尺寸重新排版。
+    /// 基于网格的布局看到的窗口会相应变小。
+    ///
+    /// The zoom factor is only set once after each call, so zooming done elsewhere (e.g. egui's
+    /// zoom shortcuts) is kept until the next call.
+    ///
+    /// 每次调用后缩放系数只会设置一次，因此在其他地方进行的缩放（例如egui的缩放快捷键）会保留到下次调用。
+    pub fn set_ui_scale(&mut self, ui_scale: f32) {
+        self.ui_scale = ui_scale.clamp(0.5, 3_f32);
+        self.ui_scale_changed = true;
+    }
+
+    /// Disables the adaptive tick interval and restores a fixed interval.
+    ///
+    /// 禁用自适应刷新间隔并恢复为固定间隔。
+    pub fn disable_adaptive_tick(&mut self, tick_interval: u128) {
+        self.adaptive_tick_interval = None;
+        self.tick_interval = tick_interval;
+    }
+
+    /// Marks that an animation is running in the current frame.
+    ///
+    /// 标记当前帧内有动画正在运行。
+    ///
+    /// Custom animations should call this every frame they are running so that the
+    /// adaptive tick interval stays short.
+    ///
+    /// 自定义动画在运行的每一帧都应调用此方法，以使自适应刷新间隔保持较短。
+    #[inline]
+    pub fn mark_animation_active(&mut self) {
+        self.animation_active = true;
+    }
+
+    /// Picks the adaptive tick interval from the animation state of the frame and clears
+    /// that state, returns true when everything is idle.
+    ///
+    /// 根据本帧的动画状态选择自适应刷新间隔并清除该状态，全部空闲时返回true。
+    ///
+    /// The page pipeline calls this once per frame, without an adaptive tick interval it
+    /// only clears the state and returns false.
+    ///
+    /// 页面流程每帧调用一次，未启用自适应刷新间隔时只清除状态并返回false。
+    pub fn update_tick_interval(&mut self) -> bool {
+        let idle = if let Some([min, max]) = self.adaptive_tick_interval {
+            self.tick_interval = if self.animation_active { min } else { max };
+            !self.animation_active
+        } else {
+            false
+        };
+        self.animation_active = false;
+        idle
+    }
+
     /// Consume all completed background image loads and create egui textures.
     ///
     /// 消费所有已完成的后台图片加载结果并创建 egui 纹理。
@@ -182,8 +772,17 @@ impl App {
             if self.check_resource_exists(&id).is_none() {
                 continue;
             }
-            let texture =
-                ui.load_texture(&id.name, loaded_data.color_image, TextureOptions::LINEAR);
+            let Ok(texture_filter) = self
+                .get_resource::<Image>(&id)
+                .map(|image| image.texture_filter)
+            else {
+                continue;
+            };
+            let texture = ui.load_texture(
+                &id.name,
+                loaded_data.color_image,
+                texture_filter.to_texture_options(),
+            );
             let handle = DebugTextureHandle {
                 path: loaded_data.path,
                 texture_handle: texture,
@@ -195,105 +794,579 @@ impl App {
         }
     }
 
-    /// Draws a specific resource by its index in the rendering queue.
+    /// Enables or disables reloading image textures when their source file changes.
     ///
-    /// 根据资源在渲染队列中的索引值绘制特定资源。
+    /// 启用或禁用在源文件变化时重新加载图像纹理。
     ///
-    /// This method handles the rendering of different resource types including:
-    /// - Images with various loading methods and transformations
-    /// - Text with formatting, selection, and hyperlink support
-    /// - Custom rectangles with borders and styling
+    /// Only available in debug builds, so it never ships in a release. Modified times are checked
+    /// once per `tick_interval`, a changed file is decoded again in the background and the old
+    /// texture stays visible until the new one is ready.
     ///
-    /// 此方法处理不同类型资源的渲染，包括：
-    /// - 具有各种加载方法和变换的图像
-    /// - 具有格式设置、选择和超链接支持的文本
-    /// - 具有边框和样式的自定义矩形
-    pub fn draw_resource_by_index(
+    /// 仅在调试构建中可用，因此不会出现在发布版本中。每个`tick_interval`检查一次修改时间，
+    /// 文件变化时会在后台重新解码，新纹理就绪前保持显示旧纹理。
+    pub fn enable_texture_hot_reload(&mut self, enable: bool) {
+        if enable && !cfg!(debug_assertions) {
+            warn!("Texture hot reload is only available in debug builds.");
+            return;
+        };
+        self.texture_hot_reload = enable;
+        self.texture_mtimes.clear();
+    }
+
+    /// Reloads the textures of images whose source file was modified since the last check.
+    ///
+    /// 重新加载自上次检查以来源文件被修改的图像的纹理。
+    pub fn update_texture_hot_reload(&mut self) {
+        if !self.texture_hot_reload
+            || self
+                .timer
+                .total_time
+                .saturating_sub(self.last_texture_check)
+                < self.tick_interval
+        {
+            return;
+        };
+        self.last_texture_check = self.timer.total_time;
+        let mut changed = Vec::new();
+        let mut watched = Vec::new();
+        for rcr in &self.rust_constructor_resource {
+            if rcr.id.discern_type != "Image" {
+                continue;
+            };
+            let Ok(image) = downcast_resource::<Image>(&*rcr.content) else {
+                continue;
+            };
+            let ImageLoadMethod::ByPath((ref path, flip)) = image.image_load_method else {
+                continue;
+            };
+            // 只读取文件元数据，文件变化时才重新解码
+            let Ok(modified) = std::fs::metadata(path).and_then(|x| x.modified()) else {
+                continue;
+            };
+            match self
+                .texture_mtimes
+                .iter()
+                .find(|x| x.0 == rcr.id && x.1 == *path)
+            {
+                Some(entry) if entry.2 != modified => {
+                    changed.push((rcr.id.clone(), path.clone(), flip));
+                }
+                _ => {}
+            };
+            watched.push((rcr.id.clone(), path.clone(), modified));
+        }
+        self.texture_mtimes = watched;
+        for (id, path, flip) in changed {
+            if let Ok(image) = self.get_resource_mut::<Image>(&id) {
+                // 丢弃缓存的旧纹理，避免切换路径后再次使用
+                image.texture_list.retain(|x| x.path != path);
+            };
+            info!("Reloading texture for image '{}' from '{path}'.", id.name);
+            self.spawn_image_load(&id.name, &path, flip);
+        }
+    }
+
+    /// Starts loading the texture of an image on a worker thread without waiting for it to be
+    /// drawn.
+    ///
+    /// 在工作线程中开始加载图像的纹理，而无需等待其被绘制。
+    ///
+    /// Images loaded by path are already decoded in the background the first time they are
+    /// drawn, calling this ahead of time (e.g. when entering a page) hides the delay. The image
+    /// draws nothing until the texture is uploaded by `process_completed_image_loads`, check the
+    /// progress with `image_load_state`.
+    ///
+    /// 通过路径加载的图像在首次绘制时就会在后台解码，提前调用此方法（例如进入页面时）可以隐藏延迟。
+    /// 在`process_completed_image_loads`上传纹理之前图像不会绘制任何内容，可以用`image_load_state`
+    /// 查看进度。
+    pub fn load_image_async(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        let image = self.get_resource::<Image>(id)?;
+        let ImageLoadMethod::ByPath((ref path, flip)) = image.image_load_method else {
+            return Ok(());
+        };
+        let path = path.clone();
+        if image.texture.as_ref().is_some_and(|x| x.path == path) || image.last_frame_path == path {
+            return Ok(());
+        };
+        let resource_name = self.resolved_id(id)?.name;
+        self.spawn_image_load(&resource_name, &path, flip);
+        let image = self.get_resource_mut::<Image>(id)?;
+        image.texture = None;
+        image.gif = None;
+        image.last_frame_path = path;
+        Ok(())
+    }
+
+    /// Decodes image bytes and uses them as the texture of an image.
+    ///
+    /// 解码图像字节并将其用作图像的纹理。
+    ///
+    /// Works with assets embedded through `include_bytes!` or fetched over the network. The
+    /// bytes are decoded the same way as files loaded by path and the image switches to
+    /// `ImageLoadMethod::ByTexture`.
+    ///
+    /// 适用于通过`include_bytes!`嵌入或通过网络获取的资源。字节的解码方式与通过路径加载的文件相同，
+    /// 图像会切换为`ImageLoadMethod::ByTexture`。
+    pub fn load_image_from_bytes(
         &mut self,
+        id: &RustConstructorId,
+        bytes: &[u8],
+        flip: [bool; 2],
         ui: &mut Ui,
-        index: usize,
     ) -> Result<(), RustConstructorError> {
-        if let Some(render_resource) = self.render_list.clone().get(index) {
-            match &*render_resource.0.discern_type {
-                "Image" => {
-                    let image =
-                        self.get_resource::<Image>(&build_id(&render_resource.0.name, "Image"))?;
-                    if image.display_info.enable {
+        let texture_filter = self.get_resource::<Image>(id)?.texture_filter;
+        let color_image = match Self::decode_image(bytes, flip) {
+            Ok(color_image) => color_image,
+            Err(e) => {
+                error!(
+                    "[ImageLoadFailed]load_image_from_bytes: Failed to decode the bytes of image '{}': {e}",
+                    id.name
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::IMAGE_LOAD_FAILED.to_string(),
+                    description: format!("Failed to decode the bytes of image '{}': {e}", id.name),
+                });
+            }
+        };
+        let resource_name = self.resolved_id(id)?.name;
+        let handle = DebugTextureHandle {
+            path: String::new(),
+            texture_handle: ui.load_texture(
+                &resource_name,
+                color_image,
+                texture_filter.to_texture_options(),
+            ),
+        };
+        let image = self.get_resource_mut::<Image>(id)?;
+        image.image_load_method = ImageLoadMethod::ByTexture(handle.clone());
+        image.texture = Some(handle);
+        image.gif = None;
+        Ok(())
+    }
+
+    /// Rasterizes an SVG at the given pixel size and uses it as the texture of an image.
+    ///
+    /// 按指定像素尺寸光栅化SVG并将其用作图像的纹理。
+    ///
+    /// The SVG data is kept so that `resize_svg_texture` can rasterize it again, e.g. after the
+    /// UI scale changed, keeping icons sharp. Requires the `svg` feature.
+    ///
+    /// SVG数据会被保留，以便`resize_svg_texture`重新光栅化（例如界面缩放改变后），使图标保持清晰。
+    /// 需要启用`svg`特性。
+    #[cfg(feature = "svg")]
+    pub fn load_svg_texture(
+        &mut self,
+        id: &RustConstructorId,
+        source: SvgSource,
+        target_size: [u32; 2],
+        ui: &mut Ui,
+    ) -> Result<(), RustConstructorError> {
+        let texture_filter = self.get_resource::<Image>(id)?.texture_filter;
+        let data = match source {
+            SvgSource::Path(path) => match read(&path) {
+                Ok(data) => data,
+                Err(e) => {
+                    error!(
+                        "[ImageLoadFailed]load_svg_texture: Failed to read an SVG from the path '{path}': {e}"
+                    );
+                    return Err(RustConstructorError {
+                        error_id: RustConstructorError::IMAGE_LOAD_FAILED.to_string(),
+                        description: format!("Failed to read an SVG from the path '{path}': {e}"),
+                    });
+                }
+            },
+            SvgSource::Bytes(data) => data,
+        };
+        let color_image = match Self::rasterize_svg(&data, target_size) {
+            Ok(color_image) => color_image,
+            Err(e) => {
+                error!(
+                    "[SvgParseFailed]load_svg_texture: Failed to parse the SVG of image '{}': {e}",
+                    id.name
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::SVG_PARSE_FAILED.to_string(),
+                    description: format!("Failed to parse the SVG of image '{}': {e}", id.name),
+                });
+            }
+        };
+        let resource_id = self.resolved_id(id)?;
+        let handle = DebugTextureHandle {
+            path: String::new(),
+            texture_handle: ui.load_texture(
+                &resource_id.name,
+                color_image,
+                texture_filter.to_texture_options(),
+            ),
+        };
+        let image = self.get_resource_mut::<Image>(id)?;
+        image.image_load_method = ImageLoadMethod::ByTexture(handle.clone());
+        image.texture = Some(handle);
+        image.gif = None;
+        self.svg_sources.retain(|x| x.0 != resource_id);
+        self.svg_sources.push((resource_id, data));
+        Ok(())
+    }
+
+    /// Rasterizes the SVG of an image again at a new pixel size.
+    ///
+    /// 按新的像素尺寸重新光栅化图像的SVG。
+    #[cfg(feature = "svg")]
+    pub fn resize_svg_texture(
+        &mut self,
+        id: &RustConstructorId,
+        target_size: [u32; 2],
+        ui: &mut Ui,
+    ) -> Result<(), RustConstructorError> {
+        let data = self
+            .check_resource_exists(id)
+            .and_then(|index| {
+                let resource_id = &self.rust_constructor_resource[index].id;
+                self.svg_sources.iter().find(|x| x.0 == *resource_id)
+            })
+            .map(|x| x.1.clone());
+        if let Some(data) = data {
+            self.load_svg_texture(id, SvgSource::Bytes(data), target_size, ui)
+        } else {
+            error!(
+                "[SvgNotFound]resize_svg_texture: Image '{}' was not loaded from an SVG.",
+                id.name
+            );
+            Err(RustConstructorError {
+                error_id: RustConstructorError::SVG_NOT_FOUND.to_string(),
+                description: format!("Image '{}' was not loaded from an SVG.", id.name),
+            })
+        }
+    }
+
+    /// Decodes every frame of an animated GIF and plays it on an image.
+    ///
+    /// 解码GIF动画的每一帧并在图像上播放。
+    ///
+    /// All frames are uploaded up front and the image swaps its texture each frame following
+    /// the delay of every GIF frame. Delays below 20 ms are shown for 100 ms, as browsers do.
+    /// Requires the `gif` feature.
+    ///
+    /// 所有帧会预先上传，图像按每个GIF帧的延迟逐帧切换纹理。与浏览器一致，低于20毫秒的延迟按100毫秒显示。
+    /// 需要启用`gif`特性。
+    #[cfg(feature = "gif")]
+    pub fn load_gif(
+        &mut self,
+        id: &RustConstructorId,
+        bytes: &[u8],
+        ui: &mut Ui,
+    ) -> Result<(), RustConstructorError> {
+        use image::{AnimationDecoder, codecs::gif::GifDecoder};
+        let texture_filter = self.get_resource::<Image>(id)?.texture_filter;
+        let frames = match GifDecoder::new(std::io::Cursor::new(bytes))
+            .and_then(|decoder| decoder.into_frames().collect_frames())
+        {
+            Ok(frames) if !frames.is_empty() => frames,
+            Ok(_) => {
+                error!(
+                    "[ImageLoadFailed]load_gif: The GIF of image '{}' has no frames.",
+                    id.name
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::IMAGE_LOAD_FAILED.to_string(),
+                    description: format!("The GIF of image '{}' has no frames.", id.name),
+                });
+            }
+            Err(e) => {
+                error!(
+                    "[ImageLoadFailed]load_gif: Failed to decode the GIF of image '{}': {e}",
+                    id.name
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::IMAGE_LOAD_FAILED.to_string(),
+                    description: format!("Failed to decode the GIF of image '{}': {e}", id.name),
+                });
+            }
+        };
+        let resource_name = self.resolved_id(id)?.name;
+        let mut gif = GifAnimation::default();
+        for (index, frame) in frames.into_iter().enumerate() {
+            let (numerator, denominator) = frame.delay().numer_denom_ms();
+            let delay = numerator as u128 / denominator.max(1) as u128;
+            gif.delays.push(if delay < 20 { 100 } else { delay });
+            let buffer = frame.into_buffer();
+            let color_image = ColorImage::from_rgba_unmultiplied(
+                [buffer.width() as usize, buffer.height() as usize],
+                &buffer.into_raw(),
+            );
+            gif.frames.push(DebugTextureHandle {
+                path: String::new(),
+                texture_handle: ui.load_texture(
+                    format!("{resource_name}#{index}"),
+                    color_image,
+                    texture_filter.to_texture_options(),
+                ),
+            });
+        }
+        let image = self.get_resource_mut::<Image>(id)?;
+        image.image_load_method = ImageLoadMethod::ByTexture(gif.frames[0].clone());
+        image.texture = Some(gif.frames[0].clone());
+        image.gif = Some(gif);
+        Ok(())
+    }
+
+    /// Plays or pauses the GIF of an image.
+    ///
+    /// 播放或暂停图像的GIF。
+    pub fn set_gif_playing(
+        &mut self,
+        id: &RustConstructorId,
+        playing: bool,
+    ) -> Result<(), RustConstructorError> {
+        let total_time = self.timer.total_time;
+        let gif = self.gif_mut(id, "set_gif_playing")?;
+        if playing {
+            gif.play();
+        } else {
+            gif.pause(total_time);
+        };
+        Ok(())
+    }
+
+    /// Sets whether the GIF of an image restarts after its last frame.
+    ///
+    /// 设置图像的GIF在最后一帧后是否重新开始。
+    pub fn set_gif_looping(
+        &mut self,
+        id: &RustConstructorId,
+        looping: bool,
+    ) -> Result<(), RustConstructorError> {
+        let total_time = self.timer.total_time;
+        let gif = self.gif_mut(id, "set_gif_looping")?;
+        // 保持当前播放进度，只改变之后的行为
+        let played_time = gif.played_time(total_time);
+        gif.accumulated_time = played_time;
+        gif.start_time = None;
+        gif.looping = looping;
+        Ok(())
+    }
+
+    /// Jumps the GIF of an image back to its first frame.
+    ///
+    /// 将图像的GIF跳回第一帧。
+    pub fn reset_gif(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        self.gif_mut(id, "reset_gif")?.reset();
+        Ok(())
+    }
+
+    /// Stops playing the GIF of an image and drops its frames.
+    ///
+    /// 停止播放图像的GIF并丢弃其所有帧。
+    ///
+    /// The image keeps the first frame as a static texture. Loading a new texture through
+    /// `load_image_from_bytes`, `load_svg_texture` or a new `ImageLoadMethod` clears the GIF
+    /// automatically.
+    ///
+    /// 图像保留第一帧作为静态纹理。通过`load_image_from_bytes`、`load_svg_texture`
+    /// 或新的`ImageLoadMethod`加载新纹理时会自动清除GIF。
+    pub fn clear_gif(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        self.get_resource_mut::<Image>(id)?.gif = None;
+        Ok(())
+    }
+
+    /// Returns the GIF of an image, or an error naming the calling method if it has none.
+    ///
+    /// 返回图像的GIF，没有时返回带有调用方法名称的错误。
+    fn gif_mut(
+        &mut self,
+        id: &RustConstructorId,
+        method: &str,
+    ) -> Result<&mut GifAnimation, RustConstructorError> {
+        let image = self.get_resource_mut::<Image>(id)?;
+        if let Some(ref mut gif) = image.gif {
+            Ok(gif)
+        } else {
+            error!(
+                "[GifNotFound]{method}: Image '{}' has no GIF animation.",
+                id.name
+            );
+            Err(RustConstructorError {
+                error_id: RustConstructorError::GIF_NOT_FOUND.to_string(),
+                description: format!("Image '{}' has no GIF animation.", id.name),
+            })
+        }
+    }
+
+    /// Returns whether the texture of an image is loading, ready or failed to load.
+    ///
+    /// 返回图像的纹理是正在加载、已就绪还是加载失败。
+    pub fn image_load_state(
+        &self,
+        id: &RustConstructorId,
+    ) -> Result<ImageLoadState, RustConstructorError> {
+        let image = self.get_resource::<Image>(id)?;
+        let ImageLoadMethod::ByPath((ref path, _)) = image.image_load_method else {
+            return Ok(ImageLoadState::Ready);
+        };
+        if image.texture.as_ref().is_some_and(|x| x.path == *path) {
+            return Ok(ImageLoadState::Ready);
+        };
+        let resource_name = self.resolved_id(id)?.name;
+        Ok(
+            match self.image_loader.failed.lock().unwrap().get(&resource_name) {
+                Some((failed_path, message)) if failed_path == path => {
+                    ImageLoadState::Failed(message.clone())
+                }
+                _ => ImageLoadState::Loading,
+            },
+        )
+    }
+
+    /// Returns the pixel size of an image's current texture as [width, height].
+    ///
+    /// 返回图像当前纹理的像素尺寸，为[宽, 高]。
+    ///
+    /// Images loaded by path only have a texture once the background load finished, check
+    /// `image_load_state` first when the size is needed right after creating the image.
+    ///
+    /// 通过路径加载的图像在后台加载完成后才有纹理，创建图像后立即需要尺寸时请先检查`image_load_state`。
+    pub fn texture_size(&self, id: &RustConstructorId) -> Result<[u32; 2], RustConstructorError> {
+        let image = self.get_resource::<Image>(id)?;
+        if let Some(ref texture) = image.texture {
+            let [width, height] = texture.texture_handle.size();
+            Ok([width as u32, height as u32])
+        } else {
+            error!(
+                "[TextureNotLoaded]texture_size: Image '{}' has no texture yet.",
+                id.name
+            );
+            Err(RustConstructorError {
+                error_id: RustConstructorError::TEXTURE_NOT_LOADED.to_string(),
+                description: format!("Image '{}' has no texture yet.", id.name),
+            })
+        }
+    }
+
+    /// Reads and decodes an image file on a worker thread, the result is picked up by
+    /// `process_completed_image_loads`.
+    ///
+    /// 在工作线程中读取并解码图像文件，结果由`process_completed_image_loads`取用。
+    fn spawn_image_load(&self, resource_name: &str, path: &str, flip: [bool; 2]) {
+        let resource_name = resource_name.to_string();
+        let path = path.to_string();
+        let completed = Arc::clone(&self.image_loader.completed);
+        let failed = Arc::clone(&self.image_loader.failed);
+        failed.lock().unwrap().remove(&resource_name);
+        thread::spawn(move || {
+            let result = std::fs::read(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|bytes| Self::decode_image(&bytes, flip));
+            match result {
+                Ok(color_image) => {
+                    completed
+                        .lock()
+                        .unwrap()
+                        .insert(resource_name, LoadedImageData { path, color_image });
+                }
+                Err(e) => {
+                    warn!(
+                        "[ImageLoadFailed]spawn_image_load: Failed to load an image from the path '{path}': {e}"
+                    );
+                    failed.lock().unwrap().insert(resource_name, (path, e));
+                }
+            };
+        });
+    }
+
+    /// Decodes image bytes into an RGBA image, flipping it and shrinking it to the maximum
+    /// texture side if needed.
+    ///
+    /// 将图像字节解码为RGBA图像，并按需翻转和缩小到最大纹理边长。
+    fn decode_image(bytes: &[u8], flip: [bool; 2]) -> Result<ColorImage, String> {
+        const MAX_TEXTURE_SIDE: u32 = 8192;
+        let img = image::load_from_memory(bytes).map_err(|e| e.to_string())?;
+        let (w, h) = (img.width(), img.height());
+        let img = if w > MAX_TEXTURE_SIDE || h > MAX_TEXTURE_SIDE {
+            let scale = MAX_TEXTURE_SIDE as f64 / w.max(h) as f64;
+            let new_w = (w as f64 * scale).round() as u32;
+            let new_h = (h as f64 * scale).round() as u32;
+            img.resize(new_w, new_h, image::imageops::FilterType::Triangle)
+        } else {
+            img
+        };
+        let color_data = match flip {
+            [true, true] => img.fliph().flipv().into_rgba8(),
+            [true, false] => img.fliph().into_rgba8(),
+            [false, true] => img.flipv().into_rgba8(),
+            _ => img.into_rgba8(),
+        };
+        Ok(ColorImage::from_rgba_unmultiplied(
+            [color_data.width() as usize, color_data.height() as usize],
+            &color_data.into_raw(),
+        ))
+    }
+
+    /// Renders SVG data into an RGBA image scaled to `target_size`.
+    ///
+    /// 将SVG数据渲染为缩放到`target_size`的RGBA图像。
+    #[cfg(feature = "svg")]
+    fn rasterize_svg(data: &[u8], target_size: [u32; 2]) -> Result<ColorImage, String> {
+        use resvg::{tiny_skia, usvg};
+        let tree =
+            usvg::Tree::from_data(data, &usvg::Options::default()).map_err(|e| e.to_string())?;
+        let [width, height] = target_size.map(|x| x.max(1));
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)
+            .ok_or_else(|| format!("Invalid target size {width}x{height}."))?;
+        let size = tree.size();
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::from_scale(
+                width as f32 / size.width(),
+                height as f32 / size.height(),
+            ),
+            &mut pixmap.as_mut(),
+        );
+        Ok(ColorImage::from_rgba_premultiplied(
+            [width as usize, height as usize],
+            pixmap.data(),
+        ))
+    }
+
+    /// Draws a specific resource by its index in the rendering queue.
+    ///
+    /// 根据资源在渲染队列中的索引值绘制特定资源。
+    ///
+    /// This method handles the rendering of different resource types including:
+    /// - Images with various loading methods and transformations
+    /// - Text with formatting, selection, and hyperlink support
+    /// - Custom rectangles with borders and styling
+    ///
+    /// 此方法处理不同类型资源的渲染，包括：
+    /// - 具有各种加载方法和变换的图像
+    /// - 具有格式设置、选择和超链接支持的文本
+    /// - 具有边框和样式的自定义矩形
+    pub fn draw_resource_by_index(
+        &mut self,
+        ui: &mut Ui,
+        index: usize,
+    ) -> Result<(), RustConstructorError> {
+        if let Some(render_resource) = self.render_list.clone().get(index) {
+            self.apply_theme_colors(&render_resource.0)?;
+            match &*render_resource.0.discern_type {
+                "Image" => {
+                    let image =
+                        self.get_resource::<Image>(&build_id(&render_resource.0.name, "Image"))?;
+                    if image.display_info.enable {
                         let mut image = image.clone();
                         match image.image_load_method {
                             ImageLoadMethod::ByPath((ref path, flip)) => {
                                 if *path != image.last_frame_path {
+                                    // 纹理来源已改变，旧的GIF不再适用
+                                    image.gif = None;
                                     if let Some(texture) =
                                         image.texture_list.iter().find(|x| x.path == *path)
                                     {
                                         image.texture = Some(texture.clone())
                                     } else {
                                         image.last_frame_path = path.clone();
-                                        let resource_name = render_resource.0.name.clone();
-                                        let path_clone = path.clone();
-                                        let flip_val = flip;
-                                        let completed_arc =
-                                            Arc::clone(&self.image_loader.completed);
-                                        thread::spawn(move || {
-                                            const MAX_TEXTURE_SIDE: u32 = 8192;
-                                            match std::fs::read(&path_clone) {
-                                                Ok(bytes) => {
-                                                    if let Ok(img) = image::load_from_memory(&bytes)
-                                                    {
-                                                        let (w, h) = (img.width(), img.height());
-                                                        let img = if w > MAX_TEXTURE_SIDE
-                                                            || h > MAX_TEXTURE_SIDE
-                                                        {
-                                                            let scale = MAX_TEXTURE_SIDE as f64
-                                                                / w.max(h) as f64;
-                                                            let new_w =
-                                                                (w as f64 * scale).round() as u32;
-                                                            let new_h =
-                                                                (h as f64 * scale).round() as u32;
-                                                            img.resize(
-                                                            new_w,
-                                                            new_h,
-                                                            image::imageops::FilterType::Triangle,
-                                                        )
-                                                        } else {
-                                                            img
-                                                        };
-                                                        let color_data = match flip_val {
-                                                            [true, true] => {
-                                                                img.fliph().flipv().into_rgba8()
-                                                            }
-                                                            [true, false] => {
-                                                                img.fliph().into_rgba8()
-                                                            }
-                                                            [false, true] => {
-                                                                img.flipv().into_rgba8()
-                                                            }
-                                                            _ => img.into_rgba8(),
-                                                        };
-                                                        let color_image =
-                                                            ColorImage::from_rgba_unmultiplied(
-                                                                [
-                                                                    color_data.width() as usize,
-                                                                    color_data.height() as usize,
-                                                                ],
-                                                                &color_data.into_raw(),
-                                                            );
-                                                        completed_arc.lock().unwrap().insert(
-                                                            resource_name,
-                                                            LoadedImageData {
-                                                                path: path_clone,
-                                                                color_image,
-                                                            },
-                                                        );
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    warn!(
-                                                        "[ImageLoadFailed]draw_resource_by_index: Failed to load an image from the path '{path_clone}': {e}",
-                                                    );
-                                                }
-                                            }
-                                        });
+                                        self.spawn_image_load(&render_resource.0.name, path, flip);
+                                        // 新纹理就绪前不绘制旧纹理
+                                        image.texture = None;
                                     }
                                 } else if let Some(ref texture) = image.texture
                                     && !image.texture_list.iter().any(|x| x.path == *path)
@@ -316,7 +1389,7 @@ impl App {
                             let texture = ui.load_texture(
                                 &render_resource.0.name,
                                 loaded.color_image,
-                                TextureOptions::LINEAR,
+                                image.texture_filter.to_texture_options(),
                             );
                             image.texture = Some(DebugTextureHandle {
                                 path: loaded.path,
@@ -324,57 +1397,208 @@ impl App {
                             });
                         }
                         [image.position, image.size] = position_size_processor(
-                            image.basic_front_resource_config.position_size_config,
+                            self.responsive_position_size_config(
+                                &render_resource.0,
+                                image.basic_front_resource_config.position_size_config,
+                                ui,
+                            ),
                             ui,
                         );
-                        if !image.display_info.hidden {
-                            if let Some(clip_rect) = image.basic_front_resource_config.clip_rect {
-                                let [min, size] = position_size_processor(clip_rect, ui);
-                                ui.set_clip_rect(Rect::from_min_size(min.into(), size.into()));
+                        if let Some(position) =
+                            self.anchored_position(&render_resource.0, image.size, ui)?
+                        {
+                            image.position = position;
+                        };
+                        if let Some(hover_effect) = image.hover_effect {
+                            image.hover_progress = if image.display_info.hidden {
+                                0_f32
+                            } else {
+                                self.update_hover_progress(
+                                    ui,
+                                    &render_resource.0,
+                                    image.hover_progress,
+                                )
+                            };
+                            [image.position, image.size] = hover_effect
+                                .apply_to_rect([image.position, image.size], image.hover_progress);
+                        };
+                        // 应用入场动画，透明度只在本帧内修改
+                        let original_alpha = image.alpha;
+                        if let Some((rect, alpha_factor)) =
+                            self.entrance_progress(&render_resource.0, [image.position, image.size])
+                        {
+                            [image.position, image.size] = rect;
+                            image.alpha = (image.alpha as f32 * alpha_factor) as u8;
+                        };
+                        // 推进GIF播放并切换到当前帧的纹理
+                        if let Some(ref mut gif) = image.gif
+                            && !gif.frames.is_empty()
+                        {
+                            gif.update(self.timer.total_time);
+                            image.texture = Some(gif.frames[gif.current_frame].clone());
+                            if gif.playing && !gif.finished {
+                                self.animation_active = true;
+                                ui.ctx().request_repaint_after(Duration::from_millis(
+                                    gif.delays[gif.current_frame] as u64,
+                                ));
+                            };
+                        };
+                        // 推进精灵图播放
+                        if let Some(ref mut sprite_sheet) = image.sprite_sheet {
+                            sprite_sheet.update(self.timer.total_time);
+                            if !sprite_sheet.finished && sprite_sheet.fps > 0_f32 {
+                                self.animation_active = true;
+                                ui.ctx().request_repaint_after(Duration::from_secs_f32(
+                                    1_f32 / sprite_sheet.fps,
+                                ));
                             };
+                        };
+                        // 旋转后的图片可能超出原矩形，按对角线长度扩展剔除范围
+                        let cull_margin = if image.rotate_angle != 0_f32 {
+                            image.size[0].hypot(image.size[1])
+                        } else {
+                            0_f32
+                        };
+                        if !image.display_info.hidden
+                            && !self.is_culled(
+                                &render_resource.0,
+                                image.display_info,
+                                image.basic_front_resource_config.clip_rect,
+                                [image.position, image.size],
+                                cull_margin,
+                                ui,
+                            )
+                        {
+                            let previous_clip = self.begin_clip(
+                                &render_resource.0,
+                                image.basic_front_resource_config.clip_rect,
+                                ui,
+                            );
                             if let Some(texture) = &image.texture {
-                                let rect = Rect::from_min_size(
+                                let mut rect = Rect::from_min_size(
                                     Pos2::new(image.position[0], image.position[1]),
                                     Vec2::new(image.size[0], image.size[1]),
                                 );
 
-                                // 直接绘制图片
-                                Img::new(ImageSource::Texture((&texture.texture_handle).into()))
-                                    .tint(Color32::from_rgba_unmultiplied(
-                                        image.overlay_color[0],
-                                        image.overlay_color[1],
-                                        image.overlay_color[2],
-                                        (image.alpha as f32 * image.overlay_alpha as f32 / 255_f32)
-                                            as u8,
-                                    ))
-                                    .bg_fill(Color32::from_rgba_unmultiplied(
-                                        image.background_color[0],
-                                        image.background_color[1],
-                                        image.background_color[2],
-                                        (image.alpha as f32 * image.background_alpha as f32
-                                            / 255_f32)
-                                            as u8,
-                                    ))
-                                    .rotate(
-                                        image.rotate_angle,
+                                // 应用悬停效果的颜色
+                                let overlay_color = if let Some(hover_effect) = image.hover_effect {
+                                    hover_effect
+                                        .apply_to_color(image.overlay_color, image.hover_progress)
+                                } else {
+                                    image.overlay_color
+                                };
+
+                                // 只采样纹理图集中的指定区域
+                                let mut uv_rect =
+                                    image.uv_rect.unwrap_or([0_f32, 0_f32, 1_f32, 1_f32]);
+                                // 精灵图只采样当前帧所在的格子
+                                if let Some(sprite_sheet) = image.sprite_sheet {
+                                    uv_rect = sprite_sheet.frame_uv(uv_rect);
+                                };
+                                // 按纹理实际像素尺寸计算缩放后的绘制区域
+                                if image.nine_patch.is_none() {
+                                    let texture_size = texture.texture_handle.size();
+                                    let (position, size, scaled_uv_rect) = image.scale_mode.apply(
+                                        image.position,
+                                        image.size,
+                                        uv_rect,
                                         [
-                                            image.rotate_center[0] / image.size[0],
-                                            image.rotate_center[1] / image.size[1],
-                                        ]
-                                        .into(),
-                                    )
-                                    .paint_at(ui, rect)
-                            };
-                            if image.basic_front_resource_config.clip_rect.is_some() {
-                                ui.set_clip_rect(Rect::from_min_size(
-                                    [0_f32, 0_f32].into(),
-                                    [
-                                        ui.ctx().content_rect().width(),
-                                        ui.ctx().content_rect().height(),
-                                    ]
-                                    .into(),
-                                ));
+                                            texture_size[0] as f32 * (uv_rect[2] - uv_rect[0]),
+                                            texture_size[1] as f32 * (uv_rect[3] - uv_rect[1]),
+                                        ],
+                                    );
+                                    rect = Rect::from_min_size(position.into(), size.into());
+                                    uv_rect = scaled_uv_rect;
+                                };
+                                let uv = Rect::from_min_max(
+                                    Pos2::new(uv_rect[0], uv_rect[1]),
+                                    Pos2::new(uv_rect[2], uv_rect[3]),
+                                );
+
+                                let tint = Color32::from_rgba_unmultiplied(
+                                    overlay_color[0],
+                                    overlay_color[1],
+                                    overlay_color[2],
+                                    (image.alpha as f32 * image.overlay_alpha as f32 / 255_f32)
+                                        as u8,
+                                );
+                                let bg_fill = Color32::from_rgba_unmultiplied(
+                                    image.background_color[0],
+                                    image.background_color[1],
+                                    image.background_color[2],
+                                    (image.alpha as f32 * image.background_alpha as f32 / 255_f32)
+                                        as u8,
+                                );
+                                let rotate_origin = [
+                                    image.rotate_center[0] / image.size[0],
+                                    image.rotate_center[1] / image.size[1],
+                                ];
+
+                                if let Some(insets) = image.nine_patch {
+                                    // 九宫格模式下边角保持原尺寸，边缘和中心拉伸
+                                    ui.painter().rect_filled(rect, CornerRadius::ZERO, bg_fill);
+                                    ui.painter().add(Shape::mesh(Self::nine_patch_mesh(
+                                        texture,
+                                        rect,
+                                        uv,
+                                        insets,
+                                        tint,
+                                        image.rotate_angle,
+                                        rect.min
+                                            + Vec2::new(
+                                                image.rotate_center[0],
+                                                image.rotate_center[1],
+                                            ),
+                                    )));
+                                } else {
+                                    // 直接绘制图片
+                                    Img::new(ImageSource::Texture(
+                                        (&texture.texture_handle).into(),
+                                    ))
+                                    .uv(uv)
+                                    .tint(tint)
+                                    .bg_fill(bg_fill)
+                                    .rotate(image.rotate_angle, rotate_origin.into())
+                                    .paint_at(ui, rect);
+                                };
+
+                                // 色调无法超过原色，因此增亮时以叠加方式再绘制一次
+                                if let Some(HoverEffect::Brightness(brightness)) =
+                                    image.hover_effect
+                                    && brightness > 1_f32
+                                {
+                                    let add =
+                                        ((brightness - 1_f32) * image.hover_progress * 255_f32)
+                                            .clamp(0_f32, 255_f32)
+                                            as u8;
+                                    let add_color =
+                                        Color32::from_rgba_premultiplied(add, add, add, 0);
+                                    if let Some(insets) = image.nine_patch {
+                                        ui.painter().add(Shape::mesh(Self::nine_patch_mesh(
+                                            texture,
+                                            rect,
+                                            uv,
+                                            insets,
+                                            add_color,
+                                            image.rotate_angle,
+                                            rect.min
+                                                + Vec2::new(
+                                                    image.rotate_center[0],
+                                                    image.rotate_center[1],
+                                                ),
+                                        )));
+                                    } else {
+                                        Img::new(ImageSource::Texture(
+                                            (&texture.texture_handle).into(),
+                                        ))
+                                        .tint(add_color)
+                                        .uv(uv)
+                                        .rotate(image.rotate_angle, rotate_origin.into())
+                                        .paint_at(ui, rect);
+                                    };
+                                };
                             };
+                            ui.set_clip_rect(previous_clip);
                         };
                         match image.image_load_method {
                             ImageLoadMethod::ByPath((ref path, _)) => {
@@ -382,6 +1606,7 @@ impl App {
                             }
                             ImageLoadMethod::ByTexture(_) => {}
                         };
+                        image.alpha = original_alpha;
                         self.replace_resource(&render_resource.0.name, image)?;
                     };
                 }
@@ -390,82 +1615,416 @@ impl App {
                         self.get_resource::<Text>(&build_id(&render_resource.0.name, "Text"))?;
                     if text.display_info.enable {
                         let mut text = text.clone();
-                        [_, text.truncate_size] = position_size_processor(
+                        let position_size_config = self.responsive_position_size_config(
+                            &render_resource.0,
                             text.basic_front_resource_config.position_size_config,
                             ui,
                         );
-                        let display_content = if text.content.is_empty()
-                            || text
+                        [_, text.truncate_size] = position_size_processor(position_size_config, ui);
+                        let layout_key = TextLayoutKey {
+                            content: text.content.clone(),
+                            font: text.font.clone(),
+                            font_size: text.font_size,
+                            color: text.color,
+                            alpha: text.alpha,
+                            bold: text.bold,
+                            italic: text.italic,
+                            origin_size: text
                                 .basic_front_resource_config
                                 .position_size_config
-                                .origin_size
-                                .contains(&0_f32)
-                        {
-                            "".to_string()
-                        } else {
-                            let original_galley = ui.fonts_mut(|f| {
-                                f.layout(
-                                    text.content.to_string(),
-                                    FontId::proportional(text.font_size),
-                                    Color32::default(),
-                                    text.truncate_size[0],
-                                )
-                            });
-
-                            let mut truncated = text.content.to_string();
-                            let mut ellipsis = "";
-                            if original_galley.size().y > text.truncate_size[1] {
-                                // 如果超出，逐步缩短文本直到加上省略号后能放下
-                                ellipsis = "...";
-
-                                while !truncated.is_empty() {
-                                    let test_text = format!("{}{}", truncated, ellipsis);
-                                    let test_galley = ui.fonts_mut(|f| {
-                                        f.layout(
-                                            test_text,
-                                            FontId::proportional(text.font_size),
-                                            Color32::default(),
-                                            text.truncate_size[0],
-                                        )
-                                    });
-
-                                    if test_galley.size().y <= text.truncate_size[1] {
-                                        break;
-                                    }
-
-                                    // 移除最后一个字符
-                                    truncated.pop();
-                                }
-                            };
-                            format!("{}{}", truncated, ellipsis)
+                                .origin_size,
+                            truncate_size: text.truncate_size,
+                            truncate_config: text.truncate_config.clone(),
+                            collapsible: text.collapsible.clone(),
+                            expanded: text.expanded,
+                            marquee: text.marquee.is_some(),
+                            styled_segments: text.styled_segments.clone(),
+                            text_align: text.text_align,
+                            window_size: [
+                                ui.ctx().content_rect().width(),
+                                ui.ctx().content_rect().height(),
+                            ],
+                            pixels_per_point: ui.ctx().pixels_per_point(),
+                            loaded_font_count: self.loaded_fonts.len(),
+                            font_fallback: self.font_fallback.clone(),
                         };
-                        // 计算文本大小
-                        let galley: Arc<Galley> = ui.fonts_mut(|f| {
-                            f.layout(
-                                display_content.to_string(),
-                                if !text.font.is_empty() {
-                                    if self.loaded_fonts.iter().any(|x| x[0] == text.font) {
-                                        FontId::new(
-                                            text.font_size,
-                                            FontFamily::Name(text.font.clone().into()),
-                                        )
+                        // 排版输入与上一帧相同时直接复用缓存的排版结果
+                        let cached_layout = self
+                            .text_layout_cache
+                            .iter()
+                            .find(|x| x.target == render_resource.0 && x.key == layout_key)
+                            .cloned();
+                        let (collapsible_label, display_content, galley, bold_galley) =
+                            if let Some(cache) = cached_layout {
+                                (
+                                    cache.collapsible_label,
+                                    cache.display_content,
+                                    cache.galley,
+                                    cache.bold_galley,
+                                )
+                            } else {
+                                let font_id = |font: &str, font_size: f32| {
+                                    if !font.is_empty()
+                                        && self.loaded_fonts.iter().any(|x| x[0] == font)
+                                    {
+                                        FontId::new(font_size, FontFamily::Name(font.into()))
                                     } else {
-                                        FontId::proportional(text.font_size)
+                                        FontId::proportional(font_size)
+                                    }
+                                };
+                                // 查找已注册的粗体/斜体字体变体，找不到时返回需要仿粗体/仿斜体的标记
+                                let font_variant = |font: &str, bold: bool, italic: bool| {
+                                    let suffix = match (bold, italic) {
+                                        (true, true) => "-BoldItalic",
+                                        (true, false) => "-Bold",
+                                        (false, true) => "-Italic",
+                                        (false, false) => {
+                                            return (font.to_string(), false, false, None);
+                                        }
+                                    };
+                                    let variant = format!("{font}{suffix}");
+                                    if self.loaded_fonts.iter().any(|x| x[0] == variant) {
+                                        (variant, false, false, None)
+                                    } else {
+                                        (
+                                            font.to_string(),
+                                            bold,
+                                            italic,
+                                            (!font.is_empty()).then_some(variant),
+                                        )
+                                    }
+                                };
+                                let (
+                                    base_font,
+                                    base_faux_bold,
+                                    base_faux_italic,
+                                    base_missing_variant,
+                                ) = font_variant(&text.font, text.bold, text.italic);
+                                let base_format = TextFormat {
+                                    font_id: font_id(&base_font, text.font_size),
+                                    italics: base_faux_italic,
+                                    color: Color32::from_rgba_unmultiplied(
+                                        text.color[0],
+                                        text.color[1],
+                                        text.color[2],
+                                        text.alpha,
+                                    ),
+                                    ..Default::default()
+                                };
+                                // 构建绘制用的排版任务，折叠和截断时也用它测量以保证结果一致，
+                                // 返回排版任务、仿粗体叠加层的排版任务、缺失的字体变体和是否使用了仿粗体。
+                                // 分段范围以原文的字符索引表示，通过display_source映射到显示文本
+                                let build_jobs =
+                                    |display_content: &str, display_source: &[Option<usize>]| {
+                                        let display_segments: Vec<([usize; 2], &TextStyle)> = text
+                                            .styled_segments
+                                            .iter()
+                                            .flat_map(|(range, style)| {
+                                                Self::display_segment_ranges(display_source, *range)
+                                                    .into_iter()
+                                                    .map(move |x| (x, style))
+                                            })
+                                            .collect();
+                                        let mut missing_variants = Vec::new();
+                                        let mut faux_bold_used = false;
+                                        // 仿粗体叠加层的排版，只有需要仿粗体的部分可见
+                                        let mut bold_job = LayoutJob::default();
+                                        let mut job = LayoutJob::default();
+                                        if display_segments.is_empty() {
+                                            missing_variants.extend(base_missing_variant.clone());
+                                            faux_bold_used = base_faux_bold;
+                                            bold_job.append(
+                                                display_content,
+                                                0_f32,
+                                                TextFormat {
+                                                    color: if base_faux_bold {
+                                                        base_format.color
+                                                    } else {
+                                                        Color32::TRANSPARENT
+                                                    },
+                                                    ..base_format.clone()
+                                                },
+                                            );
+                                            job.append(display_content, 0_f32, base_format.clone());
+                                        } else {
+                                            // 按分段边界切分文本，越界的分段会被截断
+                                            let chars: Vec<char> =
+                                                display_content.chars().collect();
+                                            let mut boundaries = vec![0, chars.len()];
+                                            for ([start, end], _) in &display_segments {
+                                                boundaries.push((*start).min(chars.len()));
+                                                boundaries.push((*end).min(chars.len()));
+                                            }
+                                            boundaries.sort_unstable();
+                                            boundaries.dedup();
+                                            for piece in boundaries.windows(2) {
+                                                let mut format = base_format.clone();
+                                                let mut font = text.font.clone();
+                                                let mut bold = text.bold;
+                                                let mut italic = text.italic;
+                                                for ([start, end], style) in &display_segments {
+                                                    if *start > piece[0] || *end < piece[1] {
+                                                        continue;
+                                                    };
+                                                    if let Some(color) = style.color {
+                                                        format.color =
+                                                            Color32::from_rgba_unmultiplied(
+                                                                color[0],
+                                                                color[1],
+                                                                color[2],
+                                                                (color[3] as f32
+                                                                    * text.alpha as f32
+                                                                    / 255_f32)
+                                                                    as u8,
+                                                            );
+                                                    };
+                                                    if let Some(font_size) = style.font_size {
+                                                        format.font_id.size = font_size;
+                                                    };
+                                                    if let Some(ref segment_font) = style.font {
+                                                        font = segment_font.clone();
+                                                    };
+                                                    bold |= style.bold;
+                                                    italic |= style.italic;
+                                                    if style.underline {
+                                                        format.underline = Stroke::new(
+                                                            (text.font_size / 14_f32).max(1_f32),
+                                                            format.color,
+                                                        );
+                                                    };
+                                                    if style.strikethrough {
+                                                        format.strikethrough = Stroke::new(
+                                                            (text.font_size / 14_f32).max(1_f32),
+                                                            format.color,
+                                                        );
+                                                    };
+                                                }
+                                                let (
+                                                    variant,
+                                                    faux_bold,
+                                                    faux_italic,
+                                                    missing_variant,
+                                                ) = font_variant(&font, bold, italic);
+                                                format.font_id =
+                                                    font_id(&variant, format.font_id.size);
+                                                format.italics = faux_italic;
+                                                missing_variants.extend(missing_variant);
+                                                faux_bold_used |= faux_bold;
+                                                let piece_content = chars[piece[0]..piece[1]]
+                                                    .iter()
+                                                    .collect::<String>();
+                                                bold_job.append(
+                                                    &piece_content,
+                                                    0_f32,
+                                                    TextFormat {
+                                                        color: if faux_bold {
+                                                            format.color
+                                                        } else {
+                                                            Color32::TRANSPARENT
+                                                        },
+                                                        underline: Stroke::NONE,
+                                                        strikethrough: Stroke::NONE,
+                                                        ..format.clone()
+                                                    },
+                                                );
+                                                job.append(&piece_content, 0_f32, format);
+                                            }
+                                        };
+                                        // 跑马灯文本保持单行，超出的部分通过滚动显示
+                                        job.wrap.max_width = if text.marquee.is_some() {
+                                            f32::INFINITY
+                                        } else {
+                                            text.truncate_size[0]
+                                        };
+                                        job.halign = match text.text_align {
+                                            TextAlign::Left => Align::LEFT,
+                                            TextAlign::Center => Align::Center,
+                                            TextAlign::Right => Align::RIGHT,
+                                        };
+                                        bold_job.wrap = job.wrap.clone();
+                                        bold_job.halign = job.halign;
+                                        (job, bold_job, missing_variants, faux_bold_used)
+                                    };
+                                let layout = |content: &str, source: &[Option<usize>]| {
+                                    ui.fonts_mut(|f| f.layout_job(build_jobs(content, source).0))
+                                };
+                                // 处理可折叠文本
+                                let mut collapsible_label = None;
+                                // 折叠后仍保留的原文字符数，其余字符为插入的标签
+                                let mut kept_chars = usize::MAX;
+                                let content = if let Some(ref collapsible) = text.collapsible
+                                    && !text.content.is_empty()
+                                    && text.truncate_size[0] > 0_f32
+                                {
+                                    let full_source: Vec<Option<usize>> =
+                                        (0..text.content.chars().count()).map(Some).collect();
+                                    // 折叠后的文本由保留的字符、"... "和标签组成
+                                    let collapsed_source = |keep: usize| -> Vec<Option<usize>> {
+                                        (0..keep)
+                                            .map(Some)
+                                            .chain(std::iter::repeat_n(
+                                                None,
+                                                4 + collapsible.more_label.chars().count(),
+                                            ))
+                                            .collect()
+                                    };
+                                    if layout(&text.content, &full_source).rows.len()
+                                        <= collapsible.collapsed_lines
+                                    {
+                                        text.content.clone()
+                                    } else if text.expanded {
+                                        collapsible_label = Some(collapsible.less_label.clone());
+                                        format!("{} {}", text.content, collapsible.less_label)
+                                    } else {
+                                        collapsible_label = Some(collapsible.more_label.clone());
+                                        let chars: Vec<char> = text.content.chars().collect();
+                                        let mut keep = chars.len();
+                                        // 逐步缩短文本直到加上标签后不超过折叠行数
+                                        while keep > 0
+                                            && layout(
+                                                &format!(
+                                                    "{}... {}",
+                                                    chars[..keep].iter().collect::<String>(),
+                                                    collapsible.more_label
+                                                ),
+                                                &collapsed_source(keep),
+                                            )
+                                            .rows
+                                            .len()
+                                                > collapsible.collapsed_lines
+                                        {
+                                            keep -= 1;
+                                        }
+                                        kept_chars = keep;
+                                        format!(
+                                            "{}... {}",
+                                            chars[..keep].iter().collect::<String>(),
+                                            collapsible.more_label
+                                        )
                                     }
                                 } else {
-                                    FontId::proportional(text.font_size)
-                                },
-                                Color32::from_rgba_unmultiplied(
-                                    text.color[0],
-                                    text.color[1],
-                                    text.color[2],
-                                    text.alpha,
-                                ),
-                                text.truncate_size[0],
-                            )
-                        });
+                                    text.content.clone()
+                                };
+                                let content_source: Vec<Option<usize>> =
+                                    (0..content.chars().count())
+                                        .map(|x| (x < kept_chars).then_some(x))
+                                        .collect();
+                                let ellipsis_length = text.truncate_config.ellipsis.chars().count();
+                                let mut truncated_keep = None;
+                                let display_content = if content.is_empty()
+                                    || position_size_config.origin_size.contains(&0_f32)
+                                {
+                                    "".to_string()
+                                } else {
+                                    let original_galley = layout(&content, &content_source);
+
+                                    let chars: Vec<char> = content.chars().collect();
+                                    // 按截断方式保留指定数量的字符并插入省略号
+                                    let build_truncated = |keep: usize| -> String {
+                                        let ellipsis = &text.truncate_config.ellipsis;
+                                        match text.truncate_config.mode {
+                                            TruncateMode::None => content.to_string(),
+                                            TruncateMode::Start => format!(
+                                                "{}{}",
+                                                ellipsis,
+                                                chars[chars.len() - keep..]
+                                                    .iter()
+                                                    .collect::<String>()
+                                            ),
+                                            TruncateMode::Middle => {
+                                                let head = keep.div_ceil(2);
+                                                let tail = keep / 2;
+                                                format!(
+                                                    "{}{}{}",
+                                                    chars[..head].iter().collect::<String>(),
+                                                    ellipsis,
+                                                    chars[chars.len() - tail..]
+                                                        .iter()
+                                                        .collect::<String>()
+                                                )
+                                            }
+                                            TruncateMode::End => format!(
+                                                "{}{}",
+                                                chars[..keep].iter().collect::<String>(),
+                                                ellipsis
+                                            ),
+                                        }
+                                    };
+                                    if text.marquee.is_none()
+                                        && text.truncate_config.mode != TruncateMode::None
+                                        && original_galley.size().y > text.truncate_size[1]
+                                    {
+                                        // 如果超出，二分查找加上省略号后能放下的最多字符数
+                                        let fits = |keep: usize| {
+                                            layout(
+                                                &build_truncated(keep),
+                                                &Self::truncated_source(
+                                                    &content_source,
+                                                    ellipsis_length,
+                                                    text.truncate_config.mode,
+                                                    keep,
+                                                ),
+                                            )
+                                            .size()
+                                            .y <= text.truncate_size[1]
+                                        };
+                                        let [mut low, mut high] = [0, chars.len()];
+                                        while low < high {
+                                            let middle = (low + high).div_ceil(2);
+                                            if fits(middle) {
+                                                low = middle;
+                                            } else {
+                                                high = middle - 1;
+                                            };
+                                        }
+                                        truncated_keep = Some(low);
+                                        build_truncated(low)
+                                    } else {
+                                        content.to_string()
+                                    }
+                                };
+                                let display_source = match truncated_keep {
+                                    Some(keep) => Self::truncated_source(
+                                        &content_source,
+                                        ellipsis_length,
+                                        text.truncate_config.mode,
+                                        keep,
+                                    ),
+                                    None if display_content.is_empty() => Vec::new(),
+                                    None => content_source,
+                                };
+                                // 计算文本大小
+                                let (job, bold_job, missing_variants, faux_bold_used) =
+                                    build_jobs(&display_content, &display_source);
+                                let bold_galley: Option<Arc<Galley>> = if faux_bold_used {
+                                    Some(ui.fonts_mut(|f| f.layout_job(bold_job)))
+                                } else {
+                                    None
+                                };
+                                let galley: Arc<Galley> = ui.fonts_mut(|f| f.layout_job(job));
+                                for variant in missing_variants {
+                                    if !self.missing_font_variants.contains(&variant) {
+                                        warn!(
+                                            "Font variant '{variant}' is not registered, a faux style is used instead."
+                                        );
+                                        self.missing_font_variants.push(variant);
+                                    };
+                                }
+                                self.text_layout_cache
+                                    .retain(|x| x.target != render_resource.0);
+                                self.text_layout_cache.push(TextLayoutCache {
+                                    target: render_resource.0.clone(),
+                                    key: layout_key,
+                                    display_content: display_content.clone(),
+                                    collapsible_label: collapsible_label.clone(),
+                                    galley: galley.clone(),
+                                    bold_galley: bold_galley.clone(),
+                                });
+                                (collapsible_label, display_content, galley, bold_galley)
+                            };
+                        // 跑马灯文本的宽度始终为配置的文本框宽度，超出部分通过滚动显示
                         text.size = [
-                            if text.auto_fit[0] {
+                            if text.auto_fit[0] && text.marquee.is_none() {
                                 galley.size().x
                             } else {
                                 text.truncate_size[0]
@@ -478,13 +2037,27 @@ impl App {
                         ];
                         text.actual_size = [galley.size().x, galley.size().y];
                         [text.position, _] = position_size_processor(
-                            text.basic_front_resource_config
-                                .position_size_config
+                            position_size_config
                                 .x_size_grid(0_f32, 0_f32)
                                 .y_size_grid(0_f32, 0_f32)
                                 .origin_size(text.size[0], text.size[1]),
                             ui,
                         );
+                        if let Some(position) =
+                            self.anchored_position(&render_resource.0, text.size, ui)?
+                        {
+                            text.position = position;
+                        };
+                        // 居中或右对齐时各行相对于对齐线排列，计算字形的绘制原点
+                        let mut galley_position = [
+                            text.position[0]
+                                + match text.text_align {
+                                    TextAlign::Left => 0_f32,
+                                    TextAlign::Center => text.size[0] / 2_f32,
+                                    TextAlign::Right => text.size[0],
+                                },
+                            text.position[1],
+                        ];
                         // 查找超链接索引值
                         if text.last_frame_content != display_content {
                             text.hyperlink_index.clear();
@@ -532,15 +2105,84 @@ impl App {
                                     }
                                 };
                             }
+                            // 将折叠标签标记为超链接样式
+                            if let Some(ref label) = collapsible_label
+                                && display_content.ends_with(label.as_str())
+                            {
+                                let end = display_content.chars().count();
+                                text.hyperlink_index.push((
+                                    end - label.chars().count(),
+                                    end,
+                                    String::new(),
+                                ));
+                            };
+                        };
+                        // 查找搜索高亮的匹配范围
+                        text.highlight_index = match text.highlight {
+                            Some(ref highlight) => highlight.find_matches(&display_content),
+                            None => Vec::new(),
+                        };
+                        // 文本宽于文本框时滚动显示，并在末尾之后重复绘制一份以无缝衔接
+                        let mut marquee_shifts = vec![0_f32];
+                        if let Some(marquee) = text.marquee
+                            && galley.size().x > text.size[0]
+                        {
+                            let period = galley.size().x + marquee.gap.max(0_f32);
+                            let paused = marquee.pause_on_hover
+                                && if let Some(index) =
+                                    self.get_render_layer_resource(&render_resource.0)
+                                    && let Some(mouse_pos) = ui.input(|i| i.pointer.hover_pos())
+                                {
+                                    self.resource_get_focus(index, mouse_pos.into(), true, vec![])
+                                } else {
+                                    false
+                                };
+                            if !paused {
+                                text.marquee_offset = (text.marquee_offset
+                                    + marquee.speed * ui.input(|i| i.stable_dt))
+                                .rem_euclid(period);
+                            };
+                            galley_position[0] =
+                                text.position[0] - galley.rect.min.x - text.marquee_offset;
+                            marquee_shifts.push(period);
+                            self.animation_active = true;
+                            ui.ctx().request_repaint();
+                        } else {
+                            text.marquee_offset = 0_f32;
+                        };
+                        // 使用绝对定位放置文本
+                        let rect = if marquee_shifts.len() > 1 {
+                            Rect::from_min_size(
+                                text.position.into(),
+                                Vec2::new(text.size[0], galley.size().y),
+                            )
+                        } else {
+                            galley
+                                .rect
+                                .translate(Vec2::new(galley_position[0], galley_position[1]))
                         };
-                        if !text.display_info.hidden {
-                            // 使用绝对定位放置文本
-                            let rect =
-                                Rect::from_min_size(text.position.into(), text.actual_size.into());
+                        if !text.display_info.hidden
+                            && !self.is_culled(
+                                &render_resource.0,
+                                text.display_info,
+                                text.basic_front_resource_config.clip_rect,
+                                [rect.min.into(), rect.size().into()],
+                                text.background_shadow.map_or(0_f32, |x| x.extent()),
+                                ui,
+                            )
+                        {
+                            if let Some(shadow) = text.background_shadow {
+                                ui.painter().add(
+                                    shadow.to_shadow(text.alpha).as_shape(
+                                        rect,
+                                        text.background_rounding.to_corner_radius(),
+                                    ),
+                                );
+                            };
                             // 绘制背景颜色
                             ui.painter().rect_filled(
                                 rect,
-                                text.background_rounding,
+                                text.background_rounding.to_corner_radius(),
                                 Color32::from_rgba_unmultiplied(
                                     text.background_color[0],
                                     text.background_color[1],
@@ -549,22 +2191,168 @@ impl App {
                                 ),
                             );
 
-                            if let Some(clip_rect) = text.basic_front_resource_config.clip_rect {
-                                let [min, size] = position_size_processor(clip_rect, ui);
-                                ui.set_clip_rect(Rect::from_min_size(min.into(), size.into()));
+                            let previous_clip = self.begin_clip(
+                                &render_resource.0,
+                                text.basic_front_resource_config.clip_rect,
+                                ui,
+                            );
+                            // 滚动的文本只在文本框内可见
+                            if marquee_shifts.len() > 1 {
+                                ui.set_clip_rect(ui.clip_rect().intersect(rect));
                             };
 
-                            // 绘制文本
-                            ui.painter().galley(
-                                text.position.into(),
-                                galley.clone(),
+                            // 在文本下方绘制搜索高亮
+                            if let Some(ref highlight) = text.highlight {
+                                let color = Color32::from_rgba_unmultiplied(
+                                    highlight.color[0],
+                                    highlight.color[1],
+                                    highlight.color[2],
+                                    (highlight.color[3] as f32 * text.alpha as f32 / 255_f32) as u8,
+                                );
+                                for (start, end) in &text.highlight_index {
+                                    let start_rect = galley.pos_from_cursor(CCursor::new(*start));
+                                    let end_rect = galley.pos_from_cursor(CCursor::new(*end));
+                                    // 匹配可能因换行跨越多行，逐行绘制
+                                    for row in &galley.rows {
+                                        let row_rect = row.rect();
+                                        if row_rect.max.y <= start_rect.min.y
+                                            || row_rect.min.y >= end_rect.max.y
+                                        {
+                                            continue;
+                                        };
+                                        let left = if row_rect.min.y <= start_rect.min.y {
+                                            start_rect.min.x
+                                        } else {
+                                            row_rect.min.x
+                                        };
+                                        let right = if row_rect.max.y >= end_rect.max.y {
+                                            end_rect.min.x
+                                        } else {
+                                            row_rect.max.x
+                                        };
+                                        ui.painter().rect_filled(
+                                            Rect::from_min_max(
+                                                Pos2::new(
+                                                    galley_position[0] + left,
+                                                    galley_position[1] + row_rect.min.y,
+                                                ),
+                                                Pos2::new(
+                                                    galley_position[0] + right,
+                                                    galley_position[1] + row_rect.max.y,
+                                                ),
+                                            ),
+                                            0.0,
+                                            color,
+                                        );
+                                    }
+                                }
+                            };
+
+                            // 按文本透明度绘制的字形颜色
+                            let glyph_color = |color: [u8; 4]| {
                                 Color32::from_rgba_unmultiplied(
-                                    text.color[0],
-                                    text.color[1],
-                                    text.color[2],
-                                    text.alpha,
-                                ),
-                            );
+                                    color[0],
+                                    color[1],
+                                    color[2],
+                                    (color[3] as f32 * text.alpha as f32 / 255_f32) as u8,
+                                )
+                            };
+                            for shift in &marquee_shifts {
+                                let galley_position =
+                                    [galley_position[0] + shift, galley_position[1]];
+                                if let Some((color, offset)) = text.glyph_shadow {
+                                    ui.painter().galley_with_override_text_color(
+                                        Pos2::new(
+                                            galley_position[0] + offset[0],
+                                            galley_position[1] + offset[1],
+                                        ),
+                                        galley.clone(),
+                                        glyph_color(color),
+                                    );
+                                };
+                                // 在八个方向上偏移绘制以形成描边
+                                if let Some((color, width)) = text.outline
+                                    && width > 0_f32
+                                {
+                                    for [x, y] in [
+                                        [-1_f32, -1_f32],
+                                        [0_f32, -1_f32],
+                                        [1_f32, -1_f32],
+                                        [-1_f32, 0_f32],
+                                        [1_f32, 0_f32],
+                                        [-1_f32, 1_f32],
+                                        [0_f32, 1_f32],
+                                        [1_f32, 1_f32],
+                                    ] {
+                                        ui.painter().galley_with_override_text_color(
+                                            Pos2::new(
+                                                galley_position[0] + x * width,
+                                                galley_position[1] + y * width,
+                                            ),
+                                            galley.clone(),
+                                            glyph_color(color),
+                                        );
+                                    }
+                                };
+
+                                // 绘制文本
+                                ui.painter().galley(
+                                    galley_position.into(),
+                                    galley.clone(),
+                                    Color32::from_rgba_unmultiplied(
+                                        text.color[0],
+                                        text.color[1],
+                                        text.color[2],
+                                        text.alpha,
+                                    ),
+                                );
+                                // 稍微偏移再绘制一次以形成仿粗体
+                                if let Some(bold_galley) = &bold_galley {
+                                    ui.painter().galley(
+                                        Pos2::new(
+                                            galley_position[0] + (text.font_size / 20_f32).max(0.5),
+                                            galley_position[1],
+                                        ),
+                                        bold_galley.clone(),
+                                        Color32::TRANSPARENT,
+                                    );
+                                };
+                            }
+
+                            // 绘制文本输入框的闪烁光标
+                            if let Some(caret) = text.caret {
+                                let period = text.caret_blink_period as f64;
+                                if period <= 0_f64
+                                    || (ui.input(|i| i.time) - text.caret_blink_start) % period
+                                        < period / 2_f64
+                                {
+                                    let caret_rect = galley.pos_from_cursor(CCursor::new(
+                                        caret.min(display_content.chars().count()),
+                                    ));
+                                    ui.painter().line_segment(
+                                        [
+                                            Pos2::new(
+                                                galley_position[0] + caret_rect.min.x,
+                                                galley_position[1] + caret_rect.min.y,
+                                            ),
+                                            Pos2::new(
+                                                galley_position[0] + caret_rect.min.x,
+                                                galley_position[1] + caret_rect.max.y,
+                                            ),
+                                        ],
+                                        Stroke::new(
+                                            (text.font_size / 12_f32).max(1_f32),
+                                            Color32::from_rgba_unmultiplied(
+                                                text.color[0],
+                                                text.color[1],
+                                                text.color[2],
+                                                text.alpha,
+                                            ),
+                                        ),
+                                    );
+                                };
+                                ui.ctx().request_repaint_after(Duration::from_millis(100));
+                            };
 
                             // 绘制超链接
                             for (start, end, _) in &text.hyperlink_index {
@@ -578,7 +2366,7 @@ impl App {
                                 // 检查超链接是否跨行
                                 if start_cursor.min.y == end_cursor.min.y {
                                     // 单行超链接
-                                    let underline_y = text.position[1]
+                                    let underline_y = galley_position[1]
                                         + start_pos.y
                                         + galley.rows.first().map_or(14.0, |row| row.height())
                                         - 2.0;
@@ -593,8 +2381,11 @@ impl App {
 
                                     ui.painter().line_segment(
                                         [
-                                            Pos2::new(text.position[0] + start_pos.x, underline_y),
-                                            Pos2::new(text.position[0] + end_pos.x, underline_y),
+                                            Pos2::new(
+                                                galley_position[0] + start_pos.x,
+                                                underline_y,
+                                            ),
+                                            Pos2::new(galley_position[0] + end_pos.x, underline_y),
                                         ],
                                         Stroke::new(text.font_size / 10_f32, color),
                                     );
@@ -608,9 +2399,10 @@ impl App {
                                     let end_row = (end_pos.y / row_height).round() as usize;
 
                                     for row in start_row..=end_row {
-                                        let row_y =
-                                            text.position[1] + row as f32 * row_height + row_height
-                                                - 2.0; // 行底部稍微上移一点绘制下划线
+                                        let row_y = galley_position[1]
+                                            + row as f32 * row_height
+                                            + row_height
+                                            - 2.0; // 行底部稍微上移一点绘制下划线
 
                                         // 获取当前行的矩形范围
                                         if let Some(current_row) = galley.rows.get(row) {
@@ -628,11 +2420,11 @@ impl App {
                                                 ui.painter().line_segment(
                                                     [
                                                         Pos2::new(
-                                                            text.position[0] + start_pos.x,
+                                                            galley_position[0] + start_pos.x,
                                                             row_y,
                                                         ),
                                                         Pos2::new(
-                                                            text.position[0] + row_rect.max.x,
+                                                            galley_position[0] + row_rect.max.x,
                                                             row_y,
                                                         ),
                                                     ],
@@ -643,11 +2435,11 @@ impl App {
                                                 ui.painter().line_segment(
                                                     [
                                                         Pos2::new(
-                                                            text.position[0] + row_rect.min.x,
+                                                            galley_position[0] + row_rect.min.x,
                                                             row_y,
                                                         ),
                                                         Pos2::new(
-                                                            text.position[0] + end_pos.x,
+                                                            galley_position[0] + end_pos.x,
                                                             row_y,
                                                         ),
                                                     ],
@@ -658,11 +2450,11 @@ impl App {
                                                 ui.painter().line_segment(
                                                     [
                                                         Pos2::new(
-                                                            text.position[0] + row_rect.min.x,
+                                                            galley_position[0] + row_rect.min.x,
                                                             row_y,
                                                         ),
                                                         Pos2::new(
-                                                            text.position[0] + row_rect.max.x,
+                                                            galley_position[0] + row_rect.max.x,
                                                             row_y,
                                                         ),
                                                     ],
@@ -677,16 +2469,15 @@ impl App {
                             if text.selectable {
                                 // 处理选择逻辑
                                 let cursor_at_pointer = |pointer_pos: Vec2| -> usize {
-                                    let relative_pos = pointer_pos - text.position.into();
+                                    let relative_pos = pointer_pos - galley_position.into();
                                     let cursor = galley.cursor_from_pos(relative_pos);
                                     cursor.index.into()
                                 };
 
                                 let fullscreen_detect_result = ui.input(|i| i.pointer.clone());
-                                let rect = Rect::from_min_size(
-                                    text.position.into(),
-                                    text.actual_size.into(),
-                                );
+                                let rect = galley
+                                    .rect
+                                    .translate(Vec2::new(galley_position[0], galley_position[1]));
                                 let detect_result = ui.interact(
                                     rect,
                                     Id::new(&render_resource.0.name),
@@ -752,7 +2543,19 @@ impl App {
                                     if start <= chars.len() && end <= chars.len() && start < end {
                                         let selected_text: String =
                                             chars[start..end].iter().collect();
-                                        ui.copy_text(selected_text);
+                                        ui.copy_text(selected_text.clone());
+                                        if let Some((_, hook)) = self
+                                            .copy_hooks
+                                            .iter()
+                                            .find(|x| x.0 == render_resource.0)
+                                        {
+                                            hook(&selected_text);
+                                        };
+                                        self.last_copied = Some((
+                                            render_resource.0.clone(),
+                                            [start, end],
+                                            selected_text,
+                                        ));
                                     };
                                 };
 
@@ -786,12 +2589,12 @@ impl App {
 
                                             let selection_rect = Rect::from_min_max(
                                                 Pos2::new(
-                                                    text.position[0] + start_pos.x,
-                                                    text.position[1] + start_pos.y,
+                                                    galley_position[0] + start_pos.x,
+                                                    galley_position[1] + start_pos.y,
                                                 ),
                                                 Pos2::new(
-                                                    text.position[0] + end_pos.x,
-                                                    text.position[1] + start_pos.y + row_height,
+                                                    galley_position[0] + end_pos.x,
+                                                    galley_position[1] + start_pos.y + row_height,
                                                 ),
                                             );
                                             ui.painter().rect_filled(
@@ -811,9 +2614,9 @@ impl App {
 
                                             // 计算选择的上下边界
                                             let selection_top =
-                                                text.position[1] + start_pos.y.min(end_pos.y);
+                                                galley_position[1] + start_pos.y.min(end_pos.y);
                                             let selection_bottom =
-                                                text.position[1] + start_pos.y.max(end_pos.y);
+                                                galley_position[1] + start_pos.y.max(end_pos.y);
 
                                             // 确定起始行和结束行的索引
                                             let start_row_index =
@@ -829,7 +2632,7 @@ impl App {
 
                                             for (i, row) in rows.iter().enumerate() {
                                                 let row_y =
-                                                    text.position[1] + row_height * i as f32;
+                                                    galley_position[1] + row_height * i as f32;
                                                 let row_bottom = row_y + row_height;
                                                 // 检查当前行是否与选择区域相交
                                                 if row_bottom > selection_top
@@ -837,18 +2640,18 @@ impl App {
                                                 {
                                                     let left = if i == first_row_index {
                                                         // 首行 - 从选择开始位置开始
-                                                        text.position[0] + start_pos.x
+                                                        galley_position[0] + start_pos.x
                                                     } else {
                                                         // 非首行 - 从行首开始
-                                                        text.position[0] + row.rect().min.x
+                                                        galley_position[0] + row.rect().min.x
                                                     };
 
                                                     let right = if i == last_row_index {
                                                         // 尾行 - 到选择结束位置结束
-                                                        text.position[0] + end_pos.x
+                                                        galley_position[0] + end_pos.x
                                                     } else {
                                                         // 非尾行 - 到行尾结束
-                                                        text.position[0] + row.rect().max.x
+                                                        galley_position[0] + row.rect().max.x
                                                     };
 
                                                     let selection_rect = Rect::from_min_max(
@@ -892,12 +2695,12 @@ impl App {
                                     // 单行超链接
                                     let link_rect = Rect::from_min_max(
                                         Pos2::new(
-                                            text.position[0] + start_pos.x,
-                                            text.position[1] + start_pos.y,
+                                            galley_position[0] + start_pos.x,
+                                            galley_position[1] + start_pos.y,
                                         ),
                                         Pos2::new(
-                                            text.position[0] + end_pos.x,
-                                            text.position[1] + start_pos.y + row_height,
+                                            galley_position[0] + end_pos.x,
+                                            galley_position[1] + start_pos.y + row_height,
                                         ),
                                     );
                                     vec![ui.interact(
@@ -917,17 +2720,18 @@ impl App {
                                     for row in start_row..=end_row {
                                         if let Some(current_row) = galley.rows.get(row) {
                                             let row_rect = current_row.rect();
-                                            let row_y = text.position[1] + row as f32 * row_height;
+                                            let row_y =
+                                                galley_position[1] + row as f32 * row_height;
 
                                             let link_rect = if row == start_row {
                                                 // 第一行从文本开始位置到行尾
                                                 Rect::from_min_max(
                                                     Pos2::new(
-                                                        text.position[0] + start_pos.x,
+                                                        galley_position[0] + start_pos.x,
                                                         row_y,
                                                     ),
                                                     Pos2::new(
-                                                        text.position[0] + row_rect.max.x,
+                                                        galley_position[0] + row_rect.max.x,
                                                         row_y + row_height,
                                                     ),
                                                 )
@@ -935,11 +2739,11 @@ impl App {
                                                 // 最后一行从行首到文本结束位置
                                                 Rect::from_min_max(
                                                     Pos2::new(
-                                                        text.position[0] + row_rect.min.x,
+                                                        galley_position[0] + row_rect.min.x,
                                                         row_y,
                                                     ),
                                                     Pos2::new(
-                                                        text.position[0] + end_pos.x,
+                                                        galley_position[0] + end_pos.x,
                                                         row_y + row_height,
                                                     ),
                                                 )
@@ -947,11 +2751,11 @@ impl App {
                                                 // 中间整行
                                                 Rect::from_min_max(
                                                     Pos2::new(
-                                                        text.position[0] + row_rect.min.x,
+                                                        galley_position[0] + row_rect.min.x,
                                                         row_y,
                                                     ),
                                                     Pos2::new(
-                                                        text.position[0] + row_rect.max.x,
+                                                        galley_position[0] + row_rect.max.x,
                                                         row_y + row_height,
                                                     ),
                                                 )
@@ -993,7 +2797,9 @@ impl App {
                                                 ui.input(|i| i.pointer.interact_pos())
                                             {
                                                 let relative_pos = pointer_pos
-                                                    - <[f32; 2] as Into<Pos2>>::into(text.position);
+                                                    - <[f32; 2] as Into<Pos2>>::into(
+                                                        galley_position,
+                                                    );
                                                 let cursor = galley.cursor_from_pos(relative_pos);
                                                 #[cfg(feature = "rc_standard")]
                                                 if cursor.index.0 >= *start
@@ -1017,7 +2823,9 @@ impl App {
                                                     ui.input(|i| i.pointer.interact_pos())
                                             {
                                                 let relative_pos = pointer_pos
-                                                    - <[f32; 2] as Into<Pos2>>::into(text.position);
+                                                    - <[f32; 2] as Into<Pos2>>::into(
+                                                        galley_position,
+                                                    );
                                                 let cursor = galley.cursor_from_pos(relative_pos);
                                                 #[cfg(feature = "rc_standard")]
                                                 if cursor.index.0 >= *start
@@ -1049,12 +2857,12 @@ impl App {
                                         // 单行超链接高亮
                                         let selection_rect = Rect::from_min_max(
                                             Pos2::new(
-                                                text.position[0] + start_pos.x,
-                                                text.position[1] + start_pos.y,
+                                                galley_position[0] + start_pos.x,
+                                                galley_position[1] + start_pos.y,
                                             ),
                                             Pos2::new(
-                                                text.position[0] + end_pos.x,
-                                                text.position[1]
+                                                galley_position[0] + end_pos.x,
+                                                galley_position[1]
                                                     + start_pos.y
                                                     + galley
                                                         .rows
@@ -1082,13 +2890,13 @@ impl App {
                                                     // 第一行从文本开始位置到行尾
                                                     let selection_rect = Rect::from_min_max(
                                                         Pos2::new(
-                                                            text.position[0] + start_pos.x,
-                                                            text.position[1]
+                                                            galley_position[0] + start_pos.x,
+                                                            galley_position[1]
                                                                 + row as f32 * row_height,
                                                         ),
                                                         Pos2::new(
-                                                            text.position[0] + row_rect.max.x,
-                                                            text.position[1]
+                                                            galley_position[0] + row_rect.max.x,
+                                                            galley_position[1]
                                                                 + row as f32 * row_height
                                                                 + row_height,
                                                         ),
@@ -1104,13 +2912,13 @@ impl App {
                                                     // 最后一行从行首到文本结束位置
                                                     let selection_rect = Rect::from_min_max(
                                                         Pos2::new(
-                                                            text.position[0] + row_rect.min.x,
-                                                            text.position[1]
+                                                            galley_position[0] + row_rect.min.x,
+                                                            galley_position[1]
                                                                 + row as f32 * row_height,
                                                         ),
                                                         Pos2::new(
-                                                            text.position[0] + end_pos.x,
-                                                            text.position[1]
+                                                            galley_position[0] + end_pos.x,
+                                                            galley_position[1]
                                                                 + row as f32 * row_height
                                                                 + row_height,
                                                         ),
@@ -1126,13 +2934,13 @@ impl App {
                                                     // 中间整行高亮
                                                     let selection_rect = Rect::from_min_max(
                                                         Pos2::new(
-                                                            text.position[0] + row_rect.min.x,
-                                                            text.position[1]
+                                                            galley_position[0] + row_rect.min.x,
+                                                            galley_position[1]
                                                                 + row as f32 * row_height,
                                                         ),
                                                         Pos2::new(
-                                                            text.position[0] + row_rect.max.x,
-                                                            text.position[1]
+                                                            galley_position[0] + row_rect.max.x,
+                                                            galley_position[1]
                                                                 + row as f32 * row_height
                                                                 + row_height,
                                                         ),
@@ -1150,16 +2958,58 @@ impl App {
                                     };
                                 };
                             }
-                            if text.basic_front_resource_config.clip_rect.is_some() {
-                                ui.set_clip_rect(Rect::from_min_size(
-                                    [0_f32, 0_f32].into(),
-                                    [
-                                        ui.ctx().content_rect().width(),
-                                        ui.ctx().content_rect().height(),
-                                    ]
-                                    .into(),
-                                ));
+
+                            // 处理折叠文本的展开和收起
+                            if let Some(ref label) = collapsible_label
+                                && display_content.ends_with(label.as_str())
+                            {
+                                let end = display_content.chars().count();
+                                let start_cursor = galley
+                                    .pos_from_cursor(CCursor::new(end - label.chars().count()));
+                                let end_cursor = galley.pos_from_cursor(CCursor::new(end));
+                                let label_rect = if start_cursor.min.y == end_cursor.min.y {
+                                    Rect::from_min_max(start_cursor.min, end_cursor.max)
+                                } else {
+                                    // 标签跨行时只取最后一行
+                                    Rect::from_min_max(
+                                        Pos2::new(
+                                            galley
+                                                .rows
+                                                .last()
+                                                .map_or(0_f32, |row| row.rect().min.x),
+                                            end_cursor.min.y,
+                                        ),
+                                        end_cursor.max,
+                                    )
+                                }
+                                .translate(Vec2::new(galley_position[0], galley_position[1]));
+                                let label_response = ui.interact(
+                                    label_rect,
+                                    Id::new(format!("collapsible_{}", render_resource.0.name)),
+                                    Sense::click(),
+                                );
+                                if let Some(index) = self.get_render_layer_resource(&build_id(
+                                    &render_resource.0.name,
+                                    "Text",
+                                )) && let Some(mouse_pos) = ui.input(|i| i.pointer.hover_pos())
+                                    && self.resource_get_focus(
+                                        index,
+                                        mouse_pos.into(),
+                                        false,
+                                        vec![],
+                                    )
+                                {
+                                    if label_response.hovered() {
+                                        ui.set_cursor_icon(CursorIcon::PointingHand);
+                                    };
+                                    if label_response.clicked() {
+                                        text.expanded = !text.expanded;
+                                        text.selection = None;
+                                        ui.ctx().request_repaint();
+                                    };
+                                };
                             };
+                            ui.set_clip_rect(previous_clip);
                         } else {
                             text.selection = None;
                         };
@@ -1175,106 +3025,360 @@ impl App {
                     if custom_rect.display_info.enable {
                         let mut custom_rect = custom_rect.clone();
                         [custom_rect.position, custom_rect.size] = position_size_processor(
-                            custom_rect.basic_front_resource_config.position_size_config,
+                            self.responsive_position_size_config(
+                                &render_resource.0,
+                                custom_rect.basic_front_resource_config.position_size_config,
+                                ui,
+                            ),
                             ui,
                         );
-                        if !custom_rect.display_info.hidden {
-                            if let Some(clip_rect) =
-                                custom_rect.basic_front_resource_config.clip_rect
-                            {
-                                let [min, size] = position_size_processor(clip_rect, ui);
-                                ui.set_clip_rect(Rect::from_min_size(min.into(), size.into()));
+                        if let Some(position) =
+                            self.anchored_position(&render_resource.0, custom_rect.size, ui)?
+                        {
+                            custom_rect.position = position;
+                        };
+                        if let Some(hover_effect) = custom_rect.hover_effect {
+                            custom_rect.hover_progress = if custom_rect.display_info.hidden {
+                                0_f32
+                            } else {
+                                self.update_hover_progress(
+                                    ui,
+                                    &render_resource.0,
+                                    custom_rect.hover_progress,
+                                )
                             };
-                            ui.painter().rect(
-                                Rect::from_min_max(
-                                    Pos2::new(custom_rect.position[0], custom_rect.position[1]),
-                                    Pos2::new(
-                                        custom_rect.position[0] + custom_rect.size[0],
-                                        custom_rect.position[1] + custom_rect.size[1],
-                                    ),
-                                ),
-                                custom_rect.rounding,
+                            [custom_rect.position, custom_rect.size] = hover_effect.apply_to_rect(
+                                [custom_rect.position, custom_rect.size],
+                                custom_rect.hover_progress,
+                            );
+                        };
+                        // 应用入场动画，透明度只在本帧内修改
+                        let original_alpha = custom_rect.alpha;
+                        if let Some((rect, alpha_factor)) = self.entrance_progress(
+                            &render_resource.0,
+                            [custom_rect.position, custom_rect.size],
+                        ) {
+                            [custom_rect.position, custom_rect.size] = rect;
+                            custom_rect.alpha = (custom_rect.alpha as f32 * alpha_factor) as u8;
+                        };
+                        let cull_margin = custom_rect.border_width
+                            + custom_rect.shadow.map_or(0_f32, |x| x.extent())
+                            + if custom_rect.rotate_angle != 0_f32 {
+                                custom_rect.size[0].hypot(custom_rect.size[1])
+                            } else {
+                                0_f32
+                            };
+                        if !custom_rect.display_info.hidden
+                            && !self.is_culled(
+                                &render_resource.0,
+                                custom_rect.display_info,
+                                custom_rect.basic_front_resource_config.clip_rect,
+                                [custom_rect.position, custom_rect.size],
+                                cull_margin,
+                                ui,
+                            )
+                        {
+                            let previous_clip = self.begin_clip(
+                                &render_resource.0,
+                                custom_rect.basic_front_resource_config.clip_rect,
+                                ui,
+                            );
+                            let fill = custom_rect.fill.unwrap_or(RectFill::Solid([
+                                custom_rect.color[0],
+                                custom_rect.color[1],
+                                custom_rect.color[2],
+                                255,
+                            ]));
+                            // 将填充颜色与悬停效果和覆盖层混合
+                            let blend_fill = |rgba: [u8; 4]| {
+                                let color = if let Some(hover_effect) = custom_rect.hover_effect {
+                                    hover_effect.apply_to_color(
+                                        [rgba[0], rgba[1], rgba[2]],
+                                        custom_rect.hover_progress,
+                                    )
+                                } else {
+                                    [rgba[0], rgba[1], rgba[2]]
+                                };
+                                let alpha =
+                                    (custom_rect.alpha as f32 * rgba[3] as f32 / 255_f32) as u8;
                                 if let Some(overlay_alpha) = custom_rect.overlay_alpha {
                                     Color32::from_rgba_unmultiplied(
-                                        (custom_rect.color[0] as f32
-                                            * custom_rect.overlay_color[0] as f32
+                                        (color[0] as f32 * custom_rect.overlay_color[0] as f32
+                                            / 255_f32)
+                                            as u8,
+                                        (color[1] as f32 * custom_rect.overlay_color[1] as f32
+                                            / 255_f32)
+                                            as u8,
+                                        (color[2] as f32 * custom_rect.overlay_color[2] as f32
+                                            / 255_f32)
+                                            as u8,
+                                        (alpha as f32 * overlay_alpha as f32 / 255_f32) as u8,
+                                    )
+                                } else {
+                                    Color32::from_rgba_unmultiplied(
+                                        color[0], color[1], color[2], alpha,
+                                    )
+                                }
+                            };
+                            let stroke = Stroke {
+                                width: custom_rect.border_width,
+                                color: if let Some(overlay_border_alpha) =
+                                    custom_rect.overlay_border_alpha
+                                {
+                                    Color32::from_rgba_unmultiplied(
+                                        (custom_rect.border_color[0] as f32
+                                            * custom_rect.overlay_border_color[0] as f32
                                             / 255_f32)
                                             as u8,
-                                        (custom_rect.color[1] as f32
-                                            * custom_rect.overlay_color[1] as f32
+                                        (custom_rect.border_color[1] as f32
+                                            * custom_rect.overlay_border_color[1] as f32
                                             / 255_f32)
                                             as u8,
-                                        (custom_rect.color[2] as f32
-                                            * custom_rect.overlay_color[2] as f32
+                                        (custom_rect.border_color[2] as f32
+                                            * custom_rect.overlay_border_color[2] as f32
                                             / 255_f32)
                                             as u8,
-                                        (custom_rect.alpha as f32 * overlay_alpha as f32 / 255_f32)
+                                        (custom_rect.border_alpha as f32
+                                            * overlay_border_alpha as f32
+                                            / 255_f32)
                                             as u8,
                                     )
                                 } else {
                                     Color32::from_rgba_unmultiplied(
-                                        custom_rect.color[0],
-                                        custom_rect.color[1],
-                                        custom_rect.color[2],
-                                        custom_rect.alpha,
+                                        custom_rect.border_color[0],
+                                        custom_rect.border_color[1],
+                                        custom_rect.border_color[2],
+                                        custom_rect.border_alpha,
                                     )
                                 },
-                                Stroke {
-                                    width: custom_rect.border_width,
-                                    color: if let Some(overlay_border_alpha) =
-                                        custom_rect.overlay_border_alpha
-                                    {
-                                        Color32::from_rgba_unmultiplied(
-                                            (custom_rect.border_color[0] as f32
-                                                * custom_rect.overlay_border_color[0] as f32
-                                                / 255_f32)
-                                                as u8,
-                                            (custom_rect.border_color[1] as f32
-                                                * custom_rect.overlay_border_color[1] as f32
-                                                / 255_f32)
-                                                as u8,
-                                            (custom_rect.border_color[2] as f32
-                                                * custom_rect.overlay_border_color[2] as f32
-                                                / 255_f32)
-                                                as u8,
-                                            (custom_rect.border_alpha as f32
-                                                * overlay_border_alpha as f32
-                                                / 255_f32)
-                                                as u8,
-                                        )
-                                    } else {
-                                        Color32::from_rgba_unmultiplied(
-                                            custom_rect.border_color[0],
-                                            custom_rect.border_color[1],
-                                            custom_rect.border_color[2],
-                                            custom_rect.border_alpha,
-                                        )
-                                    },
-                                },
-                                match custom_rect.border_kind {
-                                    BorderKind::Inside => StrokeKind::Inside,
-                                    BorderKind::Middle => StrokeKind::Middle,
-                                    BorderKind::Outside => StrokeKind::Outside,
-                                },
+                            };
+                            let rect = Rect::from_min_max(
+                                Pos2::new(custom_rect.position[0], custom_rect.position[1]),
+                                Pos2::new(
+                                    custom_rect.position[0] + custom_rect.size[0],
+                                    custom_rect.position[1] + custom_rect.size[1],
+                                ),
                             );
-                            if custom_rect.basic_front_resource_config.clip_rect.is_some() {
-                                ui.set_clip_rect(Rect::from_min_size(
-                                    [0_f32, 0_f32].into(),
-                                    [
-                                        ui.ctx().content_rect().width(),
-                                        ui.ctx().content_rect().height(),
-                                    ]
-                                    .into(),
-                                ));
+                            // 阴影绘制在填充之前
+                            if let Some(shadow) = custom_rect.shadow
+                                && custom_rect.rotate_angle == 0_f32
+                            {
+                                ui.painter().add(
+                                    shadow
+                                        .to_shadow(custom_rect.alpha)
+                                        .as_shape(rect, custom_rect.rounding.to_corner_radius()),
+                                );
                             };
+                            let stroke_kind = match custom_rect.border_kind {
+                                BorderKind::Inside => StrokeKind::Inside,
+                                BorderKind::Middle => StrokeKind::Middle,
+                                BorderKind::Outside => StrokeKind::Outside,
+                            };
+                            let rotated = custom_rect.rotate_angle != 0_f32;
+                            // 虚线和点线边框在填充之后单独绘制
+                            let patterned_border = custom_rect.border_style != BorderStyle::Solid
+                                && stroke.width > 0_f32;
+                            let solid_stroke = if patterned_border {
+                                Stroke::NONE
+                            } else {
+                                stroke
+                            };
+                            match fill {
+                                RectFill::Solid(rgba) if !rotated => {
+                                    ui.painter().rect(
+                                        rect,
+                                        custom_rect.rounding.to_corner_radius(),
+                                        blend_fill(rgba),
+                                        solid_stroke,
+                                        stroke_kind,
+                                    );
+                                }
+                                _ => {
+                                    // 渐变填充和旋转后的矩形由带顶点颜色的网格绘制
+                                    ui.painter().add(Shape::mesh(Self::custom_rect_mesh(
+                                        rect,
+                                        custom_rect.rounding.corners(),
+                                        &fill,
+                                        blend_fill,
+                                        custom_rect.rotate_angle,
+                                        custom_rect.rotate_center,
+                                    )));
+                                    if !rotated {
+                                        ui.painter().rect_stroke(
+                                            rect,
+                                            custom_rect.rounding.to_corner_radius(),
+                                            solid_stroke,
+                                            stroke_kind,
+                                        );
+                                    };
+                                }
+                            };
+                            // 旋转后的矩形边框也按图案绘制，实线边框同样经过旋转
+                            if patterned_border || (rotated && stroke.width > 0_f32) {
+                                let rotation = Rot2::from_angle(custom_rect.rotate_angle);
+                                let origin = rect.min + Vec2::from(custom_rect.rotate_center);
+                                // 按边框类型调整绘制边框的矩形和圆角半径
+                                let offset = match custom_rect.border_kind {
+                                    BorderKind::Inside => -stroke.width / 2_f32,
+                                    BorderKind::Middle => 0_f32,
+                                    BorderKind::Outside => stroke.width / 2_f32,
+                                };
+                                ui.painter().extend(Self::patterned_border_shapes(
+                                    rect.expand(offset),
+                                    custom_rect.rounding.corners().map(|x| {
+                                        if x > 0_f32 {
+                                            (x + offset).max(0_f32)
+                                        } else {
+                                            0_f32
+                                        }
+                                    }),
+                                    stroke,
+                                    custom_rect.border_style,
+                                    |point| origin + rotation * (point - origin),
+                                ));
+                            };
+                            ui.set_clip_rect(previous_clip);
                         };
+                        custom_rect.alpha = original_alpha;
                         self.replace_resource(&render_resource.0.name, custom_rect)?;
                     };
                 }
+                "Polygon" => {
+                    let polygon = self
+                        .get_resource::<Polygon>(&build_id(&render_resource.0.name, "Polygon"))?;
+                    if polygon.display_info.enable {
+                        let mut polygon = polygon.clone();
+                        [polygon.position, polygon.size] = position_size_processor(
+                            self.responsive_position_size_config(
+                                &render_resource.0,
+                                polygon.basic_front_resource_config.position_size_config,
+                                ui,
+                            ),
+                            ui,
+                        );
+                        if let Some(position) =
+                            self.anchored_position(&render_resource.0, polygon.size, ui)?
+                        {
+                            polygon.position = position;
+                        };
+                        // 应用入场动画，透明度只在本帧内修改
+                        let original_alpha = polygon.alpha;
+                        if let Some((rect, alpha_factor)) = self
+                            .entrance_progress(&render_resource.0, [polygon.position, polygon.size])
+                        {
+                            [polygon.position, polygon.size] = rect;
+                            polygon.alpha = (polygon.alpha as f32 * alpha_factor) as u8;
+                        };
+                        if !polygon.display_info.hidden
+                            && polygon.points.len() >= 3
+                            && !self.is_culled(
+                                &render_resource.0,
+                                polygon.display_info,
+                                polygon.basic_front_resource_config.clip_rect,
+                                [polygon.position, polygon.size],
+                                polygon.border_width,
+                                ui,
+                            )
+                        {
+                            let previous_clip = self.begin_clip(
+                                &render_resource.0,
+                                polygon.basic_front_resource_config.clip_rect,
+                                ui,
+                            );
+                            let points: Vec<Pos2> = polygon
+                                .screen_points()
+                                .into_iter()
+                                .map(Pos2::from)
+                                .collect();
+                            let fill = Color32::from_rgba_unmultiplied(
+                                polygon.fill_color[0],
+                                polygon.fill_color[1],
+                                polygon.fill_color[2],
+                                polygon.alpha,
+                            );
+                            let stroke = Stroke::new(
+                                polygon.border_width,
+                                Color32::from_rgba_unmultiplied(
+                                    polygon.border_color[0],
+                                    polygon.border_color[1],
+                                    polygon.border_color[2],
+                                    polygon.border_alpha,
+                                ),
+                            );
+                            if Self::polygon_is_convex(&points) {
+                                ui.painter()
+                                    .add(Shape::convex_polygon(points, fill, stroke));
+                            } else {
+                                // 凹多边形先三角化再以网格填充
+                                let mut mesh = Mesh::default();
+                                for point in &points {
+                                    mesh.colored_vertex(*point, fill);
+                                }
+                                for [a, b, c] in Self::triangulate_polygon(&points) {
+                                    mesh.add_triangle(a, b, c);
+                                }
+                                ui.painter().add(Shape::mesh(mesh));
+                                if stroke.width > 0_f32 {
+                                    ui.painter().add(Shape::closed_line(points, stroke));
+                                };
+                            };
+                            ui.set_clip_rect(previous_clip);
+                        };
+                        polygon.alpha = original_alpha;
+                        self.replace_resource(&render_resource.0.name, polygon)?;
+                    };
+                }
                 _ => {
                     unreachable!()
                 }
             }
+            // 为可点击资源绘制悬停和按下叠加层
+            if let Some(clickable) = self
+                .clickables
+                .iter()
+                .find(|x| x.target == render_resource.0 && x.hovered)
+            {
+                let resource = self.get_basic_front_resource(&render_resource.0)?;
+                if resource.display_display_info().enable {
+                    let color = if clickable.pressed {
+                        clickable.style.pressed_overlay
+                    } else {
+                        clickable.style.hover_overlay
+                    };
+                    let rounding = if render_resource.0.discern_type == "CustomRect" {
+                        self.get_resource::<CustomRect>(&render_resource.0)?
+                            .rounding
+                            .to_corner_radius()
+                    } else {
+                        CornerRadius::ZERO
+                    };
+                    ui.painter().rect_filled(
+                        Rect::from_min_size(
+                            resource.display_position().into(),
+                            resource.display_size().into(),
+                        ),
+                        rounding,
+                        Color32::from_rgba_unmultiplied(color[0], color[1], color[2], color[3]),
+                    );
+                };
+            };
+            // 资源绘制完成后运行对应的钩子
+            if let Some(&(_, hook)) = self
+                .post_draw_hooks
+                .iter()
+                .find(|x| x.0 == render_resource.0)
+            {
+                let resource = self.get_basic_front_resource(&render_resource.0)?;
+                if resource.display_display_info().enable {
+                    hook(
+                        ui.painter(),
+                        Rect::from_min_size(
+                            resource.display_position().into(),
+                            resource.display_size().into(),
+                        ),
+                    );
+                };
+            };
             Ok(())
         } else {
             error!(
@@ -1282,7 +3386,7 @@ impl App {
                 self.render_list.len() - 1
             );
             Err(RustConstructorError {
-                error_id: "IndexOutOfRange".to_string(),
+                error_id: RustConstructorError::INDEX_OUT_OF_RANGE.to_string(),
                 description: format!(
                     "The maximum index of the target list is {}, but the index is {index}.",
                     self.render_list.len() - 1
@@ -1291,6 +3395,44 @@ impl App {
         }
     }
 
+    /// Draws every resource of one type in the render list with a single call.
+    ///
+    /// 一次调用绘制渲染列表中某一类型的所有资源。
+    ///
+    /// Resources are drawn by z-index first, within the same z-index images sharing a texture
+    /// are drawn next to each other and everything else keeps the render list order. Each
+    /// resource is still painted separately.
+    ///
+    /// 资源首先按层级序号绘制，层级序号相同时使用相同纹理的图片会被连续绘制，
+    /// 其余资源保持渲染列表中的顺序。每个资源仍会被单独绘制。
+    pub fn batch_draw_by_type(
+        &mut self,
+        discern_type: &str,
+        ui: &mut Ui,
+    ) -> Result<(), RustConstructorError> {
+        let mut index_list: Vec<(usize, i32, String)> = Vec::new();
+        for (index, (id, _)) in self.render_list.iter().enumerate() {
+            if id.discern_type != discern_type {
+                continue;
+            };
+            let group = if discern_type == "Image" {
+                match &self.get_resource::<Image>(id)?.image_load_method {
+                    ImageLoadMethod::ByPath((path, _)) => path.clone(),
+                    ImageLoadMethod::ByTexture(texture) => texture.path.clone(),
+                }
+            } else {
+                String::new()
+            };
+            index_list.push((index, self.get_z_index(id), group));
+        }
+        // 稳定排序，保证同组资源之间的相对顺序不变
+        index_list.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.2.cmp(&b.2)));
+        for (index, _, _) in index_list {
+            self.draw_resource_by_index(ui, index)?;
+        }
+        Ok(())
+    }
+
     /// Generate information for Rust Constructor resources.
     ///
     /// 生成Rust Constructor资源的信息。
@@ -1391,11 +3533,12 @@ impl App {
             RustConstructorId { name, discern_type },
             [min_position, max_position],
             ignore_render_layer,
+            z_index,
         ) in &self.render_layer
         {
             text += &format!(
-                "\nName: {}\nType: {}\nMin Position: {:?}\nMax Position: {:?}\nIgnore Render Layer: {}\n",
-                name, discern_type, min_position, max_position, ignore_render_layer
+                "\nName: {}\nType: {}\nMin Position: {:?}\nMax Position: {:?}\nIgnore Render Layer: {}\nZ Index: {}\n",
+                name, discern_type, min_position, max_position, ignore_render_layer, z_index
             )
         }
         if print {
@@ -1426,6 +3569,239 @@ impl App {
         text
     }
 
+    /// Collects the current pointer and keyboard state into a single snapshot.
+    ///
+    /// 将当前的指针和键盘状态收集为一个快照。
+    ///
+    /// The returned summary implements `Display`, so it can be printed directly when
+    /// diagnosing why an interaction did not register.
+    ///
+    /// 返回的快照实现了`Display`，在排查交互未生效的原因时可以直接打印。
+    pub fn input_summary(&self, ui: &Ui) -> InputSummary {
+        ui.input(|i| {
+            let mut keys_down: Vec<String> = i
+                .keys_down
+                .iter()
+                .map(|key| key.name().to_string())
+                .collect();
+            keys_down.sort();
+            InputSummary {
+                pointer_position: i.pointer.hover_pos().map(|pos| [pos.x, pos.y]),
+                pointer_buttons_down: [
+                    i.pointer.primary_down(),
+                    i.pointer.secondary_down(),
+                    i.pointer.middle_down(),
+                ],
+                modifiers: [
+                    i.modifiers.ctrl,
+                    i.modifiers.shift,
+                    i.modifiers.alt,
+                    i.modifiers.command,
+                ],
+                keys_down,
+                scroll_delta: i.smooth_scroll_delta.into(),
+            }
+        })
+    }
+
+    /// Changes the title of the window.
+    ///
+    /// 修改窗口标题。
+    pub fn set_window_title(&self, ui: &Ui, title: &str) {
+        ui.ctx()
+            .send_viewport_cmd(ViewportCommand::Title(title.to_string()));
+    }
+
+    /// Enters or leaves fullscreen.
+    ///
+    /// 进入或退出全屏。
+    pub fn set_fullscreen(&self, ui: &Ui, fullscreen: bool) {
+        ui.ctx()
+            .send_viewport_cmd(ViewportCommand::Fullscreen(fullscreen));
+    }
+
+    /// Resizes the inner area of the window to [width, height].
+    ///
+    /// 将窗口内部区域的大小调整为[宽度, 高度]。
+    pub fn set_window_size(&self, ui: &Ui, size: [f32; 2]) {
+        ui.ctx()
+            .send_viewport_cmd(ViewportCommand::InnerSize(size.into()));
+    }
+
+    /// Moves the window to the center of its monitor.
+    ///
+    /// 将窗口移动到所在显示器的中央。
+    pub fn center_window(&self, ui: &Ui) -> Result<(), RustConstructorError> {
+        // 显示器或窗口大小未知时无法计算居中位置
+        match ViewportCommand::center_on_screen(ui.ctx()) {
+            Some(command) => {
+                ui.ctx().send_viewport_cmd(command);
+                Ok(())
+            }
+            None => {
+                error!("[MonitorSizeUnavailable]center_window: The monitor size is unknown.");
+                Err(RustConstructorError {
+                    error_id: RustConstructorError::MONITOR_SIZE_UNAVAILABLE.to_string(),
+                    description: "The monitor size is unknown.".to_string(),
+                })
+            }
+        }
+    }
+
+    /// Asks the window to close.
+    ///
+    /// 请求关闭窗口。
+    pub fn request_close(&self, ui: &Ui) {
+        ui.ctx().send_viewport_cmd(ViewportCommand::Close);
+    }
+
+    /// Opens a secondary window, or updates the title and size of an open one.
+    ///
+    /// 打开次级窗口，或更新已打开窗口的标题和大小。
+    ///
+    /// Resources tagged with `["viewport", id]` are drawn in the window instead of the main one
+    /// while it is open, and fall back to the main window once it closes. Their switches,
+    /// clickables, drag and drop, text inputs and hover effects read the pointer of the window,
+    /// see `resource_input`. Context menus, modals and keyboard focus stay in the main window.
+    /// The window is shown as an immediate viewport, so the backend must support multiple
+    /// viewports.
+    ///
+    /// 窗口打开时带有`["viewport", id]`标签的资源会绘制在其中而不是主窗口中，窗口关闭后会回到主窗口。
+    /// 这些资源的开关、可点击资源、拖放、文本输入和悬停效果读取该窗口的指针，参见`resource_input`。
+    /// 右键菜单、模态对话框和键盘焦点仍在主窗口中。窗口以即时视口的形式显示，因此后端需要支持多视口。
+    pub fn open_secondary_window(&mut self, id: &str, title: &str, size: [f32; 2]) {
+        self.secondary_windows.retain(|x| x.id != id);
+        self.secondary_windows.push(SecondaryWindow {
+            id: id.to_string(),
+            title: title.to_string(),
+            size,
+            closed: false,
+        });
+    }
+
+    /// Closes a secondary window from the application.
+    ///
+    /// 由应用程序关闭次级窗口。
+    pub fn close_secondary_window(&mut self, id: &str) {
+        self.secondary_windows.retain(|x| x.id != id);
+    }
+
+    /// Returns whether a secondary window is open.
+    ///
+    /// 返回次级窗口是否处于打开状态。
+    pub fn secondary_window_open(&self, id: &str) -> bool {
+        self.secondary_windows
+            .iter()
+            .any(|x| x.id == id && !x.closed)
+    }
+
+    /// Returns whether the user closed a secondary window and marks the close as handled.
+    ///
+    /// 返回用户是否关闭了次级窗口，并将关闭标记为已处理。
+    pub fn consume_window_closed(&mut self, id: &str) -> bool {
+        let closed = self
+            .secondary_windows
+            .iter()
+            .any(|x| x.id == id && x.closed);
+        if closed {
+            self.secondary_windows.retain(|x| x.id != id);
+        };
+        closed
+    }
+
+    /// Returns whether a resource is drawn in an open secondary window.
+    ///
+    /// 返回资源是否绘制在已打开的次级窗口中。
+    fn in_secondary_window(&self, id: &RustConstructorId) -> bool {
+        self.secondary_window_of(id).is_some()
+    }
+
+    /// Returns the id of the open secondary window a resource is drawn in.
+    ///
+    /// 返回资源所绘制的已打开次级窗口的ID。
+    fn secondary_window_of(&self, id: &RustConstructorId) -> Option<String> {
+        if self.secondary_windows.is_empty() {
+            return None;
+        };
+        let index = self.check_resource_exists(id)?;
+        let (_, window_id) = get_tag(
+            "viewport",
+            &self.rust_constructor_resource[index].content.display_tags(),
+        )?;
+        self.secondary_window_open(&window_id).then_some(window_id)
+    }
+
+    /// Reads the input of the window a resource is drawn in.
+    ///
+    /// 读取资源所在窗口的输入。
+    ///
+    /// Resources routed to an open secondary window see the pointer and buttons of that window,
+    /// all other resources read the input of `ui`.
+    ///
+    /// 被路由到已打开次级窗口的资源读取该窗口的指针和按键，其他资源读取`ui`的输入。
+    pub fn resource_input<R>(
+        &self,
+        ui: &Ui,
+        id: &RustConstructorId,
+        reader: impl FnOnce(&InputState) -> R,
+    ) -> R {
+        match self.secondary_window_of(id) {
+            Some(window_id) => ui
+                .ctx()
+                .input_for(ViewportId::from_hash_of(&window_id), reader),
+            None => ui.input(reader),
+        }
+    }
+
+    /// Shows the open secondary windows and draws the resources routed to them, it is called by
+    /// the page pipeline.
+    ///
+    /// 显示已打开的次级窗口并绘制被路由到其中的资源，页面流程会调用此方法。
+    pub fn draw_secondary_windows(&mut self, ui: &Ui) -> Result<(), RustConstructorError> {
+        for count in 0..self.secondary_windows.len() {
+            if self.secondary_windows[count].closed {
+                continue;
+            };
+            let window = self.secondary_windows[count].clone();
+            ui.ctx().show_viewport_immediate(
+                ViewportId::from_hash_of(&window.id),
+                ViewportBuilder::default()
+                    .with_title(&window.title)
+                    .with_inner_size(window.size),
+                |ui, _| {
+                    // 用户关闭窗口后只记录关闭，由consume_window_closed处理
+                    if ui.input(|i| i.viewport().close_requested()) {
+                        self.secondary_windows[count].closed = true;
+                        return Ok(());
+                    };
+                    let dragged = self.dragged_resource();
+                    for i in 0..self.render_list.len() {
+                        if self
+                            .secondary_window_of(&self.render_list[i].0)
+                            .is_some_and(|x| x == window.id)
+                            && !dragged
+                                .as_ref()
+                                .is_some_and(|x| x.0 == self.render_list[i].0)
+                        {
+                            self.draw_resource_by_index(ui, i)?;
+                        };
+                    }
+                    // 被拖动的资源跟随指针绘制在该窗口的最上方
+                    if let Some((source, offset)) = dragged
+                        && self
+                            .secondary_window_of(&source)
+                            .is_some_and(|x| x == window.id)
+                        && let Some(index) = self.render_list.iter().position(|x| x.0 == source)
+                    {
+                        self.draw_with_visual_effect(ui, index, 0.8, offset.into())?;
+                    };
+                    Ok(())
+                },
+            )?;
+        }
+        Ok(())
+    }
+
     /// Updates the render queue based on active resources.
     ///
     /// 根据活跃资源更新渲染队列。
@@ -1468,6 +3844,39 @@ impl App {
                 };
             }
         };
+        // 稳定排序，层级序号相同的资源保持原有顺序
+        let z_index = &self.z_index;
+        self.render_list
+            .sort_by_key(|x| z_index.iter().find(|z| z.0 == x.0).map_or(0, |z| z.1));
+    }
+
+    /// Sets the z-index of a resource, higher values are drawn on top.
+    ///
+    /// 设置资源的层级序号，数值越大绘制越靠上。
+    ///
+    /// The render queue is sorted by z-index every frame, resources sharing a z-index keep their
+    /// queue order, so `request_jump_render_list` only reorders within the same z-index.
+    ///
+    /// 渲染队列每帧都会按层级序号排序，层级序号相同的资源保持其队列顺序，
+    /// 因此`request_jump_render_list`只会在相同层级序号内调整顺序。
+    pub fn set_z_index(&mut self, id: &RustConstructorId, z_index: i32) {
+        // 记录资源的实际名称（可能带有页面作用域前缀）
+        let id = match self.check_resource_exists(id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => id.clone(),
+        };
+        self.z_index.retain(|x| x.0 != id);
+        if z_index != 0 {
+            self.z_index.push((id, z_index));
+        };
+    }
+
+    /// Returns the z-index of a resource.
+    ///
+    /// 返回资源的层级序号。
+    #[inline]
+    pub fn get_z_index(&self, id: &RustConstructorId) -> i32 {
+        self.z_index.iter().find(|x| x.0 == *id).map_or(0, |x| x.1)
     }
 
     /// Moves a resource to the front of the render queue with error handling.
@@ -1494,7 +3903,7 @@ impl App {
                         id.name, id.discern_type
                     );
                     Err(RustConstructorError {
-                        error_id: "RenderResourceNotFound".to_string(),
+                        error_id: RustConstructorError::RENDER_RESOURCE_NOT_FOUND.to_string(),
                         description: format!(
                             "Render resource '{}({})' not found.",
                             id.name, id.discern_type
@@ -1516,7 +3925,7 @@ impl App {
                     citer.name, citer.discern_type
                 );
                 Err(RustConstructorError {
-                    error_id: "RenderResourceNotFound".to_string(),
+                    error_id: RustConstructorError::RENDER_RESOURCE_NOT_FOUND.to_string(),
                     description: format!(
                         "Render resource citer '{}({})' not found.",
                         citer.name, citer.discern_type
@@ -1554,7 +3963,7 @@ impl App {
                 self.render_list.len() - 1
             );
             Err(RustConstructorError {
-                error_id: "IndexOutOfRange".to_string(),
+                error_id: RustConstructorError::INDEX_OUT_OF_RANGE.to_string(),
                 description: format!(
                     "The maximum index of the target list is {}, but the index is {requester_index}.",
                     self.render_list.len() - 1
@@ -1563,6 +3972,75 @@ impl App {
         }
     }
 
+    /// Moves a resource to a specific index of the render queue.
+    ///
+    /// 将资源移动到渲染队列的指定索引处。
+    ///
+    /// A resource already in the queue is moved, an active basic front resource that is not
+    /// queued yet is inserted. Index 0 is drawn first.
+    ///
+    /// 已在队列中的资源会被移动，尚未入队的活跃基本前端资源会被插入。索引0最先绘制。
+    pub fn render_list_insert(
+        &mut self,
+        index: usize,
+        id: &RustConstructorId,
+    ) -> Result<(), RustConstructorError> {
+        let position = self.render_list_position(id);
+        let render_resource = if let Some(position) = position {
+            self.render_list[position].clone()
+        } else if let Some(info) = self.active_list.iter().find(|x| x.0 == *id)
+            && self.basic_front_resource_list.contains(&id.discern_type)
+        {
+            info.clone()
+        } else {
+            error!(
+                "[RenderResourceNotFound]render_list_insert: Render resource '{}({})' not found.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RENDER_RESOURCE_NOT_FOUND.to_string(),
+                description: format!(
+                    "Render resource '{}({})' not found.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        // 移动已有资源时队列长度不变，因此最大索引少1
+        let max_index = self.render_list.len() - usize::from(position.is_some());
+        if index > max_index {
+            error!(
+                "[IndexOutOfRange]render_list_insert: The maximum index of the target list is {max_index}, but the index is {index}."
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::INDEX_OUT_OF_RANGE.to_string(),
+                description: format!(
+                    "The maximum index of the target list is {max_index}, but the index is {index}."
+                ),
+            });
+        };
+        if let Some(position) = position {
+            self.render_list.remove(position);
+        };
+        self.render_list.insert(index, render_resource);
+        Ok(())
+    }
+
+    /// Returns the index of a resource in the render queue.
+    ///
+    /// 返回资源在渲染队列中的索引。
+    #[inline]
+    pub fn render_list_position(&self, id: &RustConstructorId) -> Option<usize> {
+        self.render_list.iter().position(|x| x.0 == *id)
+    }
+
+    /// Clears the render queue, it is rebuilt from the active list by `update_render_list`.
+    ///
+    /// 清空渲染队列，`update_render_list`会根据活跃列表重新构建它。
+    #[inline]
+    pub fn clear_render_list(&mut self) {
+        self.render_list.clear();
+    }
+
     /// Updates the rendering layer information for all rendering resources.
     ///
     /// 更新所有渲染资源的渲染层信息。
@@ -1618,6 +4096,7 @@ impl App {
                 basic_front_resource
                     .display_display_info()
                     .ignore_render_layer,
+                self.get_z_index(&info.0),
             ));
         }
         Ok(())
@@ -1638,7 +4117,7 @@ impl App {
         ignore_render_config: &RenderConfig,
         hover_config: Option<&RenderConfig>,
     ) {
-        for (i, (_, point, ignore_render_layer)) in self.render_layer.iter().enumerate() {
+        for (i, (_, point, ignore_render_layer, _)) in self.render_layer.iter().enumerate() {
             match if *ignore_render_layer {
                 ignore_render_config
             } else {
@@ -1751,52 +4230,3332 @@ impl App {
         }
     }
 
-    /// Search for resources in the render list by ID.
+    /// Clamps a position so that at least `margin` pixels of the area stay inside the window.
     ///
-    /// 通过ID在渲染列表中查找资源。
-    pub fn get_render_layer_resource(&self, id: &RustConstructorId) -> Option<usize> {
-        self.render_layer.iter().position(|x| &x.0 == id)
+    /// 限制位置，使区域至少有`margin`像素保留在窗口内。
+    pub fn constrain_position(
+        &self,
+        position: [f32; 2],
+        size: [f32; 2],
+        margin: f32,
+        ui: &Ui,
+    ) -> [f32; 2] {
+        let window_size = [
+            ui.ctx().content_rect().width(),
+            ui.ctx().content_rect().height(),
+        ];
+        [0, 1].map(|i| {
+            let min = margin.min(size[i]) - size[i];
+            let max = window_size[i] - margin.min(size[i]);
+            if min <= max {
+                position[i].clamp(min, max)
+            } else {
+                position[i]
+            }
+        })
     }
 
-    /// Check whether the resource has obtained the mouse focus.
+    /// Moves a resource back into the window if it has been dragged out of view.
     ///
-    /// 检查资源是否获取鼠标焦点。
+    /// 如果资源被拖出视野，则将其移回窗口内。
     ///
-    /// Use this method to ensure that mouse operations do not trigger
-    /// multiple components simultaneously, causing confusion.
+    /// At least `margin` pixels of the resource remain visible afterwards. For a resource
+    /// panel the position of its background is adjusted.
     ///
-    /// 使用此方法以保证鼠标操作不会同时触发多个组件产生混乱。
-    pub fn resource_get_focus(
-        &self,
-        index: usize,
-        mouse_pos: [f32; 2],
-        need_contains_mouse: bool,
-        ignore_render_layer: Vec<[usize; 2]>,
-    ) -> bool {
-        let mut ignore_list = Vec::new();
-        for range in ignore_render_layer {
-            for i in 0..range[1] {
-                ignore_list.push(range[0] + i);
-            }
-        }
-        for i in index + 1..self.render_layer.len() {
-            let point = self.render_layer[i].1;
-            if mouse_pos[0] >= point[0][0]
-                && mouse_pos[1] >= point[0][1]
-                && mouse_pos[0] <= point[1][0]
-                && mouse_pos[1] <= point[1][1]
-                && !self.render_layer[i].2
-                && !ignore_list.contains(&i)
-            {
-                return false;
+    /// 调整后资源至少有`margin`像素保持可见。对于资源面板，会调整其背景的位置。
+    pub fn constrain_resource_to_window(
+        &mut self,
+        id: &RustConstructorId,
+        margin: f32,
+        ui: &Ui,
+    ) -> Result<(), RustConstructorError> {
+        let id = if id.discern_type == "ResourcePanel" {
+            let background_name = format!("{}Background", id.name);
+            let background =
+                self.get_resource::<Background>(&build_id(background_name.clone(), "Background"))?;
+            build_id(
+                background_name,
+                background_type_discern(&background.background_type),
+            )
+        } else if self.basic_front_resource_list.contains(&id.discern_type) {
+            id.clone()
+        } else {
+            error!(
+                "[ResourceDowncastTypeMismatch]constrain_resource_to_window: Resource '{}({})' has no position to constrain.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' has no position to constrain.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        let mut position_size_config = self
+            .get_basic_front_resource(&id)?
+            .display_position_size_config();
+        let [position, size] = position_size_processor(position_size_config, ui);
+        let constrained = self.constrain_position(position, size, margin, ui);
+        position_size_config.origin_position[0] += constrained[0] - position[0];
+        position_size_config.origin_position[1] += constrained[1] - position[1];
+        self.get_basic_front_resource_mut(&id)?
+            .modify_position_size_config(position_size_config);
+        Ok(())
+    }
+
+    /// Attaches a hook that runs right after the resource is drawn.
+    ///
+    /// 为资源附加一个在其绘制完成后立即运行的钩子。
+    ///
+    /// The hook receives the painter and the resolved rect of the resource, so badges or
+    /// selection handles can be painted on top of it. Setting a hook again replaces the old one.
+    ///
+    /// 钩子会收到绘制器和资源的最终矩形，可用于在资源上方绘制徽章或选择手柄。重复设置会替换旧钩子。
+    pub fn set_post_draw(
+        &mut self,
+        id: &RustConstructorId,
+        hook: fn(&Painter, Rect),
+    ) -> Result<(), RustConstructorError> {
+        if !self.basic_front_resource_list.contains(&id.discern_type) {
+            error!(
+                "[ResourceDowncastTypeMismatch]set_post_draw: Resource '{}({})' is not a basic front resource.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' is not a basic front resource.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        self.get_basic_front_resource(id)?;
+        if let Some(index) = self.post_draw_hooks.iter().position(|x| x.0 == *id) {
+            self.post_draw_hooks[index].1 = hook;
+        } else {
+            self.post_draw_hooks.push((id.clone(), hook));
+        };
+        Ok(())
+    }
+
+    /// Removes the post-draw hook of a resource.
+    ///
+    /// 移除资源的绘制后钩子。
+    pub fn remove_post_draw(&mut self, id: &RustConstructorId) {
+        self.post_draw_hooks.retain(|x| x.0 != *id);
+    }
+
+    /// Attaches a hook that runs after text is copied from a selectable text.
+    ///
+    /// 为可选择文本附加一个在复制文本后运行的钩子。
+    ///
+    /// The selection is still copied to the clipboard, the hook only gets the copied string.
+    /// Setting a hook again replaces the old one.
+    ///
+    /// 选中的内容仍会被复制到剪贴板，钩子只会收到复制的字符串。重复设置会替换旧钩子。
+    pub fn set_on_copy(
+        &mut self,
+        id: &RustConstructorId,
+        hook: fn(&str),
+    ) -> Result<(), RustConstructorError> {
+        self.get_resource::<Text>(id)?;
+        // 记录资源的实际名称（可能带有页面作用域前缀）
+        let id = self.resolved_id(id)?;
+        if let Some(index) = self.copy_hooks.iter().position(|x| x.0 == id) {
+            self.copy_hooks[index].1 = hook;
+        } else {
+            self.copy_hooks.push((id, hook));
+        };
+        Ok(())
+    }
+
+    /// Removes the copy hook of a text.
+    ///
+    /// 移除文本的复制钩子。
+    pub fn remove_on_copy(&mut self, id: &RustConstructorId) {
+        self.copy_hooks.retain(|x| x.0 != *id);
+    }
+
+    /// Returns the most recent copy from a selectable text.
+    ///
+    /// 返回最近一次从可选择文本复制的内容。
+    #[inline]
+    pub fn last_copied(&self) -> Option<&(RustConstructorId, [usize; 2], String)> {
+        self.last_copied.as_ref()
+    }
+
+    /// Returns the current frame index of an image's sprite sheet and whether a non-looping
+    /// playback finished.
+    ///
+    /// 返回图像精灵图的当前帧索引以及非循环播放是否已结束。
+    pub fn sprite_sheet_state(
+        &self,
+        id: &RustConstructorId,
+    ) -> Result<(usize, bool), RustConstructorError> {
+        let image = self.get_resource::<Image>(id)?;
+        if let Some(sprite_sheet) = image.sprite_sheet {
+            Ok((sprite_sheet.current_frame, sprite_sheet.finished))
+        } else {
+            error!(
+                "[SpriteSheetNotFound]sprite_sheet_state: Image '{}' has no sprite sheet.",
+                id.name
+            );
+            Err(RustConstructorError {
+                error_id: RustConstructorError::SPRITE_SHEET_NOT_FOUND.to_string(),
+                description: format!("Image '{}' has no sprite sheet.", id.name),
+            })
+        }
+    }
+
+    /// Writes the current theme colors into the theme-bound properties of a resource.
+    ///
+    /// 将当前主题颜色写入资源中绑定到主题的属性。
+    fn apply_theme_colors(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        let bindings: Vec<(ThemeColorTarget, [u8; 3])> = self
+            .theme_bindings
+            .iter()
+            .filter(|x| x.0 == *id)
+            .filter_map(|x| self.theme.get(&x.2).map(|color| (x.1, color)))
+            .collect();
+        for (target, color) in bindings {
+            match (&*id.discern_type, target) {
+                ("Text", ThemeColorTarget::Color) => {
+                    self.get_resource_mut::<Text>(id)?.color = color
+                }
+                ("Text", ThemeColorTarget::Background) => {
+                    self.get_resource_mut::<Text>(id)?.background_color = color
+                }
+                ("Image", ThemeColorTarget::Background) => {
+                    self.get_resource_mut::<Image>(id)?.background_color = color
+                }
+                ("Image", ThemeColorTarget::Overlay) => {
+                    self.get_resource_mut::<Image>(id)?.overlay_color = color
+                }
+                ("CustomRect", ThemeColorTarget::Color) => {
+                    self.get_resource_mut::<CustomRect>(id)?.color = color
+                }
+                ("CustomRect", ThemeColorTarget::Border) => {
+                    self.get_resource_mut::<CustomRect>(id)?.border_color = color
+                }
+                ("CustomRect", ThemeColorTarget::Overlay) => {
+                    self.get_resource_mut::<CustomRect>(id)?.overlay_color = color
+                }
+                ("Polygon", ThemeColorTarget::Color) => {
+                    self.get_resource_mut::<Polygon>(id)?.fill_color = color
+                }
+                ("Polygon", ThemeColorTarget::Border) => {
+                    self.get_resource_mut::<Polygon>(id)?.border_color = color
+                }
+                _ => {}
+            };
+        }
+        Ok(())
+    }
+
+    /// Applies the own clip and the inherited clip of a resource and returns the clip rect to
+    /// restore after drawing it.
+    ///
+    /// 应用资源自身的裁剪和继承的裁剪，并返回绘制后需要恢复的裁剪矩形。
+    fn begin_clip(
+        &self,
+        id: &RustConstructorId,
+        clip_rect: Option<PositionSizeConfig>,
+        ui: &mut Ui,
+    ) -> Rect {
+        let previous_clip = ui.clip_rect();
+        ui.set_clip_rect(self.effective_clip(id, clip_rect, ui));
+        previous_clip
+    }
+
+    /// Returns the rect a basic front resource is laid out at this frame as [position, size].
+    ///
+    /// 返回基本前端资源在本帧布局的矩形，为[位置, 尺寸]。
+    fn layout_rect(
+        &self,
+        id: &RustConstructorId,
+        ui: &Ui,
+    ) -> Result<[[f32; 2]; 2], RustConstructorError> {
+        let position_size_config = self
+            .get_basic_front_resource(id)?
+            .display_position_size_config();
+        let id = &self.resolved_id(id)?;
+        Ok(position_size_processor(
+            self.responsive_position_size_config(id, position_size_config, ui),
+            ui,
+        ))
+    }
+
+    /// Returns the area a resource is allowed to paint in, combining the current clip, its own
+    /// clip rect and the clip it inherited from `with_clip`.
+    ///
+    /// 返回资源允许绘制的区域，由当前裁剪区域、资源自身的裁剪矩形和从`with_clip`继承的裁剪区域组合而成。
+    fn effective_clip(
+        &self,
+        id: &RustConstructorId,
+        clip_rect: Option<PositionSizeConfig>,
+        ui: &Ui,
+    ) -> Rect {
+        let mut clip = ui.clip_rect();
+        if let Some(clip_rect) = clip_rect {
+            let [min, size] = position_size_processor(clip_rect, ui);
+            clip = clip.intersect(Rect::from_min_size(min.into(), size.into()));
+        };
+        if let Some((_, inherited_clip)) = self.inherited_clips.iter().find(|x| x.0 == *id) {
+            clip = clip.intersect(*inherited_clip);
+        };
+        clip
+    }
+
+    /// Checks whether render culling skips a resource this frame.
+    ///
+    /// 检查渲染剔除是否在本帧跳过资源。
+    ///
+    /// `margin` widens the resource rect for anything painted outside it, such as shadows,
+    /// borders or rotation.
+    ///
+    /// `margin`用于为绘制在资源矩形之外的内容扩展矩形，例如阴影、边框或旋转。
+    fn is_culled(
+        &mut self,
+        id: &RustConstructorId,
+        display_info: DisplayInfo,
+        clip_rect: Option<PositionSizeConfig>,
+        rect: [[f32; 2]; 2],
+        margin: f32,
+        ui: &Ui,
+    ) -> bool {
+        if !self.render_culling || display_info.always_draw {
+            return false;
+        };
+        // 资源面板内的资源通过裁剪矩形限制在面板内容区域中
+        let visible = self
+            .effective_clip(id, clip_rect, ui)
+            .intersect(ui.ctx().content_rect());
+        let culled = !visible.intersects(
+            Rect::from_min_size(rect[0].into(), rect[1].into()).expand(margin.max(0_f32)),
+        );
+        if culled {
+            self.culled_resources += 1;
+        };
+        culled
+    }
+
+    /// Builds a rounded rectangle mesh whose vertex colors follow a gradient fill.
+    ///
+    /// 构建顶点颜色遵循渐变填充的圆角矩形网格。
+    fn gradient_rect_mesh(
+        rect: Rect,
+        rounding: [f32; 4],
+        fill: &RectFill,
+        blend_fill: impl Fn([u8; 4]) -> Color32,
+    ) -> Mesh {
+        const CORNER_SEGMENTS: usize = 8;
+        let center = rect.center();
+        let half_size = [rect.width() / 2_f32, rect.height() / 2_f32];
+        let [nw, ne, sw, se] = rounding.map(|x| x.clamp(0_f32, half_size[0].min(half_size[1])));
+        // 按顺时针顺序生成轮廓点，起点为左上角圆弧
+        let mut outline = Vec::new();
+        for (corner, radius, start_angle) in [
+            (
+                Pos2::new(rect.min.x + nw, rect.min.y + nw),
+                nw,
+                std::f32::consts::PI,
+            ),
+            (
+                Pos2::new(rect.max.x - ne, rect.min.y + ne),
+                ne,
+                std::f32::consts::PI * 1.5,
+            ),
+            (Pos2::new(rect.max.x - se, rect.max.y - se), se, 0_f32),
+            (
+                Pos2::new(rect.min.x + sw, rect.max.y - sw),
+                sw,
+                std::f32::consts::FRAC_PI_2,
+            ),
+        ] {
+            if radius > 0_f32 {
+                for i in 0..=CORNER_SEGMENTS {
+                    let angle = start_angle
+                        + std::f32::consts::FRAC_PI_2 * i as f32 / CORNER_SEGMENTS as f32;
+                    outline.push(corner + Vec2::new(angle.cos(), angle.sin()) * radius);
+                }
+            } else {
+                outline.push(corner);
+            };
+        }
+        let color_at = |point: Pos2| {
+            blend_fill(fill.color_at([point.x - center.x, point.y - center.y], half_size))
+        };
+        // 矩形是凸多边形，因此从中心扇形三角化
+        let mut mesh = Mesh::default();
+        mesh.colored_vertex(center, color_at(center));
+        for point in &outline {
+            mesh.colored_vertex(*point, color_at(*point));
+        }
+        for i in 0..outline.len() as u32 {
+            mesh.add_triangle(0, i + 1, (i + 1) % outline.len() as u32 + 1);
+        }
+        mesh
+    }
+
+    /// Maps each character of truncated text back to its index in the untruncated text.
+    ///
+    /// 将截断后文本的每个字符映射回其在未截断文本中的索引。
+    ///
+    /// `keep` is the number of characters kept, inserted ellipsis characters map to None.
+    ///
+    /// `keep`为保留的字符数，插入的省略号字符映射为None。
+    fn truncated_source(
+        source: &[Option<usize>],
+        ellipsis: usize,
+        mode: TruncateMode,
+        keep: usize,
+    ) -> Vec<Option<usize>> {
+        let ellipsis = vec![None; ellipsis];
+        match mode {
+            TruncateMode::None => source.to_vec(),
+            TruncateMode::Start => [&ellipsis, &source[source.len() - keep..]].concat(),
+            TruncateMode::Middle => [
+                &source[..keep.div_ceil(2)],
+                &ellipsis,
+                &source[source.len() - keep / 2..],
+            ]
+            .concat(),
+            TruncateMode::End => [&source[..keep], &ellipsis].concat(),
+        }
+    }
+
+    /// Converts a styled segment range of the source text into ranges of the displayed text.
+    ///
+    /// 将原文中样式分段的范围转换为显示文本中的范围。
+    ///
+    /// Truncation can split a range in two, and characters outside the text are ignored.
+    ///
+    /// 截断可能将一个范围分为两段，超出文本的字符会被忽略。
+    fn display_segment_ranges(source: &[Option<usize>], range: [usize; 2]) -> Vec<[usize; 2]> {
+        let mut ranges = Vec::new();
+        let mut run_start = None;
+        for (index, source_index) in source.iter().chain([&None]).enumerate() {
+            let covered = source_index.is_some_and(|x| x >= range[0] && x < range[1]);
+            match (covered, run_start) {
+                (true, None) => run_start = Some(index),
+                (false, Some(start)) => {
+                    ranges.push([start, index]);
+                    run_start = None;
+                }
+                _ => {}
+            };
+        }
+        ranges
+    }
+
+    /// Builds the fill mesh of a custom rectangle and rotates it around `rotate_center`.
+    ///
+    /// 构建自定义矩形的填充网格并绕`rotate_center`旋转。
+    fn custom_rect_mesh(
+        rect: Rect,
+        rounding: [f32; 4],
+        fill: &RectFill,
+        blend_fill: impl Fn([u8; 4]) -> Color32,
+        rotate_angle: f32,
+        rotate_center: [f32; 2],
+    ) -> Mesh {
+        let mut mesh = Self::gradient_rect_mesh(rect, rounding, fill, blend_fill);
+        if rotate_angle != 0_f32 {
+            mesh.rotate(
+                Rot2::from_angle(rotate_angle),
+                rect.min + Vec2::from(rotate_center),
+            );
+        };
+        mesh
+    }
+
+    /// Builds the shapes of a dashed or dotted rectangle border.
+    ///
+    /// 构建虚线或点线矩形边框的图形。
+    ///
+    /// The straight edges follow the pattern while rounded corners are drawn solid, every point
+    /// is passed through `transform` so rotated rectangles can reuse it.
+    ///
+    /// 直边按图案绘制，圆角部分绘制为实线，所有点都会经过`transform`处理以便旋转后的矩形复用。
+    fn patterned_border_shapes(
+        rect: Rect,
+        rounding: [f32; 4],
+        stroke: Stroke,
+        border_style: BorderStyle,
+        transform: impl Fn(Pos2) -> Pos2,
+    ) -> Vec<Shape> {
+        const CORNER_SEGMENTS: usize = 8;
+        let [nw, ne, sw, se] =
+            rounding.map(|x| x.clamp(0_f32, (rect.width() / 2_f32).min(rect.height() / 2_f32)));
+        // 按顺时针顺序排列的圆角（圆心, 半径, 起始角度）
+        let corners = [
+            (
+                Pos2::new(rect.min.x + nw, rect.min.y + nw),
+                nw,
+                std::f32::consts::PI,
+            ),
+            (
+                Pos2::new(rect.max.x - ne, rect.min.y + ne),
+                ne,
+                std::f32::consts::PI * 1.5,
+            ),
+            (Pos2::new(rect.max.x - se, rect.max.y - se), se, 0_f32),
+            (
+                Pos2::new(rect.min.x + sw, rect.max.y - sw),
+                sw,
+                std::f32::consts::FRAC_PI_2,
+            ),
+        ];
+        let arc = |(center, radius, start_angle): (Pos2, f32, f32)| -> Vec<Pos2> {
+            (0..=CORNER_SEGMENTS)
+                .map(|i| {
+                    let angle = start_angle
+                        + std::f32::consts::FRAC_PI_2 * i as f32 / CORNER_SEGMENTS as f32;
+                    transform(center + Vec2::new(angle.cos(), angle.sin()) * radius)
+                })
+                .collect()
+        };
+        let mut shapes = Vec::new();
+        for (index, corner) in corners.iter().enumerate() {
+            let points = arc(*corner);
+            if corner.1 > 0_f32 {
+                shapes.push(Shape::line(points.clone(), stroke));
+            };
+            // 当前圆角的终点到下一个圆角的起点为直边
+            let edge = [points[CORNER_SEGMENTS], arc(corners[(index + 1) % 4])[0]];
+            match border_style {
+                BorderStyle::Solid => shapes.push(Shape::line_segment(edge, stroke)),
+                BorderStyle::Dashed { dash, gap } => shapes.extend(Shape::dashed_line(
+                    &edge,
+                    stroke,
+                    dash.max(1_f32),
+                    gap.max(0_f32),
+                )),
+                BorderStyle::Dotted => shapes.extend(Shape::dotted_line(
+                    &edge,
+                    stroke.color,
+                    stroke.width * 2_f32,
+                    stroke.width / 2_f32,
+                )),
+            };
+        }
+        shapes
+    }
+
+    /// Checks whether the points form a convex polygon.
+    ///
+    /// 检查顶点是否构成凸多边形。
+    ///
+    /// Self-intersecting shapes such as a five-pointed star turn the same way at every vertex
+    /// but wind around more than once, so they are not treated as convex.
+    ///
+    /// 五角星这样的自相交图形在每个顶点处转向相同，但环绕超过一周，因此不视为凸多边形。
+    fn polygon_is_convex(points: &[Pos2]) -> bool {
+        let length = points.len();
+        let mut sign = 0_f32;
+        let mut turning = 0_f32;
+        // 所有相邻边的叉积同号且总转角为一周时为凸多边形
+        let same_side = points.iter().enumerate().all(|(index, point)| {
+            let edge = points[(index + 1) % length] - *point;
+            let next_edge = points[(index + 2) % length] - points[(index + 1) % length];
+            let cross = edge.x * next_edge.y - edge.y * next_edge.x;
+            turning += cross.atan2(edge.dot(next_edge));
+            if cross == 0_f32 {
+                true
+            } else if sign == 0_f32 {
+                sign = cross.signum();
+                true
+            } else {
+                cross.signum() == sign
+            }
+        });
+        same_side && turning.abs() < 3_f32 * std::f32::consts::PI
+    }
+
+    /// Splits a simple polygon into triangles by ear clipping.
+    ///
+    /// 通过耳切法将简单多边形拆分为三角形。
+    ///
+    /// Returns the vertex indices of each triangle. Collinear vertices are skipped first, and
+    /// self-intersecting polygons are filled as far as possible with a warning.
+    ///
+    /// 返回每个三角形的顶点索引。共线的顶点会先被跳过，自相交的多边形会尽可能填充并输出警告。
+    fn triangulate_polygon(points: &[Pos2]) -> Vec<[u32; 3]> {
+        let cross = |a: Pos2, b: Pos2, c: Pos2| (b - a).x * (c - a).y - (b - a).y * (c - a).x;
+        // 用有向面积判断多边形的环绕方向
+        let orientation = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<f32>()
+            .signum();
+        let mut remaining: Vec<usize> = (0..points.len()).collect();
+        // 共线或重复的顶点构成面积为零的耳朵，会使耳切法停滞，因此先移除
+        let mut index = 0;
+        while remaining.len() > 3 && index < remaining.len() {
+            let length = remaining.len();
+            let [a, b, c] = [
+                remaining[(index + length - 1) % length],
+                remaining[index],
+                remaining[(index + 1) % length],
+            ];
+            if cross(points[a], points[b], points[c]) == 0_f32 {
+                remaining.remove(index);
+                index = index.saturating_sub(1);
+            } else {
+                index += 1;
+            };
+        }
+        let mut triangles = Vec::new();
+        while remaining.len() > 3 {
+            let length = remaining.len();
+            let ear = (0..length).find(|index| {
+                let [a, b, c] = [
+                    remaining[(index + length - 1) % length],
+                    remaining[*index],
+                    remaining[(index + 1) % length],
+                ];
+                cross(points[a], points[b], points[c]) * orientation > 0_f32
+                    && !remaining.iter().any(|other| {
+                        ![a, b, c].contains(other)
+                            && cross(points[a], points[b], points[*other]) * orientation >= 0_f32
+                            && cross(points[b], points[c], points[*other]) * orientation >= 0_f32
+                            && cross(points[c], points[a], points[*other]) * orientation >= 0_f32
+                    })
+            });
+            let Some(index) = ear else {
+                warn!(
+                    "triangulate_polygon: No ear was found, {} vertices of the polygon are left unfilled.",
+                    remaining.len()
+                );
+                break;
+            };
+            triangles.push([
+                remaining[(index + length - 1) % length] as u32,
+                remaining[index] as u32,
+                remaining[(index + 1) % length] as u32,
+            ]);
+            remaining.remove(index);
+        }
+        if remaining.len() == 3 {
+            triangles.push([
+                remaining[0] as u32,
+                remaining[1] as u32,
+                remaining[2] as u32,
+            ]);
+        };
+        triangles
+    }
+
+    /// Builds a nine-patch mesh of an image texture.
+    ///
+    /// 构建图片纹理的九宫格网格。
+    ///
+    /// The corners keep their size in texture pixels while the edges and the center stretch, the
+    /// corners shrink proportionally when the target rectangle is too small to hold them.
+    ///
+    /// 边角保持其纹理像素尺寸，边缘和中心拉伸，目标矩形容纳不下边角时边角按比例缩小。
+    fn nine_patch_mesh(
+        texture: &DebugTextureHandle,
+        rect: Rect,
+        uv: Rect,
+        insets: [f32; 4],
+        color: Color32,
+        rotate_angle: f32,
+        rotate_origin: Pos2,
+    ) -> Mesh {
+        let texture_size = texture.texture_handle.size();
+        let texture_size = [texture_size[0].max(1) as f32, texture_size[1].max(1) as f32];
+        let [left, right, top, bottom] = insets.map(|x| x.max(0_f32));
+        let shrink = |start: f32, end: f32, length: f32| {
+            if start + end > length && start + end > 0_f32 {
+                length / (start + end)
+            } else {
+                1_f32
+            }
+        };
+        let scale_x = shrink(left, right, rect.width());
+        let scale_y = shrink(top, bottom, rect.height());
+        let x_list = [
+            rect.min.x,
+            rect.min.x + left * scale_x,
+            rect.max.x - right * scale_x,
+            rect.max.x,
+        ];
+        let y_list = [
+            rect.min.y,
+            rect.min.y + top * scale_y,
+            rect.max.y - bottom * scale_y,
+            rect.max.y,
+        ];
+        let u_list = [
+            uv.min.x,
+            uv.min.x + left / texture_size[0],
+            uv.max.x - right / texture_size[0],
+            uv.max.x,
+        ];
+        let v_list = [
+            uv.min.y,
+            uv.min.y + top / texture_size[1],
+            uv.max.y - bottom / texture_size[1],
+            uv.max.y,
+        ];
+        let mut mesh = Mesh::with_texture(texture.texture_handle.id());
+        for row in 0..3 {
+            for column in 0..3 {
+                mesh.add_rect_with_uv(
+                    Rect::from_min_max(
+                        Pos2::new(x_list[column], y_list[row]),
+                        Pos2::new(x_list[column + 1], y_list[row + 1]),
+                    ),
+                    Rect::from_min_max(
+                        Pos2::new(u_list[column], v_list[row]),
+                        Pos2::new(u_list[column + 1], v_list[row + 1]),
+                    ),
+                    color,
+                );
+            }
+        }
+        if rotate_angle != 0_f32 {
+            mesh.rotate(Rot2::from_angle(rotate_angle), rotate_origin);
+        };
+        mesh
+    }
+
+    /// Starts a tween animation on a basic front resource.
+    ///
+    /// 为基本前端资源启动补间动画。
+    ///
+    /// Starting another animation with the same name replaces it. Animations advance with the
+    /// total runtime and are cancelled if the target resource is removed.
+    ///
+    /// 使用相同名称启动新动画会替换旧动画。动画随总运行时间推进，目标资源被移除时会被取消。
+    pub fn animate(
+        &mut self,
+        name: &str,
+        target: &RustConstructorId,
+        property: AnimatedProperty,
+        duration: f32,
+        easing: Easing,
+    ) -> Result<(), RustConstructorError> {
+        if !self
+            .basic_front_resource_list
+            .contains(&target.discern_type)
+        {
+            error!(
+                "[ResourceDowncastTypeMismatch]animate: Resource '{}({})' is not a basic front resource.",
+                target.name, target.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' is not a basic front resource.",
+                    target.name, target.discern_type
+                ),
+            });
+        };
+        self.get_basic_front_resource(target)?;
+        self.animations.retain(|x| x.0 != name);
+        self.animations.push((
+            name.to_string(),
+            Animation {
+                target: target.clone(),
+                property,
+                easing,
+                start_time: self.timer.total_time,
+                duration: (duration.max(0_f32) * 1000_f32) as u128,
+                finished: false,
+            },
+        ));
+        self.animation_active = true;
+        Ok(())
+    }
+
+    /// Checks whether a tween animation has finished.
+    ///
+    /// 检查补间动画是否已结束。
+    ///
+    /// Cancelled animations count as finished.
+    ///
+    /// 被取消的动画视为已结束。
+    pub fn animation_finished(&self, name: &str) -> Result<bool, RustConstructorError> {
+        if let Some((_, animation)) = self.animations.iter().find(|x| x.0 == name) {
+            Ok(animation.finished)
+        } else {
+            error!("[AnimationNotFound]animation_finished: Animation '{name}' not found.");
+            Err(RustConstructorError {
+                error_id: RustConstructorError::ANIMATION_NOT_FOUND.to_string(),
+                description: format!("Animation '{name}' not found."),
+            })
+        }
+    }
+
+    /// Advances all running tween animations and writes their values to the targets.
+    ///
+    /// 推进所有正在运行的补间动画并将其值写入目标资源。
+    pub fn update_animations(&mut self) {
+        let mut animations = std::mem::take(&mut self.animations);
+        for (_, animation) in animations.iter_mut().filter(|x| !x.1.finished) {
+            // 目标资源已被移除时取消动画
+            if self.check_resource_exists(&animation.target).is_none() {
+                animation.finished = true;
+                continue;
+            };
+            let elapsed = self.timer.total_time.saturating_sub(animation.start_time);
+            let progress = if animation.duration == 0 {
+                1_f32
+            } else {
+                elapsed as f32 / animation.duration as f32
+            };
+            let eased = animation.easing.apply(progress);
+            let lerp = |start: f32, end: f32| start + (end - start) * eased;
+            match animation.property {
+                AnimatedProperty::Position(start, end) | AnimatedProperty::Size(start, end) => {
+                    let value = [lerp(start[0], end[0]), lerp(start[1], end[1])];
+                    if let Ok(resource) = self.get_basic_front_resource_mut(&animation.target) {
+                        let mut position_size_config = resource.display_position_size_config();
+                        if let AnimatedProperty::Position(_, _) = animation.property {
+                            position_size_config.origin_position = value;
+                        } else {
+                            position_size_config.origin_size = value;
+                        };
+                        resource.modify_position_size_config(position_size_config);
+                    };
+                }
+                AnimatedProperty::Alpha(start, end) => {
+                    let alpha = lerp(start as f32, end as f32).round().clamp(0_f32, 255_f32) as u8;
+                    match &*animation.target.discern_type {
+                        "Image" => {
+                            if let Ok(image) = self.get_resource_mut::<Image>(&animation.target) {
+                                image.alpha = alpha;
+                            };
+                        }
+                        "Text" => {
+                            if let Ok(text) = self.get_resource_mut::<Text>(&animation.target) {
+                                text.alpha = alpha;
+                            };
+                        }
+                        "CustomRect" => {
+                            if let Ok(custom_rect) =
+                                self.get_resource_mut::<CustomRect>(&animation.target)
+                            {
+                                custom_rect.alpha = alpha;
+                            };
+                        }
+                        "Polygon" => {
+                            if let Ok(polygon) = self.get_resource_mut::<Polygon>(&animation.target)
+                            {
+                                polygon.alpha = alpha;
+                            };
+                        }
+                        _ => {}
+                    };
+                }
+            };
+            if progress >= 1_f32 {
+                animation.finished = true;
+            } else {
+                self.animation_active = true;
+            };
+        }
+        // 保留更新期间新启动的动画
+        animations.append(&mut self.animations);
+        self.animations = animations;
+    }
+
+    /// Plays an entrance animation on a resource each time its page is entered.
+    ///
+    /// 每次进入页面时为资源播放入场动画。
+    ///
+    /// The animation is timed from the page runtime, which restarts when `check_updated`
+    /// detects a page change. Images and custom rectangles are supported.
+    ///
+    /// 动画基于页面运行时间计时，该时间会在`check_updated`检测到页面切换时重新开始。支持图像和自定义矩形。
+    pub fn animate_entrance(
+        &mut self,
+        id: &RustConstructorId,
+        preset: EntrancePreset,
+        duration: u128,
+    ) -> Result<(), RustConstructorError> {
+        if !["Image", "CustomRect"].contains(&&*id.discern_type) {
+            error!(
+                "[ResourceDowncastTypeMismatch]animate_entrance: Resource '{}({})' does not support entrance animations.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' does not support entrance animations.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        let Some(index) = self.check_resource_exists(id) else {
+            error!(
+                "[ResourceNotFound]animate_entrance: Resource '{}({})' not found.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError::resource_not_found(id));
+        };
+        // 记录资源的实际名称（可能带有页面作用域前缀）
+        let id = self.rust_constructor_resource[index].id.clone();
+        self.entrance_animations.retain(|x| x.0 != id);
+        self.entrance_animations.push((id, preset, duration.max(1)));
+        Ok(())
+    }
+
+    /// Returns the rect and alpha factor of a running entrance animation.
+    ///
+    /// 返回正在运行的入场动画的矩形和透明度系数。
+    fn entrance_progress(
+        &mut self,
+        id: &RustConstructorId,
+        rect: [[f32; 2]; 2],
+    ) -> Option<([[f32; 2]; 2], f32)> {
+        let (_, preset, duration) = *self.entrance_animations.iter().find(|x| x.0 == *id)?;
+        if self.timer.now_time >= duration {
+            return None;
+        };
+        self.animation_active = true;
+        Some(preset.apply(rect, self.timer.now_time as f32 / duration as f32))
+    }
+
+    /// Runs the given closure with the painting layer switched to `layer_id`.
+    ///
+    /// 将绘制层切换到`layer_id`后运行给定的闭包。
+    ///
+    /// Content drawn inside the closure is painted on that layer, so a layer with a higher
+    /// order (such as `Order::Foreground`) stays above the base layer regardless of call order.
+    /// This is useful for popovers, dropdowns and drag previews.
+    ///
+    /// 闭包内绘制的内容会绘制在该层上，因此更高层级的层（如`Order::Foreground`）无论调用顺序如何
+    /// 都会显示在基础层之上。适用于弹出框、下拉菜单和拖拽预览。
+    pub fn with_layer<R>(
+        &mut self,
+        ui: &mut Ui,
+        layer_id: LayerId,
+        add_contents: impl FnOnce(&mut App, &mut Ui) -> R,
+    ) -> R {
+        ui.with_layer_id(layer_id, |ui| add_contents(self, ui))
+            .inner
+    }
+
+    /// Search for resources in the render list by ID.
+    ///
+    /// 通过ID在渲染列表中查找资源。
+    pub fn get_render_layer_resource(&self, id: &RustConstructorId) -> Option<usize> {
+        self.render_layer.iter().position(|x| &x.0 == id)
+    }
+
+    /// Check whether the resource has obtained the mouse focus.
+    ///
+    /// 检查资源是否获取鼠标焦点。
+    ///
+    /// Use this method to ensure that mouse operations do not trigger
+    /// multiple components simultaneously, causing confusion.
+    ///
+    /// 使用此方法以保证鼠标操作不会同时触发多个组件产生混乱。
+    pub fn resource_get_focus(
+        &self,
+        index: usize,
+        mouse_pos: [f32; 2],
+        need_contains_mouse: bool,
+        ignore_render_layer: Vec<[usize; 2]>,
+    ) -> bool {
+        // 模态对话框打开时其后方的资源都无法获得焦点
+        if self.active_modal.is_some() {
+            return false;
+        };
+        let mut ignore_list = Vec::new();
+        for range in ignore_render_layer {
+            for i in 0..range[1] {
+                ignore_list.push(range[0] + i);
+            }
+        }
+        // 不同窗口中的资源坐标互不相关，不会互相遮挡
+        let window = self.secondary_window_of(&self.render_layer[index].0);
+        for i in index + 1..self.render_layer.len() {
+            let point = self.render_layer[i].1;
+            if mouse_pos[0] >= point[0][0]
+                && mouse_pos[1] >= point[0][1]
+                && mouse_pos[0] <= point[1][0]
+                && mouse_pos[1] <= point[1][1]
+                && !self.render_layer[i].2
+                && !ignore_list.contains(&i)
+                && (self.secondary_windows.is_empty()
+                    || self.secondary_window_of(&self.render_layer[i].0) == window)
+            {
+                return false;
+            };
+        }
+        let target_point = self.render_layer[index].1;
+        !need_contains_mouse
+            || mouse_pos[0] <= target_point[1][0]
+                && mouse_pos[0] >= target_point[0][0]
+                && mouse_pos[1] <= target_point[1][1]
+                && mouse_pos[1] >= target_point[0][1]
+    }
+
+    /// Advances the hover transition of a resource and returns the new progress (0.0-1.0).
+    ///
+    /// 推进资源的悬停过渡并返回新的进度（0.0-1.0）。
+    ///
+    /// The progress moves towards 1.0 while the resource holds the mouse focus and back
+    /// towards 0.0 otherwise, taking 150 milliseconds for a full transition.
+    ///
+    /// 资源获得鼠标焦点时进度向1.0推进，否则回落到0.0，完整过渡耗时150毫秒。
+    pub fn update_hover_progress(&mut self, ui: &Ui, id: &RustConstructorId, progress: f32) -> f32 {
+        let hovered = if let Some(index) = self.get_render_layer_resource(id)
+            && let Some(mouse_pos) = self.resource_input(ui, id, |i| i.pointer.hover_pos())
+        {
+            self.resource_get_focus(index, mouse_pos.into(), true, vec![])
+        } else {
+            false
+        };
+        let step = ui.input(|i| i.stable_dt) / 0.15;
+        let new_progress = if hovered {
+            (progress + step).min(1_f32)
+        } else {
+            (progress - step).max(0_f32)
+        };
+        if new_progress != progress {
+            self.animation_active = true;
+            ui.ctx().request_repaint();
+        };
+        new_progress
+    }
+
+    /// Greys out a switch or makes it interactive again.
+    ///
+    /// 将开关设为禁用状态或重新启用交互。
+    pub fn set_switch_disabled(
+        &mut self,
+        id: &RustConstructorId,
+        disabled: bool,
+    ) -> Result<(), RustConstructorError> {
+        self.get_resource_mut::<Switch>(id)?.disabled = disabled;
+        Ok(())
+    }
+
+    /// Sets the switches that can be focused with Tab and Shift+Tab, in order.
+    ///
+    /// 按顺序设置可以通过Tab和Shift+Tab获得焦点的开关。
+    ///
+    /// The focused switch draws a focus ring and is activated by Enter or Space like a primary
+    /// click. Focus is cleared when the page changes.
+    ///
+    /// 获得焦点的开关会绘制焦点框，按下回车键或空格键时与主键单击效果相同。切换页面时焦点会被清除。
+    pub fn set_focus_order(&mut self, focus_order: Vec<RustConstructorId>) {
+        self.focus_order = focus_order
+            .iter()
+            .map(|id| {
+                // 记录资源的实际名称（可能带有页面作用域前缀）
+                if let Some(index) = self.check_resource_exists(id) {
+                    self.rust_constructor_resource[index].id.clone()
+                } else {
+                    id.clone()
+                }
+            })
+            .collect();
+        self.focus_index = None;
+    }
+
+    /// Checks whether a resource has keyboard focus.
+    ///
+    /// 检查资源是否拥有键盘焦点。
+    #[inline]
+    pub fn is_focused(&self, id: &RustConstructorId) -> bool {
+        self.focus_index
+            .and_then(|index| self.focus_order.get(index))
+            .is_some_and(|x| x == id)
+    }
+
+    /// Moves keyboard focus when Tab or Shift+Tab is pressed.
+    ///
+    /// 按下Tab或Shift+Tab时移动键盘焦点。
+    pub fn update_focus(&mut self, ui: &Ui) {
+        // 模态对话框打开时焦点只在其按钮之间切换
+        if self.active_modal.is_some() {
+            return;
+        };
+        let focus_order: Vec<RustConstructorId> = self
+            .focus_order
+            .iter()
+            .filter(|x| self.check_resource_exists(x).is_some())
+            .cloned()
+            .collect();
+        if focus_order.len() != self.focus_order.len() {
+            self.focus_order = focus_order;
+            self.focus_index = None;
+        };
+        let length = self.focus_order.len();
+        if length == 0 {
+            self.focus_index = None;
+            return;
+        };
+        let (tab_pressed, shift) = ui.input(|i| (i.key_pressed(Key::Tab), i.modifiers.shift));
+        if tab_pressed {
+            self.focus_index = Some(match self.focus_index {
+                Some(index) if shift => (index + length - 1) % length,
+                Some(index) => (index + 1) % length,
+                None if shift => length - 1,
+                None => 0,
+            });
+        };
+    }
+
+    /// Enables or disables gamepad navigation.
+    ///
+    /// 启用或禁用手柄导航。
+    ///
+    /// The mapped buttons move the keyboard focus between the resources in `focus_order` by
+    /// their position on screen and activate the focused switch, so the focus ring shows the
+    /// gamepad selection as well.
+    ///
+    /// 映射的按钮会按屏幕位置在`focus_order`中的资源之间移动键盘焦点，并激活获得焦点的开关，
+    /// 因此焦点框同样会显示手柄的选择。
+    #[cfg(feature = "gamepad")]
+    pub fn enable_gamepad(&mut self, enable: bool) -> Result<(), RustConstructorError> {
+        if !enable {
+            self.gamepad = None;
+            return Ok(());
+        };
+        if self.gamepad.is_none() {
+            match Gilrs::new() {
+                Ok(gilrs) => self.gamepad = Some(gilrs),
+                Err(err) => {
+                    error!("[GamepadInitFailed]enable_gamepad: {err}.");
+                    return Err(RustConstructorError {
+                        error_id: RustConstructorError::GAMEPAD_INIT_FAILED.to_string(),
+                        description: format!("{err}."),
+                    });
+                }
+            };
+        };
+        Ok(())
+    }
+
+    /// Replaces the gamepad button mapping.
+    ///
+    /// 替换手柄按钮映射。
+    #[cfg(feature = "gamepad")]
+    pub fn set_gamepad_mapping(&mut self, mapping: &[(Button, GamepadAction)]) {
+        self.gamepad_mapping = mapping.to_owned();
+    }
+
+    /// Handles the gamepad buttons pressed since the last frame.
+    ///
+    /// 处理自上一帧以来按下的手柄按钮。
+    #[cfg(feature = "gamepad")]
+    pub fn update_gamepad(&mut self) -> Result<(), RustConstructorError> {
+        self.gamepad_activated = false;
+        let mut actions = Vec::new();
+        if let Some(gilrs) = &mut self.gamepad {
+            while let Some(event) = gilrs.next_event() {
+                if let EventType::ButtonPressed(button, _) = event.event
+                    && let Some(action) = self.gamepad_mapping.iter().find(|x| x.0 == button)
+                {
+                    actions.push(action.1);
+                };
+            }
+        };
+        // 模态对话框打开时不移动焦点
+        if self.active_modal.is_some() {
+            return Ok(());
+        };
+        for action in actions {
+            match action {
+                GamepadAction::Activate => self.gamepad_activated = true,
+                GamepadAction::Back => {
+                    if self.page_history.is_empty() {
+                        self.focus_index = None;
+                    } else {
+                        self.pop_page()?;
+                    };
+                }
+                _ => self.move_focus_towards(action),
+            };
+        }
+        Ok(())
+    }
+
+    /// Moves focus to the nearest resource in a direction, the first resource gets focus when
+    /// nothing is focused.
+    ///
+    /// 将焦点移动到某个方向上最近的资源，没有资源获得焦点时第一个资源获得焦点。
+    #[cfg(feature = "gamepad")]
+    fn move_focus_towards(&mut self, action: GamepadAction) {
+        let center_list: Vec<Option<[f32; 2]>> = self
+            .focus_order
+            .iter()
+            .map(|id| {
+                self.resource_rect(id).ok().map(|[position, size]| {
+                    [position[0] + size[0] / 2_f32, position[1] + size[1] / 2_f32]
+                })
+            })
+            .collect();
+        let Some(current) = self
+            .focus_index
+            .and_then(|index| center_list.get(index).copied().flatten())
+        else {
+            if !self.focus_order.is_empty() {
+                self.focus_index = Some(0);
+            };
+            return;
+        };
+        let mut nearest: Option<(usize, f32)> = None;
+        for (index, center) in center_list.iter().enumerate() {
+            let Some(center) = center else {
+                continue;
+            };
+            let [dx, dy] = [center[0] - current[0], center[1] - current[1]];
+            // 沿移动方向的距离和偏离移动方向的距离
+            let (along, across) = match action {
+                GamepadAction::Up => (-dy, dx.abs()),
+                GamepadAction::Down => (dy, dx.abs()),
+                GamepadAction::Left => (-dx, dy.abs()),
+                GamepadAction::Right => (dx, dy.abs()),
+                GamepadAction::Activate | GamepadAction::Back => return,
+            };
+            if along <= 0_f32 {
+                continue;
+            };
+            // 偏离方向的距离权重更高，使焦点优先沿直线移动
+            let score = along + across * 2_f32;
+            if nearest.is_none_or(|x| score < x.1) {
+                nearest = Some((index, score));
+            };
+        }
+        if let Some((index, _)) = nearest {
+            self.focus_index = Some(index);
+        };
+    }
+
+    /// Registers a keyboard shortcut, replacing any shortcut with the same name.
+    ///
+    /// 注册键盘快捷键，会替换同名的快捷键。
+    ///
+    /// The modifiers have to match exactly, `Modifiers::COMMAND` stands for Ctrl on Windows and
+    /// Linux and Cmd on macOS. When `page` is set the shortcut only triggers on that page.
+    ///
+    /// 修饰键需要精确匹配，`Modifiers::COMMAND`在Windows和Linux上代表Ctrl，在macOS上代表Cmd。
+    /// 设置`page`后快捷键只会在该页面触发。
+    pub fn register_shortcut(
+        &mut self,
+        name: &str,
+        key: Key,
+        modifiers: Modifiers,
+        page: Option<&str>,
+    ) {
+        self.shortcuts.retain(|x| x.name != name);
+        self.shortcuts.push(Shortcut {
+            name: name.to_string(),
+            key,
+            modifiers,
+            page: page.map(|x| x.to_string()),
+            triggered: false,
+        });
+    }
+
+    /// Removes a keyboard shortcut.
+    ///
+    /// 移除键盘快捷键。
+    pub fn unregister_shortcut(&mut self, name: &str) {
+        self.shortcuts.retain(|x| x.name != name);
+    }
+
+    /// Checks the registered shortcuts against this frame's input, it is called by the page
+    /// pipeline.
+    ///
+    /// 根据本帧的输入检查已注册的快捷键，页面流程会调用此方法。
+    pub fn update_shortcuts(&mut self, ui: &Ui) {
+        let current_page = self.current_page.clone();
+        ui.input(|i| {
+            for shortcut in &mut self.shortcuts {
+                // 未被消费的触发状态只保留一帧
+                shortcut.triggered = shortcut.page.as_ref().is_none_or(|x| *x == current_page)
+                    && i.key_pressed(shortcut.key)
+                    && i.modifiers.matches_exact(shortcut.modifiers);
+            }
+        });
+    }
+
+    /// Returns whether a shortcut was pressed this frame and marks it as handled.
+    ///
+    /// 返回快捷键是否在本帧被按下，并将其标记为已处理。
+    pub fn consume_shortcut(&mut self, name: &str) -> bool {
+        self.shortcuts
+            .iter_mut()
+            .find(|x| x.name == name)
+            .is_some_and(|x| std::mem::take(&mut x.triggered))
+    }
+
+    /// Makes a basic front resource behave like a lightweight button, replacing its previous
+    /// clickable style.
+    ///
+    /// 使基本前端资源表现为轻量按钮，会替换其之前的可点击样式。
+    ///
+    /// The resource gets the overlays of `style` while hovered or pressed, and a click is
+    /// reported through `consume_click(on_click_flag)` for the frame it happens in.
+    ///
+    /// 资源在悬停或按下时会显示`style`的叠加层，点击会在发生的那一帧通过`consume_click(on_click_flag)`报告。
+    pub fn make_clickable(
+        &mut self,
+        id: &RustConstructorId,
+        style: ClickableStyle,
+        on_click_flag: &str,
+    ) -> Result<(), RustConstructorError> {
+        if !self.basic_front_resource_list.contains(&id.discern_type) {
+            error!(
+                "[ResourceDowncastTypeMismatch]make_clickable: Resource '{}({})' is not a basic front resource.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' is not a basic front resource.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        self.get_basic_front_resource(id)?;
+        let target = self.resolved_id(id)?;
+        self.clickables.retain(|x| x.target != target);
+        self.clickables.push(Clickable {
+            target,
+            style,
+            on_click_flag: on_click_flag.to_string(),
+            hovered: false,
+            pressed: false,
+            clicked: false,
+        });
+        Ok(())
+    }
+
+    /// Turns a clickable resource back into a plain one.
+    ///
+    /// 将可点击资源恢复为普通资源。
+    pub fn remove_clickable(&mut self, id: &RustConstructorId) {
+        let target = match self.check_resource_exists(id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => id.clone(),
+        };
+        self.clickables.retain(|x| x.target != target);
+    }
+
+    /// Updates the hover, pressed and click state of the clickable resources, it is called by
+    /// the page pipeline.
+    ///
+    /// 更新可点击资源的悬停、按下和点击状态，页面流程会调用此方法。
+    pub fn update_clickables(&mut self, ui: &Ui) {
+        for count in 0..self.clickables.len() {
+            let target = self.clickables[count].target.clone();
+            let (mouse_pos, primary_pressed, primary_released, primary_down) =
+                self.resource_input(ui, &target, |i| {
+                    (
+                        i.pointer.hover_pos(),
+                        i.pointer.primary_pressed(),
+                        i.pointer.primary_released(),
+                        i.pointer.primary_down(),
+                    )
+                });
+            let usable = self
+                .get_basic_front_resource(&target)
+                .is_ok_and(|x| x.display_display_info().enable && !x.display_display_info().hidden);
+            let hovered = usable
+                && if let Some(index) = self.get_render_layer_resource(&target)
+                    && let Some(mouse_pos) = mouse_pos
+                {
+                    self.resource_get_focus(index, mouse_pos.into(), true, vec![])
+                } else {
+                    false
+                };
+            let clickable = &mut self.clickables[count];
+            // 只有在资源上开始并在资源上松开的按压才算作点击
+            clickable.clicked = clickable.pressed && primary_released && hovered;
+            if hovered && primary_pressed {
+                clickable.pressed = true;
+            } else if !primary_down || !usable {
+                clickable.pressed = false;
+            };
+            if clickable.hovered != hovered {
+                ui.ctx().request_repaint();
+            };
+            if hovered && let Some(cursor) = clickable.style.hover_cursor {
+                ui.ctx().set_cursor_icon(cursor);
+            };
+            clickable.hovered = hovered;
+        }
+    }
+
+    /// Returns whether a clickable resource with the flag was clicked this frame and marks the
+    /// click as handled.
+    ///
+    /// 返回带有该标记的可点击资源是否在本帧被点击，并将点击标记为已处理。
+    pub fn consume_click(&mut self, on_click_flag: &str) -> bool {
+        self.clickables
+            .iter_mut()
+            .filter(|x| x.on_click_flag == on_click_flag)
+            .fold(false, |clicked, x| {
+                std::mem::take(&mut x.clicked) || clicked
+            })
+    }
+
+    /// Makes a basic front resource draggable with the pointer.
+    ///
+    /// 使基本前端资源可以用指针拖动。
+    ///
+    /// While dragged the resource follows the pointer, releasing it reports a drop through
+    /// `consume_drop`. A drop on empty space returns the resource to its origin.
+    ///
+    /// 拖动时资源跟随指针，松开时会通过`consume_drop`报告放置。放置在空白处时资源会回到原位置。
+    pub fn make_draggable(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        let target = self.drag_and_drop_target(id, "make_draggable")?;
+        if !self.draggables.contains(&target) {
+            self.draggables.push(target);
+        };
+        Ok(())
+    }
+
+    /// Makes a basic front resource accept dropped resources.
+    ///
+    /// 使基本前端资源接受放置的资源。
+    pub fn make_drop_target(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        let target = self.drag_and_drop_target(id, "make_drop_target")?;
+        if !self.drop_targets.contains(&target) {
+            self.drop_targets.push(target);
+        };
+        Ok(())
+    }
+
+    /// Stops a resource from being dragged or accepting drops.
+    ///
+    /// 使资源不再可拖动或接受放置。
+    pub fn remove_drag_and_drop(&mut self, id: &RustConstructorId) {
+        let target = match self.check_resource_exists(id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => id.clone(),
+        };
+        self.draggables.retain(|x| *x != target);
+        self.drop_targets.retain(|x| *x != target);
+        if self.drag_state.as_ref().is_some_and(|x| x.source == target) {
+            self.drag_state = None;
+        };
+    }
+
+    /// Validates a resource used for drag and drop and returns its actual id.
+    ///
+    /// 校验用于拖放的资源并返回其实际ID。
+    fn drag_and_drop_target(
+        &self,
+        id: &RustConstructorId,
+        method: &str,
+    ) -> Result<RustConstructorId, RustConstructorError> {
+        if !self.basic_front_resource_list.contains(&id.discern_type) {
+            error!(
+                "[ResourceDowncastTypeMismatch]{}: Resource '{}({})' is not a basic front resource.",
+                method, id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' is not a basic front resource.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        self.get_basic_front_resource(id)?;
+        self.resolved_id(id)
+    }
+
+    /// Updates the drag held on a draggable resource and reports drops, it is called by the
+    /// page pipeline.
+    ///
+    /// 更新按住在可拖动资源上的拖动并报告放置，页面流程会调用此方法。
+    pub fn update_drag_and_drop(&mut self, ui: &Ui) {
+        // 拖动开始前指针需要移动的最小距离
+        const DRAG_THRESHOLD: f32 = 4_f32;
+        self.drop_event = None;
+        if self.drag_state.is_none()
+            && let Some((source, mouse_pos)) = self.draggables.iter().find_map(|x| {
+                let (primary_pressed, mouse_pos) = self.resource_input(ui, x, |i| {
+                    (i.pointer.primary_pressed(), i.pointer.hover_pos())
+                });
+                let mouse_pos = mouse_pos.filter(|_| primary_pressed)?;
+                self.drag_and_drop_hovered(x, mouse_pos.into())
+                    .then_some((x.clone(), mouse_pos))
+            })
+        {
+            self.drag_state = Some(DragState {
+                source,
+                press_position: mouse_pos.into(),
+                offset: [0_f32, 0_f32],
+                dragging: false,
+            });
+        };
+        let Some(mut drag_state) = self.drag_state.take() else {
+            return;
+        };
+        // 拖动只在源资源所在的窗口中进行
+        let (mouse_pos, primary_released, primary_down) =
+            self.resource_input(ui, &drag_state.source, |i| {
+                (
+                    i.pointer.hover_pos(),
+                    i.pointer.primary_released(),
+                    i.pointer.primary_down(),
+                )
+            });
+        let window = self.secondary_window_of(&drag_state.source);
+        if let Some(mouse_pos) = mouse_pos {
+            drag_state.offset = [
+                mouse_pos.x - drag_state.press_position[0],
+                mouse_pos.y - drag_state.press_position[1],
+            ];
+            if !drag_state.dragging && Vec2::from(drag_state.offset).length() >= DRAG_THRESHOLD {
+                drag_state.dragging = true;
+            };
+        };
+        if primary_released || !primary_down {
+            // 只报告真正发生的拖动，放置在空白处时源资源不会移动，因此自然回到原位置
+            if drag_state.dragging {
+                let target = mouse_pos.and_then(|mouse_pos| {
+                    self.drop_targets
+                        .iter()
+                        .find(|x| {
+                            **x != drag_state.source
+                                && self.secondary_window_of(x) == window
+                                && self.drag_and_drop_hovered(x, mouse_pos.into())
+                        })
+                        .cloned()
+                });
+                self.drop_event = Some(DropEvent {
+                    source: drag_state.source,
+                    target,
+                });
+            };
+            ui.ctx().request_repaint();
+            return;
+        };
+        if drag_state.dragging {
+            ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+            ui.ctx().request_repaint();
+        };
+        self.drag_state = Some(drag_state);
+    }
+
+    /// Returns whether the pointer is on an enabled and visible drag and drop resource.
+    ///
+    /// 返回指针是否位于已启用且可见的拖放资源上。
+    fn drag_and_drop_hovered(&self, id: &RustConstructorId, mouse_pos: [f32; 2]) -> bool {
+        self.get_basic_front_resource(id)
+            .is_ok_and(|x| x.display_display_info().enable && !x.display_display_info().hidden)
+            && self
+                .get_render_layer_resource(id)
+                .is_some_and(|index| self.resource_get_focus(index, mouse_pos, true, vec![]))
+    }
+
+    /// Returns the resource being dragged and its offset from its origin.
+    ///
+    /// 返回正在被拖动的资源及其相对原位置的偏移。
+    pub fn dragged_resource(&self) -> Option<(RustConstructorId, [f32; 2])> {
+        self.drag_state
+            .as_ref()
+            .filter(|x| x.dragging)
+            .map(|x| (x.source.clone(), x.offset))
+    }
+
+    /// Returns the drop that happened this frame and marks it as handled.
+    ///
+    /// 返回本帧发生的放置并将其标记为已处理。
+    pub fn consume_drop(&mut self) -> Option<DropEvent> {
+        self.drop_event.take()
+    }
+
+    /// Draws a ring around the resource with keyboard focus.
+    ///
+    /// 在拥有键盘焦点的资源周围绘制焦点框。
+    pub fn draw_focus_ring(&self, ui: &Ui) {
+        if let Some(id) = self
+            .focus_index
+            .and_then(|index| self.focus_order.get(index))
+            && let Ok([position, size]) = self.resource_rect(id)
+        {
+            ui.painter().rect_stroke(
+                Rect::from_min_size(position.into(), size.into()).expand(2_f32),
+                CornerRadius::same(4),
+                Stroke::new(2_f32, Color32::from_rgb(90, 160, 255)),
+                StrokeKind::Outside,
+            );
+        };
+    }
+
+    /// Groups switches so that turning one on resets the others to state 0.
+    ///
+    /// 将开关分组，打开其中一个开关时其他开关会重置为状态0。
+    ///
+    /// The group is stored as a `SwitchGroup` resource, adding a group with an existing name
+    /// replaces its members.
+    ///
+    /// 开关组以`SwitchGroup`资源存储，添加已存在名称的组会替换其成员。
+    pub fn add_switch_group(
+        &mut self,
+        group_name: &str,
+        switch_ids: &[RustConstructorId],
+    ) -> Result<(), RustConstructorError> {
+        let mut switches = Vec::new();
+        for id in switch_ids {
+            if id.discern_type != "Switch" {
+                error!(
+                    "[ResourceDowncastTypeMismatch]add_switch_group: Resource '{}({})' is not a switch.",
+                    id.name, id.discern_type
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                    description: format!(
+                        "Resource '{}({})' is not a switch.",
+                        id.name, id.discern_type
+                    ),
+                });
+            };
+            if let Some(index) = self.check_resource_exists(id) {
+                // 记录资源的实际名称（可能带有页面作用域前缀）
+                switches.push(self.rust_constructor_resource[index].id.name.clone());
+            } else {
+                error!(
+                    "[ResourceNotFound]add_switch_group: Resource '{}({})' not found.",
+                    id.name, id.discern_type
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
+                    description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
+                });
+            };
+        }
+        let group_id = build_id(group_name, "SwitchGroup");
+        if self.check_resource_exists(&group_id).is_some() {
+            self.get_resource_mut::<SwitchGroup>(&group_id)?.switches = switches;
+            Ok(())
+        } else {
+            self.add_resource(group_name, SwitchGroup::default().switches(&switches))
+        }
+    }
+
+    /// Adds an ordered group of resources, groups can contain other groups.
+    ///
+    /// 添加有序资源组，组中可以包含其他组。
+    ///
+    /// The group is stored as a `ResourceGroup` resource, adding a group with an existing name
+    /// replaces its members. A group that would end up containing itself is rejected.
+    ///
+    /// 资源组以`ResourceGroup`资源存储，添加已存在名称的组会替换其成员。会导致组包含自身的组会被拒绝。
+    pub fn add_group(
+        &mut self,
+        group_name: &str,
+        member_ids: &[RustConstructorId],
+    ) -> Result<(), RustConstructorError> {
+        let group_id = match self.check_resource_exists(&build_id(group_name, "ResourceGroup")) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => build_id(self.scoped_name(group_name), "ResourceGroup"),
+        };
+        let mut members = Vec::new();
+        for id in member_ids {
+            let Some(index) = self.check_resource_exists(id) else {
+                error!(
+                    "[ResourceNotFound]add_group: Resource '{}({})' not found.",
+                    id.name, id.discern_type
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
+                    description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
+                });
+            };
+            // 记录资源的实际名称（可能带有页面作用域前缀）
+            let member = self.rust_constructor_resource[index].id.clone();
+            if member == group_id || self.group_contains(&member, &group_id, &mut Vec::new()) {
+                error!(
+                    "[GroupCycle]add_group: Adding '{}({})' to group '{}' would create a cycle.",
+                    member.name, member.discern_type, group_id.name
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::GROUP_CYCLE.to_string(),
+                    description: format!(
+                        "Adding '{}({})' to group '{}' would create a cycle.",
+                        member.name, member.discern_type, group_id.name
+                    ),
+                });
+            };
+            members.push(member);
+        }
+        if self.check_resource_exists(&group_id).is_some() {
+            self.get_resource_mut::<ResourceGroup>(&group_id)?.members = members;
+            Ok(())
+        } else {
+            self.add_resource(&group_id.name, ResourceGroup::default().members(&members))
+        }
+    }
+
+    /// Returns whether a group contains the target directly or through nested groups.
+    ///
+    /// 返回组是否直接或通过嵌套组包含目标。
+    fn group_contains(
+        &self,
+        group_id: &RustConstructorId,
+        target: &RustConstructorId,
+        visited: &mut Vec<RustConstructorId>,
+    ) -> bool {
+        if group_id.discern_type != "ResourceGroup" || visited.contains(group_id) {
+            return false;
+        };
+        visited.push(group_id.clone());
+        self.get_resource::<ResourceGroup>(group_id).is_ok_and(|x| {
+            x.members
+                .iter()
+                .any(|member| member == target || self.group_contains(member, target, visited))
+        })
+    }
+
+    /// Returns the resources of a group in drawing order with nested groups expanded.
+    ///
+    /// 按绘制顺序返回组内的资源，嵌套的组会被展开。
+    pub fn group_members(
+        &self,
+        group_name: &str,
+    ) -> Result<Vec<RustConstructorId>, RustConstructorError> {
+        let mut members = Vec::new();
+        self.collect_group_members(
+            &build_id(group_name, "ResourceGroup"),
+            &mut members,
+            &mut Vec::new(),
+        )?;
+        Ok(members)
+    }
+
+    /// Appends the resources of a group to the list with nested groups expanded.
+    ///
+    /// 将组内的资源追加到列表中，嵌套的组会被展开。
+    fn collect_group_members(
+        &self,
+        group_id: &RustConstructorId,
+        members: &mut Vec<RustConstructorId>,
+        visited: &mut Vec<RustConstructorId>,
+    ) -> Result<(), RustConstructorError> {
+        let group = self.get_resource::<ResourceGroup>(group_id)?;
+        visited.push(group_id.clone());
+        for member in &group.members {
+            if member.discern_type == "ResourceGroup" {
+                if !visited.contains(member) {
+                    self.collect_group_members(member, members, visited)?;
+                };
+            } else {
+                members.push(member.clone());
+            };
+        }
+        Ok(())
+    }
+
+    /// Moves every basic front resource in a group by [dx, dy].
+    ///
+    /// 将组内的所有基本前端资源移动[dx, dy]。
+    ///
+    /// The offset is added to the `origin_position` of each resource, other members are left
+    /// unchanged.
+    ///
+    /// 偏移量会加到每个资源的`origin_position`上，其他成员保持不变。
+    pub fn move_group(
+        &mut self,
+        group_name: &str,
+        dx: f32,
+        dy: f32,
+    ) -> Result<(), RustConstructorError> {
+        for id in self.group_members(group_name)? {
+            if self.basic_front_resource_list.contains(&id.discern_type) {
+                let resource = self.get_basic_front_resource_mut(&id)?;
+                let mut position_size_config = resource.display_position_size_config();
+                position_size_config.origin_position[0] += dx;
+                position_size_config.origin_position[1] += dy;
+                resource.modify_position_size_config(position_size_config);
+            };
+        }
+        Ok(())
+    }
+
+    /// Hides every basic front resource in a group.
+    ///
+    /// 隐藏组内的所有基本前端资源。
+    pub fn hide_group(&mut self, group_name: &str) -> Result<(), RustConstructorError> {
+        self.set_group_hidden(group_name, true)
+    }
+
+    /// Shows every basic front resource in a group.
+    ///
+    /// 显示组内的所有基本前端资源。
+    pub fn show_group(&mut self, group_name: &str) -> Result<(), RustConstructorError> {
+        self.set_group_hidden(group_name, false)
+    }
+
+    /// Sets whether the basic front resources in a group are hidden.
+    ///
+    /// 设置组内的基本前端资源是否隐藏。
+    fn set_group_hidden(
+        &mut self,
+        group_name: &str,
+        hidden: bool,
+    ) -> Result<(), RustConstructorError> {
+        for id in self.group_members(group_name)? {
+            if self.basic_front_resource_list.contains(&id.discern_type) {
+                let resource = self.get_basic_front_resource_mut(&id)?;
+                let mut display_info = resource.display_display_info();
+                display_info.hidden = hidden;
+                resource.modify_display_info(display_info);
+            };
+        }
+        Ok(())
+    }
+
+    /// Uses every resource in a group in drawing order.
+    ///
+    /// 按绘制顺序使用组内的所有资源。
+    pub fn draw_group(
+        &mut self,
+        group_name: &str,
+        ui: &mut Ui,
+    ) -> Result<(), RustConstructorError> {
+        for id in self.group_members(group_name)? {
+            self.use_resource(&id, None, ui)?;
+        }
+        Ok(())
+    }
+
+    /// Adds a labeled checkbox, a two-state switch whose state 1 means checked.
+    ///
+    /// 添加一个带标签的复选框，即状态1表示选中的双状态开关。
+    ///
+    /// The box is 18 pixels wide with its top-left corner at `position`, the label is placed to
+    /// its right. The result is an ordinary `Switch` named `name`, so it can be restyled or
+    /// watched through `check_switch_data` like any other switch.
+    ///
+    /// 方框宽18像素，左上角位于`position`，标签位于其右侧。结果是一个名为`name`的普通`Switch`，
+    /// 因此可以像其他开关一样修改样式或通过`check_switch_data`监听。
+    pub fn add_checkbox(
+        &mut self,
+        name: &str,
+        label: &str,
+        initial: bool,
+        position: [f32; 2],
+    ) -> Result<(), RustConstructorError> {
+        const BOX_SIZE: f32 = 18_f32;
+        let box_config = |color: [u8; 3], border_color: [u8; 3]| {
+            BackgroundType::CustomRect(
+                CustomRectConfig::default()
+                    .position_size_config(Some(
+                        PositionSizeConfig::default()
+                            .origin_position(position[0], position[1])
+                            .origin_size(BOX_SIZE, BOX_SIZE),
+                    ))
+                    .rounding(Some(3_f32))
+                    .color(Some(color))
+                    .alpha(Some(255))
+                    .border_width(Some(1.5_f32))
+                    .border_color(Some(border_color))
+                    .border_alpha(Some(255)),
+            )
+        };
+        let appearance = |background_config| SwitchAppearanceConfig {
+            background_config,
+            text_config: TextConfig::default(),
+            hint_text_config: TextConfig::default(),
+        };
+        // 依次为未选中、未选中悬停、选中、选中悬停
+        let appearances = [
+            appearance(box_config([255, 255, 255], [140, 140, 140])),
+            appearance(box_config([235, 242, 255], [90, 160, 255])),
+            appearance(box_config([90, 160, 255], [90, 160, 255])),
+            appearance(box_config([110, 175, 255], [60, 130, 230])),
+        ];
+        self.add_resource(
+            name,
+            Switch::default()
+                .appearance(&appearances)
+                .background_type(&appearances[0].background_config)
+                .text_config(
+                    &TextConfig::default()
+                        .position_size_config(Some(
+                            PositionSizeConfig::default()
+                                .origin_position(
+                                    position[0] + BOX_SIZE + 8_f32,
+                                    position[1] + BOX_SIZE / 2_f32,
+                                )
+                                .display_method(HorizontalAlign::Left, VerticalAlign::Center),
+                        ))
+                        .content(Some(label.to_string()))
+                        .font_size(Some(16_f32)),
+                )
+                .hint_text_config(&TextConfig::default().content(Some(String::new())))
+                .enable_animation(true, false)
+                .state_amount(2)
+                .click_method(vec![SwitchClickConfig {
+                    click_method: PointerButton::Primary,
+                    action: true,
+                }]),
+        )?;
+        self.get_resource_mut::<Switch>(&build_id(name, "Switch"))?
+            .state = initial as usize;
+        Ok(())
+    }
+
+    /// Returns whether a checkbox is checked.
+    ///
+    /// 返回复选框是否被选中。
+    pub fn checkbox_value(&self, name: &str) -> Result<bool, RustConstructorError> {
+        Ok(self.check_switch_data(name)?.state != 0)
+    }
+
+    /// Checks or unchecks a checkbox without counting as a click.
+    ///
+    /// 选中或取消选中复选框，不计为一次点击。
+    pub fn set_checkbox_value(
+        &mut self,
+        name: &str,
+        value: bool,
+    ) -> Result<(), RustConstructorError> {
+        let switch = self.get_resource_mut::<Switch>(&build_id(name, "Switch"))?;
+        if switch.state_amount != 2 {
+            error!(
+                "[SwitchAppearanceConfigMismatch]set_checkbox_value: Checkbox '{}' must have 2 states, found {}.",
+                name, switch.state_amount
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::SWITCH_APPEARANCE_CONFIG_MISMATCH.to_string(),
+                description: format!(
+                    "Checkbox '{}' must have 2 states, found {}.",
+                    name, switch.state_amount
+                ),
+            });
+        };
+        switch.state = value as usize;
+        Ok(())
+    }
+
+    /// Attaches a tooltip to a basic front resource, or updates the existing one.
+    ///
+    /// 为基本前端资源附加工具提示，或更新已有的工具提示。
+    ///
+    /// The tooltip is a text resource named `{name}{type}Tooltip`, which can be styled like any
+    /// other text. It appears near the pointer after it rests on the target for `delay` seconds.
+    ///
+    /// 工具提示是一个名为`{name}{type}Tooltip`的文本资源，可以像其他文本一样设置样式。
+    /// 指针在目标上停留`delay`秒后，它会出现在指针附近。
+    pub fn attach_tooltip(
+        &mut self,
+        id: &RustConstructorId,
+        content: &str,
+        delay: f32,
+    ) -> Result<(), RustConstructorError> {
+        if !self.basic_front_resource_list.contains(&id.discern_type) {
+            error!(
+                "[ResourceDowncastTypeMismatch]attach_tooltip: Resource '{}({})' is not a basic front resource.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' is not a basic front resource.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        self.get_basic_front_resource(id)?;
+        let text_name = format!("{}{}Tooltip", id.name, id.discern_type);
+        let delay = (delay.max(0_f32) * 1000_f32) as u128;
+        if let Some(tooltip) = self.tooltips.iter_mut().find(|x| x.target == *id) {
+            tooltip.delay = delay;
+            let text_name = tooltip.text_name.clone();
+            self.get_resource_mut::<Text>(&build_id(text_name, "Text"))?
+                .content = content.to_string();
+            return Ok(());
+        };
+        let tags = [
+            ["citer_name".to_string(), id.name.clone()],
+            ["citer_type".to_string(), id.discern_type.clone()],
+        ];
+        self.add_resource(
+            &text_name,
+            Text::default()
+                .content(content)
+                .font_size(14_f32)
+                .background_color(40, 40, 40)
+                .ignore_render_layer(true)
+                .hidden(true)
+                .alpha(0)
+                .tags(&tags, false)
+                .tags(
+                    &[
+                        ["disable_x_scrolling".to_string(), "".to_string()],
+                        ["disable_y_scrolling".to_string(), "".to_string()],
+                    ],
+                    false,
+                ),
+        )?;
+        self.add_resource(
+            &format!("{text_name}StartHoverTime"),
+            SplitTime::default().tags(&tags, false),
+        )?;
+        self.add_resource(
+            &format!("{text_name}FadeAnimation"),
+            SplitTime::default().tags(&tags, false),
+        )?;
+        self.tooltips.push(Tooltip {
+            target: id.clone(),
+            text_name,
+            delay,
+            last_frame_hovered: false,
+        });
+        Ok(())
+    }
+
+    /// Shows, moves and fades the attached tooltips, it is called by the page pipeline.
+    ///
+    /// 显示、移动和淡出已附加的工具提示，页面流程会调用此方法。
+    pub fn update_tooltips(&mut self, ui: &mut Ui) -> Result<(), RustConstructorError> {
+        // 移除目标已被删除的工具提示
+        let tooltips: Vec<Tooltip> = self
+            .tooltips
+            .iter()
+            .filter(|x| {
+                self.check_resource_exists(&x.target).is_some()
+                    && self
+                        .check_resource_exists(&build_id(&x.text_name, "Text"))
+                        .is_some()
+            })
+            .cloned()
+            .collect();
+        self.tooltips = tooltips.clone();
+        for (count, tooltip) in tooltips.iter().enumerate() {
+            let text_id = build_id(&tooltip.text_name, "Text");
+            let start_hover_time = format!("{}StartHoverTime", tooltip.text_name);
+            let fade_animation = format!("{}FadeAnimation", tooltip.text_name);
+            let mut text = self.get_resource::<Text>(&text_id)?.clone();
+            let target_hidden = self
+                .get_basic_front_resource(&tooltip.target)?
+                .display_display_info()
+                .hidden;
+            let mut hovered = false;
+            if let Some(index) = self.get_render_layer_resource(&tooltip.target)
+                && let Some(mouse_pos) =
+                    self.resource_input(ui, &tooltip.target, |i| i.pointer.hover_pos())
+                && self.resource_get_focus(index, mouse_pos.into(), true, vec![])
+                && !target_hidden
+            {
+                if !tooltip.last_frame_hovered {
+                    self.reset_split_time(&start_hover_time)?;
+                } else if self.timer.total_time - self.get_split_time(&start_hover_time)?[1]
+                    >= tooltip.delay
+                    || text.alpha != 0
+                {
+                    text.alpha = 255;
+                    text.basic_front_resource_config
+                        .position_size_config
+                        .origin_position = [mouse_pos.x, mouse_pos.y];
+                } else {
+                    // 等待延迟结束
+                    self.animation_active = true;
+                };
+                // 靠近窗口边缘时翻转到指针的另一侧
+                text.basic_front_resource_config
+                    .position_size_config
+                    .display_method
+                    .0 = if mouse_pos.x + text.actual_size[0] <= ui.ctx().content_rect().width() {
+                    HorizontalAlign::Left
+                } else {
+                    HorizontalAlign::Right
+                };
+                text.basic_front_resource_config
+                    .position_size_config
+                    .display_method
+                    .1 = if mouse_pos.y + text.actual_size[1] <= ui.ctx().content_rect().height() {
+                    VerticalAlign::Top
+                } else {
+                    VerticalAlign::Bottom
+                };
+                hovered = true;
+            };
+            // 若鼠标未悬挂在目标上，逐渐隐藏工具提示
+            if !hovered {
+                if tooltip.last_frame_hovered {
+                    self.reset_split_time(&fade_animation)?;
+                };
+                if self.timer.total_time - self.get_split_time(&fade_animation)?[1]
+                    >= self.tick_interval
+                {
+                    self.reset_split_time(&fade_animation)?;
+                    text.alpha = text.alpha.saturating_sub(10);
+                };
+                if text.alpha != 0 {
+                    self.animation_active = true;
+                };
+            };
+            self.tooltips[count].last_frame_hovered = hovered;
+            if self.render_list.iter().any(|x| x.0 == text_id) {
+                self.request_jump_render_list(
+                    RequestMethod::Id(text_id.clone()),
+                    RequestType::Top,
+                )?;
+            };
+            let alpha = text.alpha;
+            self.use_resource(
+                &text_id,
+                Some(Box::new(
+                    TextConfig::default()
+                        .alpha(Some(alpha))
+                        .position_size_config(Some(
+                            text.basic_front_resource_config.position_size_config,
+                        ))
+                        .background_alpha(Some(alpha))
+                        .hidden(Some(target_hidden || alpha == 0))
+                        .ignore_render_layer(Some(true)),
+                )),
+                ui,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Attaches a context menu to a basic front resource, replacing its previous menu.
+    ///
+    /// 为基本前端资源附加右键菜单，会替换其之前的菜单。
+    ///
+    /// The menu opens at the pointer when the resource is right-clicked. Several resources can
+    /// share one menu.
+    ///
+    /// 右键点击资源时菜单会在指针处打开。多个资源可以共用同一个菜单。
+    pub fn attach_context_menu(
+        &mut self,
+        target_id: &RustConstructorId,
+        menu_id: &RustConstructorId,
+    ) -> Result<(), RustConstructorError> {
+        if !self
+            .basic_front_resource_list
+            .contains(&target_id.discern_type)
+        {
+            error!(
+                "[ResourceDowncastTypeMismatch]attach_context_menu: Resource '{}({})' is not a basic front resource.",
+                target_id.name, target_id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' is not a basic front resource.",
+                    target_id.name, target_id.discern_type
+                ),
+            });
+        };
+        self.get_basic_front_resource(target_id)?;
+        self.get_resource::<ContextMenu>(menu_id)?;
+        let target = self.resolved_id(target_id)?;
+        let menu = self.resolved_id(menu_id)?;
+        self.context_menus.retain(|x| x.0 != target);
+        self.context_menus.push((target, menu));
+        Ok(())
+    }
+
+    /// Removes the context menu attached to a resource.
+    ///
+    /// 移除附加到资源上的右键菜单。
+    pub fn detach_context_menu(&mut self, target_id: &RustConstructorId) {
+        let target = match self.check_resource_exists(target_id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => target_id.clone(),
+        };
+        self.context_menus.retain(|x| x.0 != target);
+    }
+
+    /// Returns the id of the item chosen from a context menu and marks it as handled.
+    ///
+    /// 返回从右键菜单中选中的菜单项id，并将其标记为已处理。
+    pub fn consume_context_menu_choice(
+        &mut self,
+        menu_id: &RustConstructorId,
+    ) -> Result<Option<String>, RustConstructorError> {
+        Ok(self.get_resource_mut::<ContextMenu>(menu_id)?.chosen.take())
+    }
+
+    /// Opens, closes, fades and draws the attached context menus, it is called by the page
+    /// pipeline.
+    ///
+    /// 打开、关闭、淡入淡出并绘制已附加的右键菜单，页面流程会调用此方法。
+    pub fn update_context_menus(&mut self, ui: &Ui) -> Result<(), RustConstructorError> {
+        // 移除目标或菜单已被删除的附加
+        let context_menus: Vec<(RustConstructorId, RustConstructorId)> = self
+            .context_menus
+            .iter()
+            .filter(|x| {
+                self.check_resource_exists(&x.0).is_some()
+                    && self.check_resource_exists(&x.1).is_some()
+            })
+            .cloned()
+            .collect();
+        self.context_menus = context_menus.clone();
+        if context_menus.is_empty() {
+            return Ok(());
+        };
+        let (mouse_pos, secondary_clicked, primary_clicked, escape_pressed, step) = ui.input(|i| {
+            (
+                i.pointer.hover_pos(),
+                i.pointer.secondary_clicked(),
+                i.pointer.primary_clicked(),
+                i.key_pressed(Key::Escape),
+                i.stable_dt / 0.15,
+            )
+        });
+        let mut menus: Vec<RustConstructorId> = Vec::new();
+        for (_, menu) in &context_menus {
+            if !menus.contains(menu) {
+                menus.push(menu.clone());
+            };
+        }
+        // 右键点击目标时打开其菜单并关闭其他菜单
+        let mut opened_menu = None;
+        if secondary_clicked && let Some(mouse_pos) = mouse_pos {
+            for (target, menu) in &context_menus {
+                if let Some(index) = self.get_render_layer_resource(target)
+                    && self.resource_get_focus(index, mouse_pos.into(), true, vec![])
+                    && !self
+                        .get_basic_front_resource(target)?
+                        .display_display_info()
+                        .hidden
+                {
+                    opened_menu = Some((menu.clone(), mouse_pos));
+                    break;
+                };
+            }
+        };
+        let window = ui.ctx().content_rect();
+        let painter = ui.ctx().layer_painter(LayerId::new(
+            Order::Foreground,
+            Id::new("rust_constructor_context_menus"),
+        ));
+        for menu_id in menus {
+            let mut menu = self.get_resource::<ContextMenu>(&menu_id)?.clone();
+            if let Some((ref opened, position)) = opened_menu {
+                if *opened == menu_id {
+                    menu.open = true;
+                    menu.position = [position.x, position.y];
+                    menu.hovered_item = None;
+                } else {
+                    menu.open = false;
+                };
+            };
+            // 计算菜单项布局
+            let galleys: Vec<_> = menu
+                .items
+                .iter()
+                .map(|x| {
+                    painter.layout_no_wrap(
+                        x.0.clone(),
+                        FontId::proportional(menu.font_size),
+                        Color32::WHITE,
+                    )
+                })
+                .collect();
+            let row_height = menu.font_size + menu.padding * 2_f32;
+            let size = Vec2::new(
+                galleys.iter().map(|x| x.size().x).fold(0_f32, f32::max) + menu.padding * 2_f32,
+                row_height * galleys.len() as f32,
+            );
+            // 靠近窗口边缘时翻转到指针的另一侧
+            let position = Pos2::new(
+                if menu.position[0] + size.x <= window.max.x {
+                    menu.position[0]
+                } else {
+                    (menu.position[0] - size.x).max(window.min.x)
+                },
+                if menu.position[1] + size.y <= window.max.y {
+                    menu.position[1]
+                } else {
+                    (menu.position[1] - size.y).max(window.min.y)
+                },
+            );
+            let rect = Rect::from_min_size(position, size);
+            menu.hovered_item = if menu.open
+                && !galleys.is_empty()
+                && let Some(mouse_pos) = mouse_pos
+                && rect.contains(mouse_pos)
+            {
+                Some((((mouse_pos.y - rect.min.y) / row_height) as usize).min(galleys.len() - 1))
+            } else {
+                None
+            };
+            if menu.open && opened_menu.is_none() {
+                if primary_clicked {
+                    // 点击菜单项时记录选择，点击其他位置时关闭菜单
+                    if let Some(index) = menu.hovered_item {
+                        menu.chosen = Some(menu.items[index].1.clone());
+                    };
+                    menu.open = false;
+                } else if escape_pressed || secondary_clicked {
+                    menu.open = false;
+                };
+            };
+            let opacity = if menu.open {
+                (menu.opacity + step).min(1_f32)
+            } else {
+                (menu.opacity - step).max(0_f32)
+            };
+            if opacity != menu.opacity {
+                self.animation_active = true;
+                ui.ctx().request_repaint();
+            };
+            menu.opacity = opacity;
+            if menu.opacity > 0_f32 {
+                let fade = |color: [u8; 4]| {
+                    Color32::from_rgba_unmultiplied(
+                        color[0],
+                        color[1],
+                        color[2],
+                        (color[3] as f32 * menu.opacity) as u8,
+                    )
+                };
+                painter.rect_filled(rect, menu.rounding, fade(menu.background_color));
+                for (count, galley) in galleys.into_iter().enumerate() {
+                    let row = Rect::from_min_size(
+                        Pos2::new(rect.min.x, rect.min.y + row_height * count as f32),
+                        Vec2::new(size.x, row_height),
+                    );
+                    if menu.hovered_item == Some(count) {
+                        painter.rect_filled(row, menu.rounding, fade(menu.hover_color));
+                    };
+                    painter.galley_with_override_text_color(
+                        Pos2::new(
+                            row.min.x + menu.padding,
+                            row.center().y - galley.size().y / 2_f32,
+                        ),
+                        galley,
+                        fade(menu.text_color),
+                    );
+                }
+            };
+            *self.get_resource_mut::<ContextMenu>(&menu_id)? = menu;
+        }
+        Ok(())
+    }
+
+    /// Opens a modal dialog, closing the modal that was open before.
+    ///
+    /// 打开模态对话框，并关闭之前打开的模态对话框。
+    pub fn open_modal(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        self.get_resource::<Modal>(id)?;
+        let id = self.resolved_id(id)?;
+        if let Some(active_modal) = self.active_modal.take() {
+            if let Ok(modal) = self.get_resource_mut::<Modal>(&active_modal) {
+                modal.open = false;
+            };
+        } else {
+            // 对话框打开期间其后方的资源不能保持焦点
+            self.modal_saved_focus = self.focus_index.take();
+        };
+        let modal = self.get_resource_mut::<Modal>(&id)?;
+        modal.open = true;
+        modal.focused_button = 0;
+        modal.result = None;
+        self.active_modal = Some(id);
+        Ok(())
+    }
+
+    /// Closes a modal dialog without a result.
+    ///
+    /// 关闭模态对话框且不产生结果。
+    pub fn close_modal(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        self.get_resource_mut::<Modal>(id)?.open = false;
+        if self
+            .active_modal
+            .as_ref()
+            .is_some_and(|x| self.check_resource_exists(x) == self.check_resource_exists(id))
+        {
+            self.release_active_modal();
+        };
+        Ok(())
+    }
+
+    /// Clears the active modal dialog and gives the keyboard focus back to the resource that
+    /// had it before the dialog opened.
+    ///
+    /// 清除当前的模态对话框，并将键盘焦点交还给对话框打开前拥有焦点的资源。
+    fn release_active_modal(&mut self) {
+        self.active_modal = None;
+        self.focus_index = self.modal_saved_focus.take();
+    }
+
+    /// Returns the button a modal dialog was closed with and marks it as handled.
+    ///
+    /// 返回关闭模态对话框时所用的按钮，并将其标记为已处理。
+    pub fn consume_modal_result(
+        &mut self,
+        id: &RustConstructorId,
+    ) -> Result<Option<ModalResult>, RustConstructorError> {
+        Ok(self.get_resource_mut::<Modal>(id)?.result.take())
+    }
+
+    /// Handles the buttons of the open modal dialog and draws the modals that are open or
+    /// fading out, it is called by the page pipeline.
+    ///
+    /// 处理打开的模态对话框的按钮，并绘制打开中或正在淡出的模态对话框，页面流程会调用此方法。
+    pub fn update_modals(&mut self, ui: &Ui) -> Result<(), RustConstructorError> {
+        let modals: Vec<RustConstructorId> = self
+            .rust_constructor_resource
+            .iter()
+            .filter(|x| x.id.discern_type == "Modal")
+            .map(|x| x.id.clone())
+            .collect();
+        if modals.is_empty() {
+            return Ok(());
+        };
+        let (mouse_pos, primary_clicked, tab_pressed, shift, enter_pressed, escape_pressed, step) =
+            ui.input(|i| {
+                (
+                    i.pointer.hover_pos(),
+                    i.pointer.primary_clicked(),
+                    i.key_pressed(Key::Tab),
+                    i.modifiers.shift,
+                    i.key_pressed(Key::Enter),
+                    i.key_pressed(Key::Escape),
+                    i.stable_dt / 0.15,
+                )
+            });
+        let window = ui.ctx().content_rect();
+        let painter = ui.ctx().layer_painter(LayerId::new(
+            Order::Foreground,
+            Id::new("rust_constructor_modals"),
+        ));
+        for id in modals {
+            let mut modal = self.get_resource::<Modal>(&id)?.clone();
+            if !modal.open && modal.opacity == 0_f32 {
+                continue;
+            };
+            let panel = Rect::from_center_size(window.center(), modal.size.into());
+            let padding = modal.font_size;
+            let mut labels = vec![modal.ok_label.clone()];
+            if let Some(cancel_label) = &modal.cancel_label {
+                labels.push(cancel_label.clone());
+            };
+            // 按钮从右向左排列，确定按钮位于最右侧
+            let button_height = modal.font_size + padding;
+            let mut right = panel.max.x - padding;
+            let mut buttons = Vec::new();
+            for label in labels {
+                let galley = painter.layout_no_wrap(
+                    label,
+                    FontId::proportional(modal.font_size),
+                    Color32::WHITE,
+                );
+                let width = galley.size().x + padding * 2_f32;
+                buttons.push((
+                    Rect::from_min_max(
+                        Pos2::new(right - width, panel.max.y - padding - button_height),
+                        Pos2::new(right, panel.max.y - padding),
+                    ),
+                    galley,
+                ));
+                right -= width + padding / 2_f32;
+            }
+            let hovered_button = if modal.open
+                && let Some(mouse_pos) = mouse_pos
+            {
+                buttons.iter().position(|x| x.0.contains(mouse_pos))
+            } else {
+                None
+            };
+            // 打开的那一帧不处理输入，避免触发打开对话框的点击直接关闭它
+            if modal.open && modal.opacity > 0_f32 {
+                if tab_pressed {
+                    modal.focused_button = if shift {
+                        (modal.focused_button + buttons.len() - 1) % buttons.len()
+                    } else {
+                        (modal.focused_button + 1) % buttons.len()
+                    };
+                };
+                let button_result = |index: usize| {
+                    if index == 0 {
+                        ModalResult::Ok
+                    } else {
+                        ModalResult::Cancel
+                    }
+                };
+                let result = if primary_clicked && let Some(index) = hovered_button {
+                    Some(button_result(index))
+                } else if enter_pressed {
+                    Some(button_result(modal.focused_button))
+                } else if escape_pressed && modal.cancel_label.is_some() {
+                    Some(ModalResult::Cancel)
+                } else {
+                    None
+                };
+                if let Some(result) = result {
+                    modal.result = Some(result);
+                    modal.open = false;
+                    if self.active_modal.as_ref() == Some(&id) {
+                        self.release_active_modal();
+                    };
+                };
+            };
+            let opacity = if modal.open {
+                (modal.opacity + step).min(1_f32)
+            } else {
+                (modal.opacity - step).max(0_f32)
+            };
+            if opacity != modal.opacity {
+                self.animation_active = true;
+                ui.ctx().request_repaint();
+            };
+            modal.opacity = opacity;
+            if modal.opacity > 0_f32 {
+                let fade = |color: [u8; 4]| {
+                    Color32::from_rgba_unmultiplied(
+                        color[0],
+                        color[1],
+                        color[2],
+                        (color[3] as f32 * modal.opacity) as u8,
+                    )
+                };
+                painter.rect_filled(window, 0_f32, fade(modal.backdrop_color));
+                painter.rect_filled(panel, modal.rounding, fade(modal.background_color));
+                let title = painter.layout(
+                    modal.title.clone(),
+                    FontId::proportional(modal.font_size * 1.25),
+                    fade(modal.text_color),
+                    modal.size[0] - padding * 2_f32,
+                );
+                let title_height = title.size().y;
+                painter.galley(panel.min + Vec2::splat(padding), title, Color32::WHITE);
+                let content = painter.layout(
+                    modal.content.clone(),
+                    FontId::proportional(modal.font_size),
+                    fade(modal.text_color),
+                    modal.size[0] - padding * 2_f32,
+                );
+                painter.galley(
+                    panel.min + Vec2::new(padding, padding * 1.5 + title_height),
+                    content,
+                    Color32::WHITE,
+                );
+                for (count, (rect, galley)) in buttons.into_iter().enumerate() {
+                    painter.rect_filled(
+                        rect,
+                        modal.rounding,
+                        fade(
+                            if hovered_button == Some(count) || modal.focused_button == count {
+                                modal.button_hover_color
+                            } else {
+                                modal.button_color
+                            },
+                        ),
+                    );
+                    painter.galley_with_override_text_color(
+                        rect.center() - galley.size() / 2_f32,
+                        galley,
+                        fade(modal.text_color),
+                    );
+                }
+            };
+            *self.get_resource_mut::<Modal>(&id)? = modal;
+        }
+        Ok(())
+    }
+
+    /// Shows a short notification at the corner of the window chosen by the style.
+    ///
+    /// 在样式所选的窗口角落显示一条简短的通知。
+    ///
+    /// Toasts stack away from their corner, slide in from its side and fade out after
+    /// `duration` seconds. They are drawn by the page pipeline, or by `draw_toasts` when the
+    /// pipeline is not used.
+    ///
+    /// 提示消息从所在角落向外堆叠，从该侧滑入并在`duration`秒后淡出。它们由页面流程绘制，
+    /// 不使用页面流程时可调用`draw_toasts`绘制。
+    ///
+    /// When `max_visible_toasts` toasts are already shown the new one waits in a queue, and its
+    /// duration only starts counting once it appears.
+    ///
+    /// 已显示`max_visible_toasts`条提示消息时新消息会进入队列等待，其时长从显示时才开始计算。
+    pub fn toast(&mut self, message: &str, duration: f32, style: ToastStyle) {
+        self.toast_with_actions(message, duration, style, &[]);
+    }
+
+    /// Shows a toast with action buttons given as (label, action id).
+    ///
+    /// 显示带有操作按钮的提示消息，按钮以(标签, 操作id)给出。
+    ///
+    /// The buttons are laid out in a row below the message and the toast grows to fit them.
+    /// Clicking one dismisses the toast and stores its id for `consume_toast_action`.
+    ///
+    /// 按钮在消息下方排成一行，提示消息会扩大以容纳它们。点击按钮会关闭提示消息，
+    /// 并保存其id供`consume_toast_action`读取。
+    pub fn toast_with_actions(
+        &mut self,
+        message: &str,
+        duration: f32,
+        style: ToastStyle,
+        actions: &[(&str, &str)],
+    ) {
+        let toast = Toast {
+            message: message.to_string(),
+            start_time: self.timer.timer.elapsed().as_millis(),
+            duration: (duration.max(0_f32) * 1000_f32) as u128,
+            style,
+            actions: actions
+                .iter()
+                .map(|x| (x.0.to_string(), x.1.to_string()))
+                .collect(),
+        };
+        if self
+            .max_visible_toasts
+            .is_some_and(|x| self.toasts.len() >= x)
+        {
+            self.queued_toasts.push(toast);
+        } else {
+            self.toasts.push(toast);
+        };
+    }
+
+    /// Returns the id of the toast action that was clicked and marks it as handled.
+    ///
+    /// 返回被点击的提示消息操作id，并将其标记为已处理。
+    pub fn consume_toast_action(&mut self) -> Option<String> {
+        self.toast_action.take()
+    }
+
+    /// Draws the pending toasts and removes the expired ones.
+    ///
+    /// 绘制待显示的提示消息并移除已过期的消息。
+    pub fn draw_toasts(&mut self, ui: &Ui) {
+        // 使用真实时间，使提示消息在计时器暂停时也能消失
+        let now = self.timer.timer.elapsed().as_millis();
+        self.toasts.retain(|x| now < x.start_time + x.duration);
+        // 有空位时按顺序显示排队的提示消息
+        while !self.queued_toasts.is_empty()
+            && self
+                .max_visible_toasts
+                .is_none_or(|x| self.toasts.len() < x)
+        {
+            let mut toast = self.queued_toasts.remove(0);
+            toast.start_time = now;
+            self.toasts.push(toast);
+        }
+        if self.toasts.is_empty() {
+            return;
+        };
+        const FADE_TIME: f32 = 200_f32;
+        let window = ui.ctx().content_rect();
+        let painter = ui.ctx().layer_painter(LayerId::new(
+            Order::Foreground,
+            Id::new("rust_constructor_toasts"),
+        ));
+        let (mouse_pos, primary_clicked) =
+            ui.input(|i| (i.pointer.hover_pos(), i.pointer.primary_clicked()));
+        let mut clicked_action = None;
+        // 每个角落已被占用的高度
+        let mut stacked = [0_f32; 4];
+        // 最新的提示消息最靠近角落
+        for (index, toast) in self.toasts.iter().enumerate().rev() {
+            let elapsed = (now - toast.start_time) as f32;
+            let remaining = (toast.start_time + toast.duration - now) as f32;
+            let opacity = (elapsed / FADE_TIME)
+                .min(remaining / FADE_TIME)
+                .clamp(0_f32, 1_f32);
+            let style = toast.style;
+            let galley = painter.layout_no_wrap(
+                toast.message.clone(),
+                FontId::proportional(style.font_size),
+                Color32::from_rgba_unmultiplied(
+                    style.text_color[0],
+                    style.text_color[1],
+                    style.text_color[2],
+                    (style.text_color[3] as f32 * opacity) as u8,
+                ),
+            );
+            let fade = |color: [u8; 4]| {
+                Color32::from_rgba_unmultiplied(
+                    color[0],
+                    color[1],
+                    color[2],
+                    (color[3] as f32 * opacity) as u8,
+                )
+            };
+            let buttons: Vec<_> = toast
+                .actions
+                .iter()
+                .map(|x| {
+                    painter.layout_no_wrap(
+                        x.0.clone(),
+                        FontId::proportional(style.font_size),
+                        fade(style.text_color),
+                    )
+                })
+                .collect();
+            // 按钮排成一行，提示消息扩大以容纳按钮
+            let button_height = style.font_size + style.padding;
+            let buttons_width = buttons
+                .iter()
+                .map(|x| x.size().x + style.padding * 2_f32)
+                .sum::<f32>()
+                + style.padding / 2_f32 * buttons.len().saturating_sub(1) as f32;
+            let mut size = Vec2::new(galley.size().x.max(buttons_width), galley.size().y)
+                + Vec2::splat(style.padding * 2_f32);
+            if !buttons.is_empty() {
+                size.y += style.padding + button_height;
+            };
+            let corner = style.corner as usize;
+            let left = matches!(style.corner, ToastCorner::TopLeft | ToastCorner::BottomLeft);
+            let top = matches!(style.corner, ToastCorner::TopLeft | ToastCorner::TopRight);
+            // 淡入淡出时从所在一侧滑入滑出
+            let slide = (1_f32 - opacity) * (size.x + style.margin);
+            let rect = Rect::from_min_size(
+                Pos2::new(
+                    if left {
+                        window.min.x + style.margin - slide
+                    } else {
+                        window.max.x - style.margin - size.x + slide
+                    },
+                    if top {
+                        window.min.y + stacked[corner] + style.margin
+                    } else {
+                        window.max.y - stacked[corner] - style.margin - size.y
+                    },
+                ),
+                size,
+            );
+            painter.rect_filled(
+                rect,
+                style.rounding,
+                Color32::from_rgba_unmultiplied(
+                    style.background_color[0],
+                    style.background_color[1],
+                    style.background_color[2],
+                    (style.background_color[3] as f32 * opacity) as u8,
+                ),
+            );
+            painter.galley(
+                rect.min + Vec2::splat(style.padding),
+                galley,
+                Color32::WHITE,
+            );
+            let mut right = rect.max.x - style.padding;
+            for (count, button) in buttons.into_iter().enumerate() {
+                let width = button.size().x + style.padding * 2_f32;
+                let button_rect = Rect::from_min_max(
+                    Pos2::new(right - width, rect.max.y - style.padding - button_height),
+                    Pos2::new(right, rect.max.y - style.padding),
+                );
+                let hovered = mouse_pos.is_some_and(|x| button_rect.contains(x));
+                if hovered && primary_clicked && opacity > 0_f32 {
+                    clicked_action = Some((index, toast.actions[count].1.clone()));
+                };
+                // 按钮使用半透明的文本颜色作为底色
+                let mut button_color = style.text_color;
+                button_color[3] = if hovered { 80 } else { 40 };
+                painter.rect_filled(button_rect, style.rounding, fade(button_color));
+                painter.galley(
+                    button_rect.center() - button.size() / 2_f32,
+                    button,
+                    Color32::WHITE,
+                );
+                right -= width + style.padding / 2_f32;
+            }
+            stacked[corner] += size.y + style.margin;
+        }
+        if let Some((index, action)) = clicked_action {
+            self.toasts.remove(index);
+            self.toast_action = Some(action);
+        };
+        self.animation_active = true;
+        ui.ctx().request_repaint();
+    }
+
+    /// Returns the resolved rect of a resource as [position, size].
+    ///
+    /// 返回资源的最终矩形，格式为[位置, 尺寸]。
+    ///
+    /// Resource panels report the rect of their background.
+    ///
+    /// 资源面板返回其背景的矩形。
+    pub fn resource_rect(
+        &self,
+        id: &RustConstructorId,
+    ) -> Result<[[f32; 2]; 2], RustConstructorError> {
+        let id = if ["ResourcePanel", "Switch"].contains(&&*id.discern_type) {
+            let background_name = format!("{}Background", id.name);
+            let background =
+                self.get_resource::<Background>(&build_id(background_name.clone(), "Background"))?;
+            build_id(
+                background_name,
+                background_type_discern(&background.background_type),
+            )
+        } else if self.basic_front_resource_list.contains(&id.discern_type) {
+            id.clone()
+        } else {
+            error!(
+                "[ResourceDowncastTypeMismatch]resource_rect: Resource '{}({})' has no rect.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!("Resource '{}({})' has no rect.", id.name, id.discern_type),
+            });
+        };
+        let resource = self.get_basic_front_resource(&id)?;
+        Ok([resource.display_position(), resource.display_size()])
+    }
+
+    /// Registers a layout for a basic front resource used when the window is at least
+    /// `min_width` wide.
+    ///
+    /// 为基本前端资源注册一个在窗口宽度不小于`min_width`时使用的布局。
+    ///
+    /// Each frame the breakpoint with the largest `min_width` not exceeding the window width
+    /// replaces the resource's own position config, when none matches the resource's own config
+    /// is used. Registering the same `min_width` again replaces that breakpoint.
+    ///
+    /// 每一帧中`min_width`不超过窗口宽度的最大断点会替代资源自身的位置配置，
+    /// 没有匹配的断点时使用资源自身的配置。再次注册相同的`min_width`会替换该断点。
+    pub fn add_breakpoint(
+        &mut self,
+        id: &RustConstructorId,
+        min_width: f32,
+        position_size_config: PositionSizeConfig,
+    ) -> Result<(), RustConstructorError> {
+        if !self.basic_front_resource_list.contains(&id.discern_type) {
+            error!(
+                "[ResourceDowncastTypeMismatch]add_breakpoint: Resource '{}({})' is not a basic front resource.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' is not a basic front resource.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        self.get_basic_front_resource(id)?;
+        // 记录资源的实际名称（可能带有页面作用域前缀）
+        let target = match self.check_resource_exists(id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => id.clone(),
+        };
+        self.breakpoints
+            .retain(|x| x.target != target || x.min_width != min_width);
+        self.breakpoints.push(Breakpoint {
+            target,
+            min_width,
+            position_size_config,
+        });
+        Ok(())
+    }
+
+    /// Removes all breakpoints of a resource.
+    ///
+    /// 移除资源的所有断点。
+    pub fn clear_breakpoints(&mut self, id: &RustConstructorId) {
+        let target = match self.check_resource_exists(id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => id.clone(),
+        };
+        self.breakpoints.retain(|x| x.target != target);
+    }
+
+    /// Returns the position config a resource uses at the current window width.
+    ///
+    /// 返回资源在当前窗口宽度下使用的位置配置。
+    pub fn responsive_position_size_config(
+        &self,
+        id: &RustConstructorId,
+        base: PositionSizeConfig,
+        ui: &Ui,
+    ) -> PositionSizeConfig {
+        let width = ui.ctx().content_rect().width();
+        let position_size_config = self
+            .breakpoints
+            .iter()
+            .filter(|x| x.target == *id && x.min_width <= width)
+            .max_by(|a, b| a.min_width.total_cmp(&b.min_width))
+            .map_or(base, |x| x.position_size_config);
+        // 相对父资源布局时，先在父资源的矩形内解析网格，再交给窗口级的处理流程
+        if let Some((_, parent)) = self.relative_layouts.iter().find(|x| x.0 == *id)
+            && let Ok(parent_rect) = self.resource_rect(parent)
+        {
+            let [position, size] = position_size_processor_in(
+                position_size_config
+                    .display_method(HorizontalAlign::Left, VerticalAlign::Top)
+                    .offset(0_f32, 0_f32),
+                parent_rect,
+            );
+            position_size_config
+                .origin_position(position[0], position[1])
+                .origin_size(size[0], size[1])
+                .x_location_grid(0_f32, 0_f32)
+                .y_location_grid(0_f32, 0_f32)
+                .x_size_grid(0_f32, 0_f32)
+                .y_size_grid(0_f32, 0_f32)
+        } else {
+            position_size_config
+        }
+    }
+
+    /// Lays out a basic front resource inside the rect of another resource.
+    ///
+    /// 在另一个资源的矩形内布局基本前端资源。
+    ///
+    /// The grids and positions of the resource are resolved against the parent's rect instead of
+    /// the window, so `x_size_grid(1, 2)` means half of the parent's width. Resource panels and
+    /// switches use the rect of their background. Setting a new parent replaces the old one, a
+    /// parent that is laid out relative to the resource itself is rejected.
+    ///
+    /// 资源的网格和位置会相对父资源的矩形而不是窗口解析，因此`x_size_grid(1, 2)`表示父资源宽度的一半。
+    /// 资源面板和开关使用其背景的矩形。设置新的父资源会替换旧的父资源，相对该资源本身布局的父资源会被拒绝。
+    pub fn set_relative_to(
+        &mut self,
+        id: &RustConstructorId,
+        parent: &RustConstructorId,
+    ) -> Result<(), RustConstructorError> {
+        if !self.basic_front_resource_list.contains(&id.discern_type) {
+            error!(
+                "[ResourceDowncastTypeMismatch]set_relative_to: Resource '{}({})' is not a basic front resource.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                description: format!(
+                    "Resource '{}({})' is not a basic front resource.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        self.get_basic_front_resource(id)?;
+        self.resource_rect(parent)?;
+        // 记录资源的实际名称（可能带有页面作用域前缀）
+        let [child, parent] = [self.resolved_id(id)?, self.resolved_id(parent)?];
+        // 沿父资源链向上查找，回到子资源时会形成循环
+        let mut ancestor = Some(parent.clone());
+        let mut visited = Vec::new();
+        while let Some(current) = ancestor {
+            if current == child {
+                error!(
+                    "[RelativeLayoutCycle]set_relative_to: Laying out '{}({})' relative to '{}({})' would form a cycle.",
+                    child.name, child.discern_type, parent.name, parent.discern_type
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::RELATIVE_LAYOUT_CYCLE.to_string(),
+                    description: format!(
+                        "Laying out '{}({})' relative to '{}({})' would form a cycle.",
+                        child.name, child.discern_type, parent.name, parent.discern_type
+                    ),
+                });
+            };
+            if visited.contains(&current) {
+                break;
+            };
+            ancestor = self
+                .relative_layouts
+                .iter()
+                .find(|x| x.0 == current)
+                .map(|x| x.1.clone());
+            visited.push(current);
+        }
+        self.relative_layouts.retain(|x| x.0 != child);
+        self.relative_layouts.push((child, parent));
+        Ok(())
+    }
+
+    /// Lays out a resource relative to the window again.
+    ///
+    /// 使资源重新相对窗口布局。
+    pub fn clear_relative_to(&mut self, id: &RustConstructorId) {
+        let target = match self.check_resource_exists(id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => id.clone(),
+        };
+        self.relative_layouts.retain(|x| x.0 != target);
+    }
+
+    /// Anchors a basic front resource to a side or corner of another one.
+    ///
+    /// 将基本前端资源锚定到另一个基本前端资源的边或角上。
+    ///
+    /// Every frame the child is placed at `edge` of the parent's computed rect, `gap` pixels
+    /// away from it, so it follows the parent when the parent moves. Anchoring a resource again
+    /// replaces its previous anchor, and anchors that would form a cycle are rejected.
+    ///
+    /// 每一帧子资源都会被放置在父资源计算后矩形的`edge`处，并与其保持`gap`像素的距离，
+    /// 因此父资源移动时子资源会跟随。再次锚定一个资源会替换其原有的锚点，会形成循环的锚定会被拒绝。
+    pub fn anchor(
+        &mut self,
+        child: &RustConstructorId,
+        parent: &RustConstructorId,
+        edge: AnchorEdge,
+        gap: [f32; 2],
+    ) -> Result<(), RustConstructorError> {
+        let mut ids = Vec::new();
+        for id in [child, parent] {
+            if !self.basic_front_resource_list.contains(&id.discern_type) {
+                error!(
+                    "[ResourceDowncastTypeMismatch]anchor: Resource '{}({})' is not a basic front resource.",
+                    id.name, id.discern_type
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                    description: format!(
+                        "Resource '{}({})' is not a basic front resource.",
+                        id.name, id.discern_type
+                    ),
+                });
+            };
+            self.get_basic_front_resource(id)?;
+            // 记录资源的实际名称（可能带有页面作用域前缀）
+            if let Some(index) = self.check_resource_exists(id) {
+                ids.push(self.rust_constructor_resource[index].id.clone());
+            };
+        }
+        let [child, parent] = [ids[0].clone(), ids[1].clone()];
+        // 沿父资源的锚点链向上查找，回到子资源即形成循环
+        let mut current = Some(&parent);
+        while let Some(id) = current {
+            if *id == child {
+                error!(
+                    "[AnchorCycle]anchor: Anchoring '{}({})' to '{}({})' would form a cycle.",
+                    child.name, child.discern_type, parent.name, parent.discern_type
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::ANCHOR_CYCLE.to_string(),
+                    description: format!(
+                        "Anchoring '{}({})' to '{}({})' would form a cycle.",
+                        child.name, child.discern_type, parent.name, parent.discern_type
+                    ),
+                });
+            };
+            current = self
+                .anchors
+                .iter()
+                .find(|x| x.child == *id)
+                .map(|x| &x.parent);
+        }
+        self.anchors.retain(|x| x.child != child);
+        self.anchors.push(Anchor {
+            child,
+            parent,
+            edge,
+            gap,
+        });
+        Ok(())
+    }
+
+    /// Removes the anchor of a resource, it goes back to its own position config.
+    ///
+    /// 移除资源的锚点，资源会恢复使用自身的位置配置。
+    pub fn remove_anchor(&mut self, child: &RustConstructorId) {
+        let child = match self.check_resource_exists(child) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => child.clone(),
+        };
+        self.anchors.retain(|x| x.child != child);
+    }
+
+    /// Returns the anchored position of a resource with the given size, if it is anchored.
+    ///
+    /// 如果资源被锚定，返回其在给定尺寸下的锚定位置。
+    pub fn anchored_position(
+        &self,
+        child: &RustConstructorId,
+        size: [f32; 2],
+        ui: &Ui,
+    ) -> Result<Option<[f32; 2]>, RustConstructorError> {
+        let Some(anchor) = self.anchors.iter().find(|x| x.child == *child) else {
+            return Ok(None);
+        };
+        let parent_rect = self.anchor_parent_rect(&anchor.parent, ui, &mut vec![child.clone()])?;
+        Ok(Some(anchor.edge.place(parent_rect, size, anchor.gap)))
+    }
+
+    fn anchor_parent_rect(
+        &self,
+        id: &RustConstructorId,
+        ui: &Ui,
+        visited: &mut Vec<RustConstructorId>,
+    ) -> Result<[[f32; 2]; 2], RustConstructorError> {
+        if visited.contains(id) {
+            error!(
+                "[AnchorCycle]anchor_parent_rect: Anchor chain of '{}({})' forms a cycle.",
+                id.name, id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::ANCHOR_CYCLE.to_string(),
+                description: format!(
+                    "Anchor chain of '{}({})' forms a cycle.",
+                    id.name, id.discern_type
+                ),
+            });
+        };
+        visited.push(id.clone());
+        let resource = self.get_basic_front_resource(id)?;
+        let position_size_config =
+            self.responsive_position_size_config(id, resource.display_position_size_config(), ui);
+        // 文本尺寸由排版结果决定，使用上次排版得到的尺寸
+        let size = if id.discern_type == "Text" {
+            resource.display_size()
+        } else {
+            position_size_processor(position_size_config, ui)[1]
+        };
+        let position = match self.anchors.iter().find(|x| x.child == *id) {
+            Some(anchor) => {
+                let parent_rect = self.anchor_parent_rect(&anchor.parent, ui, visited)?;
+                anchor.edge.place(parent_rect, size, anchor.gap)
+            }
+            None => position_size_processor(
+                position_size_config
+                    .x_size_grid(0_f32, 0_f32)
+                    .y_size_grid(0_f32, 0_f32)
+                    .origin_size(size[0], size[1]),
+                ui,
+            )[0],
+        };
+        Ok([position, size])
+    }
+
+    /// Draws a line from one resource to another, attached to their facing edges.
+    ///
+    /// 绘制从一个资源到另一个资源的连接线，连接在它们相对的边上。
+    ///
+    /// The edges are chosen from the main direction between the two centers, so the line
+    /// follows the resources as they move. Call it after both resources have been drawn.
+    ///
+    /// 根据两个中心之间的主要方向选择边，因此连接线会跟随资源移动。应在两个资源绘制后调用。
+    pub fn draw_connector(
+        &self,
+        from_id: &RustConstructorId,
+        to_id: &RustConstructorId,
+        style: ConnectorStyle,
+        ui: &Ui,
+    ) -> Result<(), RustConstructorError> {
+        let [from_position, from_size] = self.resource_rect(from_id)?;
+        let [to_position, to_size] = self.resource_rect(to_id)?;
+        Self::paint_connector(
+            Rect::from_min_size(from_position.into(), from_size.into()),
+            Rect::from_min_size(to_position.into(), to_size.into()),
+            style,
+            ui,
+        );
+        Ok(())
+    }
+
+    /// Returns the rect a line endpoint attaches to, points are treated as empty rects.
+    ///
+    /// 返回线条端点所连接的矩形，点被视为空矩形。
+    fn line_endpoint_rect(&self, endpoint: &LineEndpoint) -> Result<Rect, RustConstructorError> {
+        Ok(match endpoint {
+            LineEndpoint::Point(point) => Rect::from_min_size((*point).into(), Vec2::ZERO),
+            LineEndpoint::Resource(id) => {
+                let [position, size] = self.resource_rect(id)?;
+                Rect::from_min_size(position.into(), size.into())
+            }
+        })
+    }
+
+    /// Paints a connector between the facing edges of two rects.
+    ///
+    /// 在两个矩形相对的边之间绘制连接线。
+    fn paint_connector(from_rect: Rect, to_rect: Rect, style: ConnectorStyle, ui: &Ui) {
+        let delta = to_rect.center() - from_rect.center();
+        // 主方向为水平时连接左右边，否则连接上下边
+        let horizontal = delta.x.abs() >= delta.y.abs();
+        let (start, end, direction) = if horizontal {
+            if delta.x >= 0_f32 {
+                (from_rect.right_center(), to_rect.left_center(), Vec2::X)
+            } else {
+                (from_rect.left_center(), to_rect.right_center(), -Vec2::X)
+            }
+        } else if delta.y >= 0_f32 {
+            (from_rect.center_bottom(), to_rect.center_top(), Vec2::Y)
+        } else {
+            (from_rect.center_top(), to_rect.center_bottom(), -Vec2::Y)
+        };
+        let color = Color32::from_rgba_unmultiplied(
+            style.color[0],
+            style.color[1],
+            style.color[2],
+            style.color[3],
+        );
+        let stroke = Stroke::new(style.width, color);
+        let painter = ui.painter();
+        let arrow_direction = match style.kind {
+            ConnectorKind::Straight => {
+                painter.line_segment([start, end], stroke);
+                end - start
+            }
+            ConnectorKind::Elbow => {
+                let points = if horizontal {
+                    let middle_x = (start.x + end.x) / 2_f32;
+                    vec![
+                        start,
+                        Pos2::new(middle_x, start.y),
+                        Pos2::new(middle_x, end.y),
+                        end,
+                    ]
+                } else {
+                    let middle_y = (start.y + end.y) / 2_f32;
+                    vec![
+                        start,
+                        Pos2::new(start.x, middle_y),
+                        Pos2::new(end.x, middle_y),
+                        end,
+                    ]
+                };
+                painter.add(Shape::line(points, stroke));
+                direction
+            }
+            ConnectorKind::Bezier => {
+                let distance = if horizontal {
+                    (end.x - start.x).abs()
+                } else {
+                    (end.y - start.y).abs()
+                } / 2_f32;
+                painter.add(CubicBezierShape::from_points_stroke(
+                    [
+                        start,
+                        start + direction * distance,
+                        end - direction * distance,
+                        end,
+                    ],
+                    false,
+                    Color32::TRANSPARENT,
+                    stroke,
+                ));
+                direction
+            }
+        };
+        if let Some(arrow_size) = style.arrow_size
+            && arrow_direction.length() > 0_f32
+        {
+            let forward = arrow_direction.normalized() * arrow_size;
+            let side = forward.rot90() * 0.5;
+            painter.add(Shape::convex_polygon(
+                vec![end, end - forward + side, end - forward - side],
+                color,
+                Stroke::NONE,
+            ));
+        };
+    }
+
+    /// Sets the rotation of an image or custom rectangle.
+    ///
+    /// 设置图像或自定义矩形的旋转。
+    ///
+    /// `angle` is in radians and `center` is relative to the top-left corner of the resource.
+    /// Hit testing still uses the unrotated, axis-aligned rect. With `safe_mode` off, resources
+    /// that do not exist or cannot rotate are skipped instead of returning an error.
+    ///
+    /// `angle`以弧度为单位，`center`相对于资源左上角。命中检测仍使用未旋转的轴对齐矩形。
+    /// 关闭`safe_mode`时，不存在或无法旋转的资源会被跳过而不是返回错误。
+    pub fn set_rotation(
+        &mut self,
+        id: &RustConstructorId,
+        angle: f32,
+        center: [f32; 2],
+        safe_mode: bool,
+    ) -> Result<(), RustConstructorError> {
+        if !safe_mode && self.check_resource_exists(id).is_none() {
+            return Ok(());
+        };
+        match &*id.discern_type {
+            "Image" => {
+                let image = self.get_resource_mut::<Image>(id)?;
+                image.rotate_angle = angle;
+                image.rotate_center = center;
+            }
+            "CustomRect" => {
+                let custom_rect = self.get_resource_mut::<CustomRect>(id)?;
+                custom_rect.rotate_angle = angle;
+                custom_rect.rotate_center = center;
+            }
+            _ => {
+                if safe_mode {
+                    error!(
+                        "[ResourceDowncastTypeMismatch]set_rotation: Resource '{}({})' cannot be rotated.",
+                        id.name, id.discern_type
+                    );
+                    return Err(RustConstructorError {
+                        error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                        description: format!(
+                            "Resource '{}({})' cannot be rotated.",
+                            id.name, id.discern_type
+                        ),
+                    });
+                };
+            }
+        };
+        Ok(())
+    }
+
+    /// Sets the search highlight of a text resource and returns the number of matches.
+    ///
+    /// 设置文本资源的搜索高亮并返回匹配数量。
+    ///
+    /// The count is taken from the full content, the ranges painted on the next frame follow
+    /// the displayed content, which may be shorter when the text is truncated or collapsed.
+    ///
+    /// 数量基于完整内容统计，下一帧绘制的范围基于显示内容，文本被截断或折叠时显示内容可能更短。
+    pub fn highlight_text(
+        &mut self,
+        id: &RustConstructorId,
+        highlight: Option<TextHighlight>,
+    ) -> Result<usize, RustConstructorError> {
+        let text = self.get_resource_mut::<Text>(id)?;
+        let count = highlight
+            .as_ref()
+            .map_or(0, |highlight| highlight.find_matches(&text.content).len());
+        text.highlight = highlight;
+        Ok(count)
+    }
+
+    /// Returns true once the pointer has stayed over a resource for `delay` seconds.
+    ///
+    /// 指针在资源上停留`delay`秒后返回true。
+    ///
+    /// Leaving the resource resets the wait, so a pointer just passing through never triggers
+    /// it. Call this every frame while the result is needed, typically for hover-opened submenus.
+    ///
+    /// 离开资源会重置等待，因此只是经过的指针不会触发。需要结果时应每帧调用，常用于悬停打开的子菜单。
+    pub fn hover_intent(&mut self, id: &RustConstructorId, delay: f32, ui: &Ui) -> bool {
+        let hovered = if let Some(index) = self.get_render_layer_resource(id)
+            && let Some(mouse_pos) = self.resource_input(ui, id, |i| i.pointer.hover_pos())
+        {
+            self.resource_get_focus(index, mouse_pos.into(), true, vec![])
+        } else {
+            false
+        };
+        let position = self.hover_start_time.iter().position(|x| x.0 == *id);
+        if !hovered {
+            if let Some(index) = position {
+                self.hover_start_time.remove(index);
             };
+            return false;
+        };
+        let start_time = match position {
+            Some(index) => self.hover_start_time[index].1,
+            None => {
+                self.hover_start_time
+                    .push((id.clone(), self.timer.total_time));
+                self.timer.total_time
+            }
+        };
+        let delay = (delay.max(0_f32) * 1000_f32) as u128;
+        let hovered_time = self.timer.total_time - start_time;
+        if hovered_time < delay {
+            // 指针静止时也要在延迟结束后刷新
+            ui.ctx()
+                .request_repaint_after(Duration::from_millis((delay - hovered_time) as u64));
+            false
+        } else {
+            true
         }
-        let target_point = self.render_layer[index].1;
-        !need_contains_mouse
-            || mouse_pos[0] <= target_point[1][0]
-                && mouse_pos[0] >= target_point[0][0]
-                && mouse_pos[1] <= target_point[1][1]
-                && mouse_pos[1] >= target_point[0][1]
     }
 
     /// Mark active resources.
@@ -1840,6 +7599,7 @@ impl App {
         name: &str,
         mut resource: T,
     ) -> Result<(), RustConstructorError> {
+        let name = &*self.scoped_name(name);
         let discern_type = &*type_processor(&resource);
         if self
             .check_resource_exists(&build_id(name, discern_type))
@@ -1849,21 +7609,35 @@ impl App {
                 "[ResourceNameRepetition]add_resource: Resource '{name}({discern_type})' has already existed."
             );
             return Err(RustConstructorError {
-                error_id: "ResourceNameRepetition".to_string(),
+                error_id: RustConstructorError::RESOURCE_NAME_REPETITION.to_string(),
                 description: format!("Resource '{name}({discern_type})' has already existed."),
             });
         };
         if name.is_empty() {
             error!("[ResourceUntitled]add_resource: All resources must have a valid name.");
             return Err(RustConstructorError {
-                error_id: "ResourceUntitled".to_string(),
+                error_id: RustConstructorError::RESOURCE_UNTITLED.to_string(),
                 description: "All resources must have a valid name.".to_string(),
             });
         };
+        // 页面作用域内添加的资源归属于该页面
+        if let Some(page) = &self.page_scope
+            && get_tag("page", &resource.display_tags()).is_none()
+        {
+            resource.modify_tags(&[["page".to_string(), page.clone()]], false);
+        };
         match discern_type {
             "SplitTime" => {
                 let split_time = downcast_resource_mut::<SplitTime>(&mut resource)?;
-                split_time.time = [self.timer.now_time, self.timer.total_time];
+                split_time.time = self.split_time_now(split_time.timer.as_deref())?;
+            }
+            "NamedTimer" => {
+                let named_timer = downcast_resource_mut::<NamedTimer>(&mut resource)?;
+                named_timer.start_time = self.timer.timer.elapsed().as_millis();
+            }
+            "Countdown" => {
+                let countdown = downcast_resource_mut::<Countdown>(&mut resource)?;
+                countdown.start_time = self.timer.total_time;
             }
             "Background" => {
                 let background = downcast_resource_mut::<Background>(&mut resource)?;
@@ -1894,7 +7668,8 @@ impl App {
                         switch.appearance.len()
                     );
                     return Err(RustConstructorError {
-                        error_id: "SwitchAppearanceConfigMismatch".to_string(),
+                        error_id: RustConstructorError::SWITCH_APPEARANCE_CONFIG_MISMATCH
+                            .to_string(),
                         description: format!(
                             "Expected {} elements, found {}.",
                             count * switch.state_amount as usize,
@@ -1918,7 +7693,8 @@ impl App {
                             switch.state_amount
                         );
                         return Err(RustConstructorError {
-                            error_id: "SwitchAppearanceConfigMismatch".to_string(),
+                            error_id: RustConstructorError::SWITCH_APPEARANCE_CONFIG_MISMATCH
+                                .to_string(),
                             description: format!(
                                 "Radio group is only supported for switches with 2 states, found {}.",
                                 switch.state_amount
@@ -1992,6 +7768,91 @@ impl App {
                     ),
                 )?;
             }
+            "TextInput" => {
+                let text_input = downcast_resource_mut::<TextInput>(&mut resource)?;
+                self.add_resource(
+                    &format!("{name}Text"),
+                    Text::default()
+                        .from_config(&text_input.text_config)
+                        .selectable(false)
+                        .auto_fit(false, false)
+                        .tags(&text_input.tags, false)
+                        .tags(
+                            &[
+                                ["citer_name".to_string(), name.to_string()],
+                                ["citer_type".to_string(), discern_type.to_string()],
+                                ["panel_layout_group".to_string(), name.to_string()],
+                            ],
+                            false,
+                        ),
+                )?;
+            }
+            "ProgressBar" => {
+                let progress_bar = downcast_resource_mut::<ProgressBar>(&mut resource)?;
+                self.add_resource(
+                    &format!("{name}Track"),
+                    CustomRect::default()
+                        .from_config(&progress_bar.track_config)
+                        .tags(&progress_bar.tags, false)
+                        .tags(
+                            &[
+                                ["citer_name".to_string(), name.to_string()],
+                                ["citer_type".to_string(), discern_type.to_string()],
+                                ["panel_layout_group".to_string(), name.to_string()],
+                            ],
+                            false,
+                        ),
+                )?;
+                self.add_resource(
+                    &format!("{name}Fill"),
+                    CustomRect::default()
+                        .from_config(&progress_bar.track_config)
+                        .tags(&progress_bar.tags, false)
+                        .tags(
+                            &[
+                                ["citer_name".to_string(), name.to_string()],
+                                ["citer_type".to_string(), discern_type.to_string()],
+                            ],
+                            false,
+                        ),
+                )?;
+                self.add_resource(
+                    &format!("{name}Text"),
+                    Text::default()
+                        .from_config(&progress_bar.text_config.clone().unwrap_or_default())
+                        .tags(&progress_bar.tags, false)
+                        .tags(
+                            &[
+                                ["citer_name".to_string(), name.to_string()],
+                                ["citer_type".to_string(), discern_type.to_string()],
+                            ],
+                            false,
+                        ),
+                )?;
+            }
+            "Slider" => {
+                let slider = downcast_resource_mut::<Slider>(&mut resource)?;
+                for (suffix, config) in [
+                    ("Track", &slider.track_config),
+                    ("Fill", &slider.track_config),
+                    ("Handle", &slider.handle_config),
+                ] {
+                    self.add_resource(
+                        &format!("{name}{suffix}"),
+                        CustomRect::default()
+                            .from_config(config)
+                            .tags(&slider.tags, false)
+                            .tags(
+                                &[
+                                    ["citer_name".to_string(), name.to_string()],
+                                    ["citer_type".to_string(), discern_type.to_string()],
+                                    ["panel_layout_group".to_string(), name.to_string()],
+                                ],
+                                false,
+                            ),
+                    )?;
+                }
+            }
             "ResourcePanel" => {
                 let resource_panel = downcast_resource_mut::<ResourcePanel>(&mut resource)?;
                 self.add_resource(
@@ -2098,41 +7959,714 @@ impl App {
             _ => {}
         };
         self.rust_constructor_resource
-            .push(RustConstructorResourceBox::new(
-                name,
-                discern_type,
-                Box::new(resource),
-            ));
-        info!("Added resource: '{name}({discern_type})'");
-        Ok(())
+            .push(RustConstructorResourceBox::new(
+                name,
+                discern_type,
+                Box::new(resource),
+            ));
+        info!("Added resource: '{name}({discern_type})'");
+        Ok(())
+    }
+
+    /// Removes a resource from the application, same as `remove_resource`.
+    ///
+    /// 移除资源，与`remove_resource`相同。
+    #[inline]
+    pub fn drop_resource(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        self.remove_resource(id)
+    }
+
+    /// Removes a resource from the application.
+    ///
+    /// 从应用程序中移除资源。
+    ///
+    /// Entries referring to the resource in the render queue, render layer and active list are
+    /// removed as well, so it will not be drawn in the next frame. Child resources of a
+    /// composite resource (such as `{name}Text` of a text input) are removed along with it.
+    ///
+    /// 渲染队列、渲染层级和活跃列表中指向该资源的条目也会被移除，因此它不会在下一帧被绘制。
+    /// 复合资源的子资源（例如文本输入框的`{name}Text`）会一同移除。
+    pub fn remove_resource(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
+        if let Some(index) = self.check_resource_exists(id) {
+            let stored_id = self.rust_constructor_resource[index].id.clone();
+            let removed_id_list = self.with_composite_children(vec![stored_id.clone()]);
+            self.rust_constructor_resource
+                .retain(|x| !removed_id_list.contains(&x.id));
+            for removed_id in &removed_id_list {
+                self.prune_resource_references(removed_id);
+            }
+            if stored_id != *id {
+                self.prune_resource_references(id);
+            };
+            Ok(())
+        } else {
+            error!(
+                "[ResourceNotFound]remove_resource: Resource '{}({})' not found.",
+                id.name, id.discern_type
+            );
+            Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
+                description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
+            })
+        }
+    }
+
+    /// Renames a resource and rewrites every reference to it.
+    ///
+    /// 重命名资源并改写所有对它的引用。
+    ///
+    /// Child resources of a composite resource (such as `{name}Background` of a switch) are
+    /// renamed along with it. The rename is rejected when any of the new names is already taken
+    /// by a resource of the same type. Inside `with_page_scope` the new name gets the page
+    /// prefix, like in `add_resource`.
+    ///
+    /// 复合资源的子资源（例如开关的`{name}Background`）会一同重命名。
+    /// 任一新名称已被同类型资源占用时会拒绝重命名。在`with_page_scope`中，新名称会像
+    /// `add_resource`一样加上页面前缀。
+    pub fn rename_resource(
+        &mut self,
+        old_id: &RustConstructorId,
+        new_name: &str,
+    ) -> Result<(), RustConstructorError> {
+        let Some(index) = self.check_resource_exists(old_id) else {
+            error!(
+                "[ResourceNotFound]rename_resource: Resource '{}({})' not found.",
+                old_id.name, old_id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
+                description: format!(
+                    "Resource '{}({})' not found.",
+                    old_id.name, old_id.discern_type
+                ),
+            });
+        };
+        if new_name.is_empty() {
+            error!("[ResourceUntitled]rename_resource: All resources must have a valid name.");
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_UNTITLED.to_string(),
+                description: "All resources must have a valid name.".to_string(),
+            });
+        };
+        let old_id = self.rust_constructor_resource[index].id.clone();
+        let new_name = self.scoped_name(new_name);
+        let new_id = build_id(&new_name, &old_id.discern_type);
+        if new_id == old_id {
+            return Ok(());
+        };
+        // 收集子资源及其新名称
+        let children: Vec<(RustConstructorId, String)> = self
+            .composite_children(&old_id)
+            .into_iter()
+            .map(|(id, suffix)| (id, format!("{new_name}{suffix}")))
+            .collect();
+        let new_id_list: Vec<RustConstructorId> = std::iter::once(new_id.clone())
+            .chain(children.iter().map(|x| build_id(&x.1, &x.0.discern_type)))
+            .collect();
+        self.ensure_names_available(&new_id_list, "rename_resource")?;
+        self.rust_constructor_resource[index].id = new_id.clone();
+        self.rename_resource_references(&old_id, &new_id);
+        for (child_id, child_name) in children {
+            self.rename_resource(&child_id, &child_name)?;
+        }
+        Ok(())
+    }
+
+    /// Copies a resource under a new name and returns the id of the copy.
+    ///
+    /// 以新名称复制资源并返回副本的id。
+    ///
+    /// Interaction state such as selections, hover and click flags is reset on the copy. Child
+    /// resources of a composite resource (such as the hint text and timers of a switch) are
+    /// copied as well, so the copy works on its own.
+    ///
+    /// 副本的选区、悬停和点击标记等交互状态会被重置。复合资源的子资源（例如开关的提示文本和计时器）
+    /// 也会被复制，因此副本可以独立工作。
+    pub fn duplicate_resource(
+        &mut self,
+        src_id: &RustConstructorId,
+        new_name: &str,
+    ) -> Result<RustConstructorId, RustConstructorError> {
+        let Some(index) = self.check_resource_exists(src_id) else {
+            error!(
+                "[ResourceNotFound]duplicate_resource: Resource '{}({})' not found.",
+                src_id.name, src_id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
+                description: format!(
+                    "Resource '{}({})' not found.",
+                    src_id.name, src_id.discern_type
+                ),
+            });
+        };
+        if new_name.is_empty() {
+            error!("[ResourceUntitled]duplicate_resource: All resources must have a valid name.");
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_UNTITLED.to_string(),
+                description: "All resources must have a valid name.".to_string(),
+            });
+        };
+        let src_id = self.rust_constructor_resource[index].id.clone();
+        let new_name = self.scoped_name(new_name);
+        let new_id = build_id(&new_name, &src_id.discern_type);
+        let children = self.composite_children(&src_id);
+        let new_id_list: Vec<RustConstructorId> = std::iter::once(new_id.clone())
+            .chain(
+                children
+                    .iter()
+                    .map(|(id, suffix)| build_id(format!("{new_name}{suffix}"), &id.discern_type)),
+            )
+            .collect();
+        self.ensure_names_available(&new_id_list, "duplicate_resource")?;
+        let Some(mut content) = self.clone_resource(index) else {
+            error!(
+                "[ResourceNotCloneable]duplicate_resource: Resource '{}({})' cannot be copied.",
+                src_id.name, src_id.discern_type
+            );
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_NOT_CLONEABLE.to_string(),
+                description: format!(
+                    "Resource '{}({})' cannot be copied.",
+                    src_id.name, src_id.discern_type
+                ),
+            });
+        };
+        Self::reset_transient_state(&src_id.discern_type, &mut *content)?;
+        if get_tag("panel_layout_group", &content.display_tags())
+            .is_some_and(|x| x.1 == src_id.name)
+        {
+            content.modify_tags(
+                &[["panel_layout_group".to_string(), new_name.clone()]],
+                false,
+            );
+        };
+        self.rust_constructor_resource
+            .push(RustConstructorResourceBox::new(
+                &new_name,
+                &src_id.discern_type,
+                content,
+            ));
+        // 复制子资源并让其指向副本
+        for (child_id, suffix) in children {
+            let copy_id = self.duplicate_resource(&child_id, &format!("{new_name}{suffix}"))?;
+            let content = self.get_box_resource_mut(&copy_id)?;
+            let mut tags = vec![["citer_name".to_string(), new_name.clone()]];
+            if get_tag("panel_layout_group", &content.display_tags())
+                .is_some_and(|x| x.1 == src_id.name)
+            {
+                tags.push(["panel_layout_group".to_string(), new_name.clone()]);
+            };
+            content.modify_tags(&tags, false);
+        }
+        info!(
+            "Duplicated resource: '{}({})' as '{new_name}'",
+            src_id.name, src_id.discern_type
+        );
+        Ok(new_id)
+    }
+
+    /// Returns a copy of the resource at an index, None if its type cannot be copied.
+    ///
+    /// 返回指定索引处资源的副本，无法复制该类型时返回None。
+    fn clone_resource(&self, index: usize) -> Option<Box<dyn RustConstructorResource>> {
+        let content = &self.rust_constructor_resource[index].content;
+        if let Some(front) = content.convert_to_front() {
+            return Some(front.convert_to_original());
+        };
+        let any = content.as_any();
+        macro_rules! clone_as {
+            ($($ty:ty),*) => {
+                $(
+                    if let Some(resource) = any.downcast_ref::<$ty>() {
+                        return Some(Box::new(resource.clone()));
+                    };
+                )*
+            };
+        }
+        clone_as!(
+            SplitTime,
+            NamedTimer,
+            Countdown,
+            SwitchGroup,
+            ResourceGroup,
+            Variable<bool>,
+            Variable<i32>,
+            Variable<i64>,
+            Variable<u32>,
+            Variable<u64>,
+            Variable<usize>,
+            Variable<f32>,
+            Variable<f64>,
+            Variable<String>
+        );
+        None
+    }
+
+    /// Returns the child resources of a composite resource with the suffix added to its name.
+    ///
+    /// 返回复合资源的子资源，以及在其名称后添加的后缀。
+    fn composite_children(&self, id: &RustConstructorId) -> Vec<(RustConstructorId, String)> {
+        self.rust_constructor_resource
+            .iter()
+            .filter(|x| {
+                let tags = x.content.display_tags();
+                get_tag("citer_name", &tags).is_some_and(|x| x.1 == id.name)
+                    && get_tag("citer_type", &tags).is_some_and(|x| x.1 == id.discern_type)
+            })
+            .filter_map(|x| {
+                x.id.name
+                    .strip_prefix(&id.name)
+                    .map(|suffix| (x.id.clone(), suffix.to_string()))
+            })
+            .collect()
+    }
+
+    /// Adds the child resources of every composite resource in the list, including children of
+    /// children.
+    ///
+    /// 为列表中的每个复合资源加入其子资源，包括子资源的子资源。
+    fn with_composite_children(
+        &self,
+        mut id_list: Vec<RustConstructorId>,
+    ) -> Vec<RustConstructorId> {
+        let mut index = 0;
+        while index < id_list.len() {
+            for (child_id, _) in self.composite_children(&id_list[index]) {
+                if !id_list.contains(&child_id) {
+                    id_list.push(child_id);
+                };
+            }
+            index += 1;
+        }
+        id_list
+    }
+
+    /// Reports an error if any of the ids is already taken.
+    ///
+    /// 若任一id已被占用则报告错误。
+    fn ensure_names_available(
+        &self,
+        id_list: &[RustConstructorId],
+        method: &str,
+    ) -> Result<(), RustConstructorError> {
+        for id in id_list {
+            if self.rust_constructor_resource.iter().any(|x| x.id == *id) {
+                error!(
+                    "[ResourceNameRepetition]{method}: Resource '{}({})' has already existed.",
+                    id.name, id.discern_type
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::RESOURCE_NAME_REPETITION.to_string(),
+                    description: format!(
+                        "Resource '{}({})' has already existed.",
+                        id.name, id.discern_type
+                    ),
+                });
+            };
+        }
+        Ok(())
+    }
+
+    /// Points every reference to a resource at its new id.
+    ///
+    /// 将所有对资源的引用指向其新id。
+    fn rename_resource_references(&mut self, old: &RustConstructorId, new: &RustConstructorId) {
+        let rename = |id: &mut RustConstructorId| {
+            if *id == *old {
+                *id = new.clone();
+            };
+        };
+        for (id, citer) in self
+            .render_list
+            .iter_mut()
+            .chain(self.active_list.iter_mut())
+        {
+            rename(id);
+            if let Some(citer) = citer {
+                rename(citer);
+            };
+        }
+        if let Some(page_transition) = &mut self.active_page_transition {
+            for (id, citer) in &mut page_transition.outgoing {
+                rename(id);
+                if let Some(citer) = citer {
+                    rename(citer);
+                };
+            }
+        };
+        self.render_layer.iter_mut().for_each(|x| rename(&mut x.0));
+        self.post_draw_hooks
+            .iter_mut()
+            .for_each(|x| rename(&mut x.0));
+        self.copy_hooks.iter_mut().for_each(|x| rename(&mut x.0));
+        self.inherited_clips
+            .iter_mut()
+            .for_each(|x| rename(&mut x.0));
+        self.clickables
+            .iter_mut()
+            .for_each(|x| rename(&mut x.target));
+        self.draggables.iter_mut().for_each(rename);
+        self.drop_targets.iter_mut().for_each(rename);
+        if let Some(drag_state) = &mut self.drag_state {
+            rename(&mut drag_state.source);
+        };
+        self.theme_bindings
+            .iter_mut()
+            .for_each(|x| rename(&mut x.0));
+        self.text_layout_cache
+            .iter_mut()
+            .for_each(|x| rename(&mut x.target));
+        #[cfg(feature = "svg")]
+        self.svg_sources.iter_mut().for_each(|x| rename(&mut x.0));
+        self.context_menus.iter_mut().for_each(|x| {
+            rename(&mut x.0);
+            rename(&mut x.1);
+        });
+        if let Some(active_modal) = &mut self.active_modal {
+            rename(active_modal);
+        };
+        self.entrance_animations
+            .iter_mut()
+            .for_each(|x| rename(&mut x.0));
+        self.hover_start_time
+            .iter_mut()
+            .for_each(|x| rename(&mut x.0));
+        self.z_index.iter_mut().for_each(|x| rename(&mut x.0));
+        self.anchors.iter_mut().for_each(|x| {
+            rename(&mut x.child);
+            rename(&mut x.parent);
+        });
+        self.breakpoints
+            .iter_mut()
+            .for_each(|x| rename(&mut x.target));
+        self.relative_layouts.iter_mut().for_each(|x| {
+            rename(&mut x.0);
+            rename(&mut x.1);
+        });
+        self.focus_order.iter_mut().for_each(rename);
+        self.animations
+            .iter_mut()
+            .for_each(|x| rename(&mut x.1.target));
+        if let Some(last_copied) = &mut self.last_copied {
+            rename(&mut last_copied.0);
+        };
+        for tooltip in &mut self.tooltips {
+            rename(&mut tooltip.target);
+            if old.discern_type == "Text" && tooltip.text_name == old.name {
+                tooltip.text_name = new.name.clone();
+            };
+        }
+        match &*old.discern_type {
+            "Variable" => {
+                for watcher in &mut self.variable_watchers {
+                    if watcher.name == old.name {
+                        watcher.name = new.name.clone();
+                    };
+                }
+                for computed_variable in &mut self.computed_variables {
+                    if computed_variable.name == old.name {
+                        computed_variable.name = new.name.clone();
+                    };
+                    for dependency in &mut computed_variable.dependencies {
+                        if *dependency == old.name {
+                            *dependency = new.name.clone();
+                        };
+                    }
+                }
+            }
+            "PageData" => {
+                if self.current_page == old.name {
+                    self.current_page = new.name.clone();
+                };
+                for page in &mut self.page_history {
+                    if *page == old.name {
+                        *page = new.name.clone();
+                    };
+                }
+                if let Some(page_transition) = &mut self.active_page_transition {
+                    for page in [&mut page_transition.from_page, &mut page_transition.to_page] {
+                        if *page == old.name {
+                            *page = new.name.clone();
+                        };
+                    }
+                };
+            }
+            _ => {}
+        };
+        // 改写其他资源中保存的引用和标签
+        let renamed_tags: &[&str] = match &*old.discern_type {
+            "PageData" => &["page"],
+            "ResourcePanel" => &["panel_name", "panel_layout_group"],
+            _ => &["panel_layout_group"],
+        };
+        for rcr in &mut self.rust_constructor_resource {
+            let mut tags = rcr.content.display_tags();
+            let cited = get_tag("citer_type", &tags).is_some_and(|x| x.1 == old.discern_type);
+            let mut tags_changed = false;
+            for tag in &mut tags {
+                if (renamed_tags.contains(&&*tag[0]) || cited && tag[0] == "citer_name")
+                    && tag[1] == old.name
+                {
+                    tag[1] = new.name.clone();
+                    tags_changed = true;
+                };
+            }
+            if tags_changed {
+                rcr.content.modify_tags(&tags, true);
+            };
+            let any = rcr.content.as_any_mut();
+            if let Some(switch_group) = any.downcast_mut::<SwitchGroup>() {
+                if old.discern_type == "Switch" {
+                    for switch in &mut switch_group.switches {
+                        if *switch == old.name {
+                            *switch = new.name.clone();
+                        };
+                    }
+                };
+            } else if let Some(resource_group) = any.downcast_mut::<ResourceGroup>() {
+                for member in &mut resource_group.members {
+                    if *member == *old {
+                        *member = new.clone();
+                    };
+                }
+            } else if let Some(line) = any.downcast_mut::<Line>() {
+                for endpoint in [&mut line.start, &mut line.end] {
+                    if let LineEndpoint::Resource(id) = endpoint {
+                        rename(id);
+                    };
+                }
+            } else if let Some(slider) = any.downcast_mut::<Slider>() {
+                if old.discern_type == "Variable" && slider.variable.as_ref() == Some(&old.name) {
+                    slider.variable = Some(new.name.clone());
+                };
+            } else if let Some(resource_panel) = any.downcast_mut::<ResourcePanel>() {
+                resource_panel
+                    .resource_storage
+                    .iter_mut()
+                    .for_each(|x| rename(&mut x.id));
+                for custom_layout in &mut resource_panel.custom_layout {
+                    if let CustomPanelLayout::Id(id, _) = custom_layout {
+                        rename(id);
+                    };
+                }
+                for custom_config in &mut resource_panel.custom_config {
+                    if let CustomPanelConfig::Id(id, _) = custom_config {
+                        rename(id);
+                    };
+                }
+                if let Some(vertical_list) = &mut resource_panel.vertical_list {
+                    vertical_list.items.iter_mut().for_each(rename);
+                };
+            };
+        }
+    }
+
+    /// Removes all resources of a type and returns how many were removed.
+    ///
+    /// 移除指定类型的所有资源并返回被移除的数量。
+    ///
+    /// Child resources of removed composite resources are removed and counted as well.
+    ///
+    /// 被移除的复合资源的子资源也会被移除并计入数量。
+    pub fn remove_resources_by_type(&mut self, discern_type: &str) -> usize {
+        let removed_id_list = self.with_composite_children(
+            self.rust_constructor_resource
+                .iter()
+                .filter(|x| x.id.discern_type == discern_type)
+                .map(|x| x.id.clone())
+                .collect(),
+        );
+        self.rust_constructor_resource
+            .retain(|x| !removed_id_list.contains(&x.id));
+        for id in &removed_id_list {
+            self.prune_resource_references(id);
+        }
+        removed_id_list.len()
     }
 
-    /// Removes a resource from the application. This method is very dangerous! Ensure the resource is no longer in use before deletion.
+    /// Enables or disables every basic front resource carrying the tag `[tag_name, tag_value]`
+    /// and returns how many were changed.
     ///
-    /// 移除资源。此方法非常危险！务必确保资源一定不再使用后删除。
-    pub fn drop_resource(&mut self, id: &RustConstructorId) -> Result<(), RustConstructorError> {
-        if let Some(index) = self.check_resource_exists(id) {
-            self.rust_constructor_resource.remove(index);
-            if let Some(index) = self.active_list.iter().position(|x| &x.0 == id) {
-                self.active_list.remove(index);
+    /// 启用或禁用所有带有`[tag_name, tag_value]`标签的基本前端资源，并返回被修改的数量。
+    ///
+    /// The disable is remembered in `disabled_tags` and survives the per-frame enable reset of
+    /// the page pipeline until the tag is enabled again, resources tagged later are disabled
+    /// from their next frame on.
+    ///
+    /// 禁用会记录在`disabled_tags`中，不受页面流程每帧重置启用状态的影响，直到该标签被重新启用，
+    /// 之后才添加该标签的资源会从下一帧开始被禁用。
+    pub fn set_tag_enabled(&mut self, tag_name: &str, tag_value: &str, enable: bool) -> usize {
+        self.disabled_tags
+            .retain(|x| x[0] != tag_name || x[1] != tag_value);
+        if !enable {
+            self.disabled_tags
+                .push([tag_name.to_string(), tag_value.to_string()]);
+        };
+        self.modify_tagged_display_info(tag_name, tag_value, |display_info| {
+            display_info.enable = enable;
+        })
+    }
+
+    /// Hides or shows every basic front resource carrying the tag `[tag_name, tag_value]`
+    /// and returns how many were changed.
+    ///
+    /// 隐藏或显示所有带有`[tag_name, tag_value]`标签的基本前端资源，并返回被修改的数量。
+    pub fn set_tag_hidden(&mut self, tag_name: &str, tag_value: &str, hidden: bool) -> usize {
+        self.modify_tagged_display_info(tag_name, tag_value, |display_info| {
+            display_info.hidden = hidden;
+        })
+    }
+
+    fn modify_tagged_display_info(
+        &mut self,
+        tag_name: &str,
+        tag_value: &str,
+        modify: impl Fn(&mut DisplayInfo),
+    ) -> usize {
+        let mut count = 0;
+        for rcr in &mut self.rust_constructor_resource {
+            if !get_tag(tag_name, &rcr.content.display_tags()).is_some_and(|x| x.1 == tag_value) {
+                continue;
             };
-            if let Some(index) = self
-                .render_layer
-                .iter()
-                .position(|x| x.0.name == id.name && x.0.discern_type == id.discern_type)
+            if let Some(basic_front_resource) = rcr.content.convert_to_basic_front_dyn_mut() {
+                let mut display_info = basic_front_resource.display_display_info();
+                modify(&mut display_info);
+                basic_front_resource.modify_display_info(display_info);
+                count += 1;
+            };
+        }
+        count
+    }
+
+    /// Stacks resources top to bottom inside a resource panel as a scrollable list.
+    ///
+    /// 在资源板内将资源从上到下堆叠为可滚动的列表。
+    ///
+    /// The items are tagged into the panel, placed `spacing` pixels apart in the given order
+    /// and moved every frame, so items whose height changes (e.g. wrapped text) push the
+    /// following ones down. They are clipped to the panel, and when the panel has no vertical
+    /// scroll length method it gets `ScrollLengthMethod::AutoFit(0.0)` so the list can scroll
+    /// through its whole height.
+    ///
+    /// 项目会被标记到资源板中，按给定顺序以`spacing`像素的间距放置并每帧更新位置，
+    /// 因此高度变化的项目（例如换行的文本）会把后面的项目向下推。项目会被裁剪到资源板内，
+    /// 资源板没有垂直滚动长度计算方法时会使用`ScrollLengthMethod::AutoFit(0.0)`，
+    /// 使列表可以滚动浏览全部内容。
+    pub fn build_vertical_list(
+        &mut self,
+        panel_id: &RustConstructorId,
+        item_ids: &[RustConstructorId],
+        spacing: f32,
+    ) -> Result<(), RustConstructorError> {
+        self.get_resource::<ResourcePanel>(panel_id)?;
+        // 记录资源的实际名称（可能带有页面作用域前缀）
+        let panel_name = self.resolved_id(panel_id)?.name;
+        let mut items = Vec::new();
+        for id in item_ids {
+            if !self.basic_front_resource_list.contains(&id.discern_type) {
+                error!(
+                    "[ResourceDowncastTypeMismatch]build_vertical_list: Resource '{}({})' is not a basic front resource.",
+                    id.name, id.discern_type
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
+                    description: format!(
+                        "Resource '{}({})' is not a basic front resource.",
+                        id.name, id.discern_type
+                    ),
+                });
+            };
+            let resource = self.get_box_resource_mut(id)?;
+            resource.modify_tags(&[["panel_name".to_string(), panel_name.clone()]], false);
+            items.push(self.resolved_id(id)?);
+        }
+        let resource_panel = self.get_resource_mut::<ResourcePanel>(panel_id)?;
+        resource_panel.vertical_list = Some(VerticalList { items, spacing });
+        if resource_panel.scroll_length_method[1].is_none() {
+            resource_panel.scroll_length_method[1] = Some(ScrollLengthMethod::AutoFit(0_f32));
+        };
+        Ok(())
+    }
+
+    /// Clips every resource used inside the closure to a rect given as [position, size].
+    ///
+    /// 将闭包中使用的所有资源裁剪到以[位置, 尺寸]给出的矩形内。
+    ///
+    /// Calls can be nested, the inner rect is intersected with the outer one. The clip is
+    /// combined with each resource's own `clip_rect` when the resource is drawn, and restored
+    /// afterwards instead of being reset to the whole window.
+    ///
+    /// 调用可以嵌套，内层矩形会与外层矩形取交集。绘制资源时裁剪会与资源自身的`clip_rect`合并，
+    /// 绘制完成后会恢复原有裁剪，而不是重置为整个窗口。
+    pub fn with_clip<R>(&mut self, clip_rect: [[f32; 2]; 2], f: impl FnOnce(&mut App) -> R) -> R {
+        let rect = Rect::from_min_size(clip_rect[0].into(), clip_rect[1].into());
+        let rect = match self.clip_stack.last() {
+            Some(parent) => parent.intersect(rect),
+            None => rect,
+        };
+        self.clip_stack.push(rect);
+        let result = f(self);
+        self.clip_stack.pop();
+        result
+    }
+
+    /// Drops every reference to a removed resource kept by the application.
+    ///
+    /// 清除应用程序中对已移除资源的所有引用。
+    fn prune_resource_references(&mut self, id: &RustConstructorId) {
+        self.render_list.retain(|x| x.0 != *id);
+        self.render_layer.retain(|x| x.0 != *id);
+        self.active_list.retain(|x| x.0 != *id);
+        self.post_draw_hooks.retain(|x| x.0 != *id);
+        self.copy_hooks.retain(|x| x.0 != *id);
+        self.inherited_clips.retain(|x| x.0 != *id);
+        self.clickables.retain(|x| x.target != *id);
+        self.draggables.retain(|x| x != id);
+        self.drop_targets.retain(|x| x != id);
+        if self.drag_state.as_ref().is_some_and(|x| x.source == *id) {
+            self.drag_state = None;
+        };
+        self.theme_bindings.retain(|x| x.0 != *id);
+        self.text_layout_cache.retain(|x| x.target != *id);
+        #[cfg(feature = "svg")]
+        self.svg_sources.retain(|x| x.0 != *id);
+        self.context_menus.retain(|x| x.0 != *id && x.1 != *id);
+        if self.active_modal.as_ref() == Some(id) {
+            self.release_active_modal();
+        };
+        self.entrance_animations.retain(|x| x.0 != *id);
+        self.hover_start_time.retain(|x| x.0 != *id);
+        self.z_index.retain(|x| x.0 != *id);
+        self.tooltips.retain(|x| x.target != *id);
+        self.anchors.retain(|x| x.child != *id && x.parent != *id);
+        self.breakpoints.retain(|x| x.target != *id);
+        self.relative_layouts.retain(|x| x.0 != *id && x.1 != *id);
+        if id.discern_type == "Variable" {
+            self.variable_watchers.retain(|x| x.name != id.name);
+            // 依赖被移除变量的计算变量无法再重新计算
+            self.computed_variables
+                .retain(|x| x.name != id.name && !x.dependencies.contains(&id.name));
+        };
+        if id.discern_type == "Switch" {
+            for rcr in self
+                .rust_constructor_resource
+                .iter_mut()
+                .filter(|x| x.id.discern_type == "SwitchGroup")
             {
-                self.render_layer.remove(index);
+                if let Ok(switch_group) = downcast_resource_mut::<SwitchGroup>(&mut *rcr.content) {
+                    switch_group.switches.retain(|x| *x != id.name);
+                };
+            }
+        };
+        for rcr in self
+            .rust_constructor_resource
+            .iter_mut()
+            .filter(|x| x.id.discern_type == "ResourceGroup")
+        {
+            if let Ok(resource_group) = downcast_resource_mut::<ResourceGroup>(&mut *rcr.content) {
+                resource_group.members.retain(|x| x != id);
             };
-            Ok(())
-        } else {
-            error!(
-                "[ResourceNotFound]drop_resource: Resource '{}({})' not found.",
-                id.name, id.discern_type
-            );
-            Err(RustConstructorError {
-                error_id: "ResourceNotFound".to_string(),
-                description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
-            })
         }
     }
 
@@ -2149,15 +8683,17 @@ impl App {
     {
         let discern_type = &*type_processor(&resource);
         if let Some(index) = self.check_resource_exists(&build_id(name, discern_type)) {
+            // 保留资源的实际名称（可能带有页面作用域前缀）
+            let name = self.rust_constructor_resource[index].id.name.clone();
             self.rust_constructor_resource[index] =
-                RustConstructorResourceBox::new(name, discern_type, Box::new(resource));
+                RustConstructorResourceBox::new(&name, discern_type, Box::new(resource));
             Ok(())
         } else {
             error!(
                 "[ResourceNotFound]replace_resource: Resource '{name}({discern_type})' not found."
             );
             Err(RustConstructorError {
-                error_id: "ResourceNotFound".to_string(),
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
                 description: format!("Resource '{name}({discern_type})' not found."),
             })
         }
@@ -2178,6 +8714,7 @@ impl App {
             "Image" => Ok(downcast_resource::<Image>(self.get_box_resource(id)?)?),
             "Text" => Ok(downcast_resource::<Text>(self.get_box_resource(id)?)?),
             "CustomRect" => Ok(downcast_resource::<CustomRect>(self.get_box_resource(id)?)?),
+            "Polygon" => Ok(downcast_resource::<Polygon>(self.get_box_resource(id)?)?),
             _ => unreachable!(),
         }
     }
@@ -2204,6 +8741,9 @@ impl App {
             "CustomRect" => Ok(downcast_resource_mut::<CustomRect>(
                 self.get_box_resource_mut(id)?,
             )?),
+            "Polygon" => Ok(downcast_resource_mut::<Polygon>(
+                self.get_box_resource_mut(id)?,
+            )?),
             _ => unreachable!(),
         }
     }
@@ -2227,7 +8767,7 @@ impl App {
                 id.name, id.discern_type
             );
             Err(RustConstructorError {
-                error_id: "ResourceNotFound".to_string(),
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
                 description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
             })
         }
@@ -2252,7 +8792,7 @@ impl App {
                 id.name, id.discern_type
             );
             Err(RustConstructorError {
-                error_id: "ResourceNotFound".to_string(),
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
                 description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
             })
         }
@@ -2281,15 +8821,100 @@ impl App {
         downcast_resource_mut(self.get_box_resource_mut(id)?)
     }
 
+    /// Returns the ids of resources whose names contain the query, ignoring case.
+    ///
+    /// 返回名称包含查询内容（不区分大小写）的资源id。
+    pub fn find_resources(&self, query: &str) -> Vec<RustConstructorId> {
+        let query = query.to_lowercase();
+        self.rust_constructor_resource
+            .iter()
+            .filter(|x| x.id.name.to_lowercase().contains(&query))
+            .map(|x| x.id.clone())
+            .collect()
+    }
+
+    /// Returns the ids of all resources of a type.
+    ///
+    /// 返回指定类型的所有资源id。
+    pub fn resources_of_type(&self, discern_type: &str) -> Vec<RustConstructorId> {
+        self.rust_constructor_resource
+            .iter()
+            .filter(|x| x.id.discern_type == discern_type)
+            .map(|x| x.id.clone())
+            .collect()
+    }
+
+    /// Returns the id a resource is stored under, resolving the page scope prefix.
+    ///
+    /// 返回资源实际存储使用的ID，会解析页面作用域前缀。
+    pub fn resolved_id(
+        &self,
+        id: &RustConstructorId,
+    ) -> Result<RustConstructorId, RustConstructorError> {
+        if let Some(index) = self.check_resource_exists(id) {
+            Ok(self.rust_constructor_resource[index].id.clone())
+        } else {
+            error!(
+                "[ResourceNotFound]resolved_id: Resource '{}({})' not found.",
+                id.name, id.discern_type
+            );
+            Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
+                description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
+            })
+        }
+    }
+
     /// Checks if a specific resource exists in the application.
     ///
     /// 检查应用程序中是否存在特定资源。
     pub fn check_resource_exists(&self, id: &RustConstructorId) -> Option<usize> {
+        // 在页面作用域内优先查找带前缀的资源
+        let scoped_name = self.scoped_name(&id.name);
+        if scoped_name != id.name
+            && let Some(index) = self
+                .rust_constructor_resource
+                .iter()
+                .position(|x| x.id.name == scoped_name && x.id.discern_type == id.discern_type)
+        {
+            return Some(index);
+        };
         self.rust_constructor_resource
             .iter()
             .position(|x| &x.id == id)
     }
 
+    /// Returns the name a resource gets when added inside the current page scope.
+    ///
+    /// 返回在当前页面作用域内添加资源时使用的名称。
+    pub fn scoped_name(&self, name: &str) -> String {
+        match &self.page_scope {
+            Some(page) if !name.starts_with(&format!("{page}::")) => format!("{page}::{name}"),
+            _ => name.to_string(),
+        }
+    }
+
+    /// Runs the closure with resource names scoped to a page.
+    ///
+    /// 在页面作用域内运行闭包。
+    ///
+    /// Resources added inside the closure are stored as `page::name`, and lookups inside it
+    /// try the prefixed name before the plain one, so different pages can reuse names like
+    /// "title" without clashing. They also get the tag `["page", page]` unless they already
+    /// carry a page tag, which makes them part of the page for `clear_page_resources`,
+    /// `snapshot` and `serialize_page_layout`.
+    ///
+    /// 闭包内添加的资源会以`page::name`的名称存储，闭包内的查找会先尝试带前缀的名称再尝试原名称，
+    /// 因此不同页面可以重复使用"title"这样的名称而不会冲突。这些资源在尚未带有页面标签时还会获得
+    /// `["page", page]`标签，从而在`clear_page_resources`、`snapshot`和`serialize_page_layout`
+    /// 中属于该页面。
+    pub fn with_page_scope<R>(&mut self, page: &str, f: impl FnOnce(&mut App) -> R) -> R {
+        let previous_scope = self.page_scope.replace(page.to_string());
+        let result = f(self);
+        self.page_scope = previous_scope;
+        result
+    }
+
     /// Quickly adds and uses a resource in one operation.
     ///
     /// 快速添加并使用资源。
@@ -2328,6 +8953,11 @@ impl App {
         auto_track: Option<Box<dyn Config>>,
         ui: &mut Ui,
     ) -> Result<(), RustConstructorError> {
+        // 将作用域内的名称解析为实际名称，避免渲染列表中记录未加前缀的名称
+        let id = &match self.check_resource_exists(id) {
+            Some(index) => self.rust_constructor_resource[index].id.clone(),
+            None => id.clone(),
+        };
         if self.check_resource_exists(id).is_some() {
             if let Some(auto_track) = auto_track
                 && let Some(mut front_resource) = self.get_box_resource_mut(id)?.convert_to_front()
@@ -2345,20 +8975,81 @@ impl App {
                 };
             }
             match &*id.discern_type {
-                "CustomRect" | "Text" | "Image" => {
+                "CustomRect" | "Text" | "Image" | "Polygon" => {
+                    // 记录资源在with_clip中使用时继承的裁剪矩形
+                    self.inherited_clips.retain(|x| x.0 != *id);
+                    if let Some(clip) = self.clip_stack.last() {
+                        self.inherited_clips.push((id.clone(), *clip));
+                    };
                     self.add_active_resource(id)?;
                 }
                 "PageData" => {
                     // 更新帧数
                     self.update_frame_stats();
+                    // 应用界面缩放。
+                    if self.ui_scale_changed {
+                        ui.ctx().set_zoom_factor(self.ui_scale);
+                        self.ui_scale_changed = false;
+                    };
+                    // 重新加载源文件已变化的纹理。
+                    self.update_texture_hot_reload();
                     // 消费已完成的后台图片加载并创建纹理。
                     self.process_completed_image_loads(ui);
+                    // 推进补间动画。
+                    self.update_animations();
+                    // 更新工具提示。
+                    self.update_tooltips(ui)?;
+                    // 更新可点击资源的状态。
+                    self.update_clickables(ui);
+                    // 处理拖放。
+                    self.update_drag_and_drop(ui);
+                    // 处理键盘焦点切换。
+                    self.update_focus(ui);
+                    // 检查快捷键。
+                    self.update_shortcuts(ui);
+                    // 处理手柄导航。
+                    #[cfg(feature = "gamepad")]
+                    self.update_gamepad()?;
                     // 更新渲染队列。
                     self.update_render_list();
                     // 绘制渲染队列中的资源。
-                    for i in 0..self.render_list.len() {
-                        self.draw_resource_by_index(ui, i)?;
-                    }
+                    self.culled_resources = 0;
+                    if self.active_page_transition.is_some() {
+                        self.draw_page_transition(ui)?;
+                    } else {
+                        let dragged = self.dragged_resource();
+                        for i in 0..self.render_list.len() {
+                            if self.in_secondary_window(&self.render_list[i].0)
+                                || dragged
+                                    .as_ref()
+                                    .is_some_and(|x| x.0 == self.render_list[i].0)
+                            {
+                                continue;
+                            };
+                            self.draw_resource_by_index(ui, i)?;
+                        }
+                        // 被拖动的资源跟随指针绘制在最上方
+                        if let Some((source, offset)) = dragged
+                            && !self.in_secondary_window(&source)
+                            && let Some(index) = self.render_list.iter().position(|x| x.0 == source)
+                        {
+                            self.draw_with_visual_effect(ui, index, 0.8, offset.into())?;
+                        };
+                    };
+                    // 绘制次级窗口。
+                    self.draw_secondary_windows(ui)?;
+                    // 绘制焦点框。
+                    self.draw_focus_ring(ui);
+                    // 处理并绘制右键菜单。
+                    self.update_context_menus(ui)?;
+                    // 处理并绘制模态对话框。
+                    self.update_modals(ui)?;
+                    // 在所有资源上方绘制提示消息。
+                    self.draw_toasts(ui);
+                    // 绘制调试浮层。
+                    if self.debug_overlay {
+                        self.draw_debug_overlay(ui);
+                    };
                     // 更新渲染列表。
                     self.update_render_layer(ui)?;
                     // 更新资源活跃状态。
@@ -2368,25 +9059,40 @@ impl App {
                         self.loaded_fonts = self.loading_fonts.clone();
                         self.loading_fonts.clear();
                     };
-                    // 更新资源启用状态。
+                    // 更新资源启用状态，通过标签禁用的资源保持禁用。
                     for rcr in &mut self.rust_constructor_resource {
+                        let tag_disabled = !self.disabled_tags.is_empty() && {
+                            let tags = rcr.content.display_tags();
+                            self.disabled_tags
+                                .iter()
+                                .any(|x| get_tag(&x[0], &tags).is_some_and(|tag| tag.1 == x[1]))
+                        };
                         if let Some(basic_front_resource) =
                             rcr.content.convert_to_basic_front_dyn_mut()
                         {
                             let display_info = basic_front_resource.display_display_info();
                             basic_front_resource.modify_display_info(DisplayInfo {
-                                enable: true,
+                                enable: !tag_disabled,
                                 hidden: display_info.hidden,
                                 ignore_render_layer: display_info.ignore_render_layer,
+                                always_draw: display_info.always_draw,
                             });
                         };
                     }
                     // 更新计时器
                     self.update_timer();
+                    // 根据动画状态选择刷新间隔
+                    let idle = self.update_tick_interval();
                     let page_data =
                         self.get_resource::<PageData>(&build_id(&self.current_page, "PageData"))?;
                     if page_data.forced_update {
-                        ui.request_repaint();
+                        if idle {
+                            ui.ctx().request_repaint_after(Duration::from_millis(
+                                self.tick_interval as u64,
+                            ));
+                        } else {
+                            ui.request_repaint();
+                        };
                     };
                 }
                 "Background" => {
@@ -2412,10 +9118,8 @@ impl App {
                     let start_hover_time = format!("{}StartHoverTime", &id.name);
                     let hint_fade_animation = format!("{}HintFadeAnimation", &id.name);
                     let background_resource_type = background_type_discern(&switch.background_type);
-                    let background_resource = self.get_basic_front_resource(&build_id(
-                        &background_name,
-                        background_resource_type,
-                    ))?;
+                    let background_id = build_id(&background_name, background_resource_type);
+                    let background_resource = self.get_basic_front_resource(&background_id)?;
                     let display_info = background_resource.display_display_info();
                     let mut hint_text = self
                         .get_resource::<Text>(&build_id(hint_name.clone(), "Text"))?
@@ -2424,14 +9128,15 @@ impl App {
                     let animation_count =
                         1 + switch.enable_animation.iter().filter(|x| **x).count();
                     let mut clicked = None;
+                    let mut activated = None;
                     let mut hovered = false;
                     let mut appearance_count = 0;
                     // 处理点击事件
-                    if let Some(index) = self.get_render_layer_resource(&build_id(
-                        &background_name,
-                        background_resource_type.to_string(),
-                    )) && switch.enable
-                        && let Some(mouse_pos) = ui.input(|i| i.pointer.hover_pos())
+                    if let Some(index) = self.get_render_layer_resource(&background_id)
+                        && switch.enable
+                        && !switch.disabled
+                        && let Some(mouse_pos) =
+                            self.resource_input(ui, &background_id, |i| i.pointer.hover_pos())
                         && self.resource_get_focus(index, mouse_pos.into(), true, vec![])
                         && !display_info.hidden
                     {
@@ -2471,7 +9176,7 @@ impl App {
                         };
                         hovered = true;
                         for (count, click_method) in switch.click_method.iter().enumerate() {
-                            if ui.input(|i| {
+                            if self.resource_input(ui, &background_id, |i| {
                                 switch.last_frame_clicked.is_none()
                                     && i.pointer.button_pressed(click_method.click_method)
                                     || switch.last_frame_clicked.is_some()
@@ -2484,30 +9189,7 @@ impl App {
                         if let Some(clicked_index) = switch.last_frame_clicked
                             && clicked.is_none()
                         {
-                            switch.switched = true;
-                            if switch.click_method[clicked_index].action {
-                                if !switch.radio_group.is_empty() {
-                                    self.rust_constructor_resource
-                                        .iter_mut()
-                                        .filter(|x| &x.id.discern_type == "Switch")
-                                        .for_each(|x| {
-                                            if let Ok(check_switch) =
-                                                downcast_resource_mut::<Switch>(&mut *x.content)
-                                                && switch.radio_group == check_switch.radio_group
-                                            {
-                                                check_switch.state = 0;
-                                            };
-                                        });
-                                };
-                                if switch.radio_group.is_empty() || switch.state == 0 {
-                                    if switch.state < switch.appearance.len() / animation_count - 1
-                                    {
-                                        switch.state += 1;
-                                    } else {
-                                        switch.state = 0;
-                                    };
-                                }
-                            };
+                            activated = Some(clicked_index);
                         };
                         appearance_count = if clicked.is_some() {
                             match switch.enable_animation {
@@ -2522,6 +9204,61 @@ impl App {
                         };
                     };
 
+                    // 获得键盘焦点时，回车键或空格键与主键单击效果相同
+                    if activated.is_none()
+                        && switch.enable
+                        && !switch.disabled
+                        && !display_info.hidden
+                        && self.active_modal.is_none()
+                        && self.is_focused(id)
+                        && (ui.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::Space))
+                            || self.gamepad_activated)
+                    {
+                        activated = switch
+                            .click_method
+                            .iter()
+                            .position(|x| x.click_method == PointerButton::Primary);
+                    };
+                    if let Some(clicked_index) = activated {
+                        switch.switched = true;
+                        if switch.click_method[clicked_index].action {
+                            // 收集同一开关组内的其他开关
+                            let group_members: Vec<String> = self
+                                .rust_constructor_resource
+                                .iter()
+                                .filter(|x| &x.id.discern_type == "SwitchGroup")
+                                .filter_map(|x| downcast_resource::<SwitchGroup>(&*x.content).ok())
+                                .filter(|x| x.switches.contains(&id.name))
+                                .flat_map(|x| x.switches.clone())
+                                .collect();
+                            let grouped =
+                                !switch.radio_group.is_empty() || !group_members.is_empty();
+                            if grouped {
+                                self.rust_constructor_resource
+                                    .iter_mut()
+                                    .filter(|x| &x.id.discern_type == "Switch")
+                                    .for_each(|x| {
+                                        let in_group = group_members.contains(&x.id.name);
+                                        if let Ok(check_switch) =
+                                            downcast_resource_mut::<Switch>(&mut *x.content)
+                                            && (!switch.radio_group.is_empty()
+                                                && switch.radio_group == check_switch.radio_group
+                                                || in_group)
+                                        {
+                                            check_switch.state = 0;
+                                        };
+                                    });
+                            };
+                            if !grouped || switch.state == 0 {
+                                if switch.state < switch.appearance.len() / animation_count - 1 {
+                                    switch.state += 1;
+                                } else {
+                                    switch.state = 0;
+                                };
+                            }
+                        };
+                    };
+
                     // 若鼠标未悬挂在开关上，逐渐隐藏提示文本
                     if !hovered {
                         if switch.last_frame_hovered {
@@ -2533,6 +9270,9 @@ impl App {
                             self.reset_split_time(&hint_fade_animation)?;
                             hint_text.alpha = hint_text.alpha.saturating_sub(10);
                         };
+                        if hint_text.alpha != 0 {
+                            self.animation_active = true;
+                        };
                     };
 
                     switch.last_frame_hovered = hovered;
@@ -2540,24 +9280,53 @@ impl App {
 
                     self.replace_resource(&id.name, switch.clone())?;
 
+                    let appearance = if switch.disabled {
+                        if let Some(ref disabled_appearance) = switch.disabled_appearance {
+                            disabled_appearance.clone()
+                        } else {
+                            // 没有专用外观时调暗当前状态的外观
+                            let dim = |alpha: Option<u8>| {
+                                Some(
+                                    (alpha.unwrap_or(255) as f32 * switch.disabled_alpha as f32
+                                        / 255_f32) as u8,
+                                )
+                            };
+                            let mut appearance =
+                                switch.appearance[switch.state * animation_count].clone();
+                            appearance.background_config = match appearance.background_config {
+                                BackgroundType::CustomRect(config) => {
+                                    let alpha = dim(config.alpha);
+                                    let border_alpha = dim(config.border_alpha);
+                                    BackgroundType::CustomRect(
+                                        config.alpha(alpha).border_alpha(border_alpha),
+                                    )
+                                }
+                                BackgroundType::Image(config) => {
+                                    let alpha = dim(config.alpha);
+                                    BackgroundType::Image(config.alpha(alpha))
+                                }
+                            };
+                            let alpha = dim(appearance.text_config.alpha);
+                            appearance.text_config = appearance.text_config.alpha(alpha);
+                            appearance
+                        }
+                    } else {
+                        switch.appearance[switch.state * animation_count + appearance_count].clone()
+                    };
+
                     self.use_resource(
                         &build_id(background_name, "Background"),
                         Some(Box::new(
                             BackgroundConfig::default()
                                 .tags(Some(switch.tags.clone()))
-                                .background_type(Some(
-                                    switch.appearance
-                                        [switch.state * animation_count + appearance_count]
-                                        .background_config
-                                        .clone(),
-                                )),
+                                .background_type(Some(appearance.background_config.clone())),
                         )),
                         ui,
                     )?;
                     self.use_resource(
                         &build_id(text_name.clone(), "Text"),
                         Some(Box::new(
-                            switch.appearance[switch.state * animation_count + appearance_count]
+                            appearance
                                 .text_config
                                 .clone()
                                 .tags(Some(switch.tags.clone())),
@@ -2578,7 +9347,7 @@ impl App {
                     self.use_resource(
                         &build_id(&hint_name, "Text"),
                         Some(Box::new(
-                            switch.appearance[switch.state * animation_count + appearance_count]
+                            appearance
                                 .hint_text_config
                                 .clone()
                                 .alpha(Some(alpha))
@@ -2596,6 +9365,329 @@ impl App {
                         ui,
                     )?;
                 }
+                "Line" => {
+                    let line = self.get_resource::<Line>(id)?;
+                    if !line.hidden {
+                        let previous_clip = ui.clip_rect();
+                        if let Some(clip) = self.clip_stack.last() {
+                            ui.set_clip_rect(previous_clip.intersect(*clip));
+                        };
+                        Self::paint_connector(
+                            self.line_endpoint_rect(&line.start)?,
+                            self.line_endpoint_rect(&line.end)?,
+                            line.style,
+                            ui,
+                        );
+                        ui.set_clip_rect(previous_clip);
+                    };
+                }
+                "TextInput" => {
+                    let mut text_input = self.get_resource::<TextInput>(id)?.clone();
+                    let text_id = build_id(format!("{}Text", &id.name), "Text");
+                    text_input.changed = false;
+                    // 点击文本获得焦点，点击其他位置失去焦点
+                    if let (true, Some(mouse_pos)) = self.resource_input(ui, &text_id, |i| {
+                        (i.pointer.primary_pressed(), i.pointer.interact_pos())
+                    }) {
+                        text_input.focused = text_input.enable
+                            && self
+                                .get_render_layer_resource(&text_id)
+                                .is_some_and(|index| {
+                                    self.resource_get_focus(index, mouse_pos.into(), true, vec![])
+                                });
+                        if text_input.focused {
+                            text_input.caret = text_input.content.chars().count();
+                        };
+                    };
+                    if text_input.focused && text_input.enable {
+                        let mut chars: Vec<char> = text_input.content.chars().collect();
+                        let mut caret = text_input.caret.min(chars.len());
+                        for event in self.resource_input(ui, &text_id, |i| i.events.clone()) {
+                            match event {
+                                Event::Text(insert_text) | Event::Paste(insert_text) => {
+                                    for c in insert_text.chars().filter(|c| !c.is_control()) {
+                                        if text_input.max_length.is_some_and(|x| chars.len() >= x) {
+                                            break;
+                                        };
+                                        chars.insert(caret, c);
+                                        caret += 1;
+                                        text_input.changed = true;
+                                    }
+                                }
+                                Event::Key {
+                                    key, pressed: true, ..
+                                } => match key {
+                                    Key::Backspace if caret > 0 => {
+                                        caret -= 1;
+                                        chars.remove(caret);
+                                        text_input.changed = true;
+                                    }
+                                    Key::Delete if caret < chars.len() => {
+                                        chars.remove(caret);
+                                        text_input.changed = true;
+                                    }
+                                    Key::ArrowLeft => caret = caret.saturating_sub(1),
+                                    Key::ArrowRight => caret = (caret + 1).min(chars.len()),
+                                    Key::Home => caret = 0,
+                                    Key::End => caret = chars.len(),
+                                    Key::Escape => text_input.focused = false,
+                                    _ => {}
+                                },
+                                _ => {}
+                            };
+                        }
+                        text_input.content = chars.into_iter().collect();
+                        text_input.caret = caret;
+                    };
+                    let display_content = if text_input.content.is_empty() && !text_input.focused {
+                        text_input.placeholder.clone()
+                    } else if text_input.password {
+                        "•".repeat(text_input.content.chars().count())
+                    } else {
+                        text_input.content.clone()
+                    };
+                    self.use_resource(
+                        &text_id,
+                        Some(Box::new(
+                            text_input
+                                .text_config
+                                .clone()
+                                .content(Some(display_content))
+                                .selectable(Some(false))
+                                .auto_fit(Some([false, false]))
+                                .tags(Some(text_input.tags.clone())),
+                        )),
+                        ui,
+                    )?;
+                    let now = ui.input(|i| i.time);
+                    let text = self.get_resource_mut::<Text>(&text_id)?;
+                    let caret = if text_input.focused {
+                        Some(text_input.caret)
+                    } else {
+                        None
+                    };
+                    // 编辑或移动光标后光标立即显示，并重新开始闪烁周期
+                    if caret != text.caret || text_input.changed {
+                        text.caret_blink_start = now;
+                    };
+                    text.caret = caret;
+                    text.caret_blink_period = text_input.caret_blink_period;
+                    if let Some(index) = self.get_render_layer_resource(&text_id)
+                        && let Some(mouse_pos) =
+                            self.resource_input(ui, &text_id, |i| i.pointer.hover_pos())
+                        && text_input.enable
+                        && self.resource_get_focus(index, mouse_pos.into(), true, vec![])
+                    {
+                        ui.set_cursor_icon(CursorIcon::Text);
+                    };
+                    self.replace_resource(&id.name, text_input)?;
+                }
+                "ProgressBar" => {
+                    let progress_bar = self.get_resource::<ProgressBar>(id)?.clone();
+                    let track_id = build_id(format!("{}Track", &id.name), "CustomRect");
+                    let fill_id = build_id(format!("{}Fill", &id.name), "CustomRect");
+                    let text_id = build_id(format!("{}Text", &id.name), "Text");
+                    self.use_resource(
+                        &track_id,
+                        Some(Box::new(progress_bar.track_config.clone())),
+                        ui,
+                    )?;
+                    // 按轨道的实际位置和尺寸计算填充部分
+                    let [position, size] = self.layout_rect(&track_id, ui)?;
+                    let [start, end] = if progress_bar.indeterminate {
+                        // 不确定模式下高亮段沿轨道循环移动
+                        self.animation_active = true;
+                        ui.ctx().request_repaint();
+                        let phase = (self.timer.total_time % 1500) as f32 / 1500_f32;
+                        let start = phase * 1.3 - 0.3;
+                        [start.max(0_f32), (start + 0.3).min(1_f32)]
+                    } else {
+                        [0_f32, progress_bar.value.clamp(0_f32, 1_f32)]
+                    };
+                    let [fill_position, fill_size] = match progress_bar.orientation {
+                        Orientation::Horizontal => [
+                            [position[0] + size[0] * start, position[1]],
+                            [size[0] * (end - start), size[1]],
+                        ],
+                        Orientation::Vertical => [
+                            [position[0], position[1] + size[1] * (1_f32 - end)],
+                            [size[0], size[1] * (end - start)],
+                        ],
+                    };
+                    self.use_resource(
+                        &fill_id,
+                        Some(Box::new(
+                            progress_bar
+                                .track_config
+                                .clone()
+                                .position_size_config(Some(
+                                    PositionSizeConfig::default()
+                                        .origin_position(fill_position[0], fill_position[1])
+                                        .origin_size(fill_size[0], fill_size[1]),
+                                ))
+                                .color(Some(progress_bar.fill_color))
+                                .alpha(Some(progress_bar.fill_alpha))
+                                .fill(Some(None))
+                                .overlay_alpha(Some(None))
+                                .hover_effect(Some(None))
+                                .shadow(Some(None))
+                                .border_width(Some(0_f32)),
+                        )),
+                        ui,
+                    )?;
+                    if let Some(ref text_config) = progress_bar.text_config {
+                        let content = if progress_bar.indeterminate {
+                            String::new()
+                        } else {
+                            format!("{}%", (progress_bar.value * 100_f32).round())
+                        };
+                        self.use_resource(
+                            &text_id,
+                            Some(Box::new(
+                                text_config
+                                    .clone()
+                                    .content(Some(content))
+                                    .position_size_config(Some(
+                                        PositionSizeConfig::default()
+                                            .origin_position(
+                                                position[0] + size[0] / 2_f32,
+                                                position[1] + size[1] / 2_f32,
+                                            )
+                                            .origin_size(size[0], size[1])
+                                            .display_method(
+                                                HorizontalAlign::Center,
+                                                VerticalAlign::Center,
+                                            ),
+                                    )),
+                            )),
+                            ui,
+                        )?;
+                    };
+                }
+                "Slider" => {
+                    let mut slider = self.get_resource::<Slider>(id)?.clone();
+                    let track_id = build_id(format!("{}Track", &id.name), "CustomRect");
+                    let fill_id = build_id(format!("{}Fill", &id.name), "CustomRect");
+                    let handle_id = build_id(format!("{}Handle", &id.name), "CustomRect");
+                    slider.changed = false;
+                    // 不在拖动时从绑定的变量读取值，以反映外部修改
+                    if let Some(ref variable) = slider.variable
+                        && !slider.dragging
+                        && let Ok(Some(value)) = self.get_variable::<f32>(variable)
+                    {
+                        slider.value = slider.snap(value);
+                    };
+                    self.use_resource(&track_id, Some(Box::new(slider.track_config.clone())), ui)?;
+                    let [position, size] = self.layout_rect(&track_id, ui)?;
+                    let (pointer, primary_pressed, primary_down) =
+                        self.resource_input(ui, &track_id, |i| {
+                            (
+                                i.pointer.interact_pos(),
+                                i.pointer.primary_pressed(),
+                                i.pointer.primary_down(),
+                            )
+                        });
+                    let pointer_on = |app: &App, target: &RustConstructorId| {
+                        pointer.is_some_and(|mouse_pos| {
+                            app.get_render_layer_resource(target).is_some_and(|index| {
+                                app.resource_get_focus(index, mouse_pos.into(), true, vec![])
+                            })
+                        })
+                    };
+                    if !slider.enable {
+                        slider.dragging = false;
+                    } else {
+                        // 按下轨道或手柄时开始拖动，按住时持续，松开时停止
+                        if primary_pressed
+                            && (pointer_on(self, &track_id) || pointer_on(self, &handle_id))
+                        {
+                            slider.dragging = true;
+                        };
+                        if slider.dragging {
+                            if primary_down && let Some(mouse_pos) = pointer {
+                                let ratio = match slider.orientation {
+                                    Orientation::Horizontal => {
+                                        (mouse_pos.x - position[0]) / size[0].max(1_f32)
+                                    }
+                                    Orientation::Vertical => {
+                                        1_f32 - (mouse_pos.y - position[1]) / size[1].max(1_f32)
+                                    }
+                                }
+                                .clamp(0_f32, 1_f32);
+                                let value = slider.snap(
+                                    slider.range[0] + ratio * (slider.range[1] - slider.range[0]),
+                                );
+                                if value != slider.value {
+                                    slider.value = value;
+                                    slider.changed = true;
+                                };
+                            } else {
+                                slider.dragging = false;
+                            };
+                        };
+                        if slider.dragging {
+                            ui.set_cursor_icon(CursorIcon::Grabbing);
+                        } else if pointer_on(self, &handle_id) {
+                            ui.set_cursor_icon(CursorIcon::Grab);
+                        };
+                    };
+                    if slider.changed
+                        && let Some(ref variable) = slider.variable
+                    {
+                        self.modify_variable(variable, Some(slider.value))?;
+                    };
+                    let ratio = slider.ratio();
+                    let (fill_rect, handle_center) = match slider.orientation {
+                        Orientation::Horizontal => (
+                            [position, [size[0] * ratio, size[1]]],
+                            [position[0] + size[0] * ratio, position[1] + size[1] / 2_f32],
+                        ),
+                        Orientation::Vertical => (
+                            [
+                                [position[0], position[1] + size[1] * (1_f32 - ratio)],
+                                [size[0], size[1] * ratio],
+                            ],
+                            [
+                                position[0] + size[0] / 2_f32,
+                                position[1] + size[1] * (1_f32 - ratio),
+                            ],
+                        ),
+                    };
+                    self.use_resource(
+                        &fill_id,
+                        Some(Box::new(
+                            slider
+                                .track_config
+                                .clone()
+                                .position_size_config(Some(
+                                    PositionSizeConfig::default()
+                                        .origin_position(fill_rect[0][0], fill_rect[0][1])
+                                        .origin_size(fill_rect[1][0], fill_rect[1][1]),
+                                ))
+                                .color(Some(slider.fill_color))
+                                .alpha(Some(slider.fill_alpha))
+                                .fill(Some(None))
+                                .overlay_alpha(Some(None))
+                                .hover_effect(Some(None))
+                                .shadow(Some(None))
+                                .border_width(Some(0_f32)),
+                        )),
+                        ui,
+                    )?;
+                    self.use_resource(
+                        &handle_id,
+                        Some(Box::new(
+                            slider.handle_config.clone().position_size_config(Some(
+                                PositionSizeConfig::default()
+                                    .origin_position(handle_center[0], handle_center[1])
+                                    .origin_size(slider.handle_size[0], slider.handle_size[1])
+                                    .display_method(HorizontalAlign::Center, VerticalAlign::Center),
+                            )),
+                        )),
+                        ui,
+                    )?;
+                    self.replace_resource(&id.name, slider)?;
+                }
                 "ResourcePanel" => {
                     let mut resource_panel = self
                         .get_resource::<ResourcePanel>(&build_id(&id.name, "ResourcePanel"))?
@@ -2650,7 +9742,18 @@ impl App {
                         resource_panel.min_size[1] = 10_f32;
                     };
                     [position, size] = position_size_processor(position_size_config, ui);
-                    let scroll_delta: [f32; 2] = ui.input(|i| i.smooth_scroll_delta).into();
+                    let background_id = build_id(
+                        background_name.clone(),
+                        background_type_discern(&background.background_type),
+                    );
+                    let (scroll_delta, hover_pos, primary_pressed) =
+                        self.resource_input(ui, &background_id, |i| {
+                            (
+                                <[f32; 2]>::from(i.smooth_scroll_delta),
+                                i.pointer.hover_pos(),
+                                i.pointer.primary_pressed(),
+                            )
+                        });
                     let [x_scroll_delta, y_scroll_delta] =
                         if scroll_delta[0].abs() >= scroll_delta[1].abs() {
                             [
@@ -2672,12 +9775,9 @@ impl App {
                             ]
                         };
                     let mut resource_get_focus = [false, false];
-                    if let Some(mouse_pos) = ui.input(|i| i.pointer.hover_pos())
+                    if let Some(mouse_pos) = hover_pos
                         && !resource_panel.hidden
-                        && let Some(index) = self.get_render_layer_resource(&build_id(
-                            background_name.clone(),
-                            background_type_discern(&background.background_type).to_string(),
-                        ))
+                        && let Some(index) = self.get_render_layer_resource(&background_id)
                     {
                         resource_get_focus = [
                             self.resource_get_focus(index, mouse_pos.into(), false, vec![]),
@@ -2729,9 +9829,7 @@ impl App {
                                     0_f32
                                 };
                             };
-                            if resource_panel.raise_on_focus
-                                && ui.input(|i| i.pointer.primary_pressed())
-                            {
+                            if resource_panel.raise_on_focus && primary_pressed {
                                 self.request_jump_render_list(
                                     RequestMethod::Id(build_id(
                                         background_name.clone(),
@@ -3667,6 +10765,21 @@ impl App {
                                     position_size_config.origin_position[1] =
                                         mouse_pos[1] - offset[1];
                                 };
+                                // 防止面板被拖出窗口
+                                if self.auto_constrain_draggables {
+                                    let [position, size] =
+                                        position_size_processor(position_size_config, ui);
+                                    let constrained = self.constrain_position(
+                                        position,
+                                        size,
+                                        self.constrain_margin,
+                                        ui,
+                                    );
+                                    position_size_config.origin_position[0] +=
+                                        constrained[0] - position[0];
+                                    position_size_config.origin_position[1] +=
+                                        constrained[1] - position[1];
+                                };
                             }
                         };
                     };
@@ -3706,6 +10819,21 @@ impl App {
                     )?;
                     type PointList = Vec<([f32; 2], [f32; 2], [bool; 2], Option<String>)>;
                     let mut resource_point_list: PointList = Vec::new();
+                    // 垂直列表中的资源按上一帧的高度依次向下排列
+                    let mut vertical_list_layout = Vec::new();
+                    if let Some(ref vertical_list) = resource_panel.vertical_list {
+                        let mut list_y = 0_f32;
+                        for item in &vertical_list.items {
+                            if self.basic_front_resource_list.contains(&item.discern_type)
+                                && let Ok(basic_front_resource) =
+                                    self.get_basic_front_resource(item)
+                            {
+                                vertical_list_layout.push((item.clone(), list_y));
+                                list_y +=
+                                    basic_front_resource.display_size()[1] + vertical_list.spacing;
+                            };
+                        }
+                    };
                     let mut use_resource_list = Vec::new();
                     let mut replace_resource_list = Vec::new();
                     for rcr in &self.rust_constructor_resource {
@@ -3744,6 +10872,9 @@ impl App {
                                     "CustomRect" => Box::new(
                                         downcast_resource::<CustomRect>(&*rcr.content)?.clone(),
                                     ),
+                                    "Polygon" => Box::new(
+                                        downcast_resource::<Polygon>(&*rcr.content)?.clone(),
+                                    ),
                                     _ => {
                                         unreachable!()
                                     }
@@ -3804,6 +10935,14 @@ impl App {
                                     }
                                 };
                             }
+                            if let Some((_, list_y)) =
+                                vertical_list_layout.iter().find(|x| x.0 == rcr.id)
+                            {
+                                layout = PanelLayout {
+                                    panel_margin: PanelMargin::None([0_f32; 4], true),
+                                    panel_location: PanelLocation::Absolute([0_f32, *list_y]),
+                                };
+                            };
                             let panel_layout_group = if let Some(panel_layout_group) =
                                 get_tag("panel_layout_group", &basic_front_resource.display_tags())
                             {
@@ -4194,6 +11333,7 @@ impl App {
                                 "CustomRect" => Box::new(config.custom_rect_config.clone()),
                                 "Image" => Box::new(config.image_config.clone()),
                                 "Text" => Box::new(config.text_config.clone()),
+                                "Polygon" => Box::new(PolygonConfig::default()),
                                 _ => Box::new(config.image_config.clone()),
                             }),
                             ui,
@@ -4516,6 +11656,14 @@ impl App {
                             } else {
                                 resource_panel.scroll_bar_alpha[1]
                             };
+                            // 淡出过程中的每一帧都保持较短的刷新间隔
+                            if resource_panel
+                                .scroll_bar_alpha
+                                .iter()
+                                .any(|x| (1..255).contains(x))
+                            {
+                                self.animation_active = true;
+                            };
                             let line_length = if resource_panel.scroll_length[1] == 0_f32 {
                                 (size[0] - margin[0] * 2_f32)
                                     * (size[0] / (resource_panel.scroll_length[0] + size[0]))
@@ -4634,108 +11782,856 @@ impl App {
                                         * (resource_panel.scroll_progress[1]
                                             / resource_panel.scroll_length[1])
                             };
-                            self.use_resource(
-                                &build_id(format!("{}YScroll", &id.name), "Background"),
-                                Some(Box::new(
-                                    BackgroundConfig::default().background_type(Some(match config
-                                        .clone()
-                                    {
-                                        BackgroundType::CustomRect(config) => {
-                                            BackgroundType::CustomRect(
-                                                config
-                                                    .clip_rect(Some(Some(
-                                                        PositionSizeConfig::default()
-                                                            .origin_position(
-                                                                position[0],
-                                                                position[1],
-                                                            )
-                                                            .origin_size(size[0], size[1]),
-                                                    )))
-                                                    .ignore_render_layer(Some(true))
-                                                    .hidden(Some(resource_panel.hidden))
-                                                    .position_size_config(Some(
-                                                        PositionSizeConfig::default()
-                                                            .display_method(
-                                                                HorizontalAlign::Right,
-                                                                VerticalAlign::Top,
-                                                            )
-                                                            .origin_position(
-                                                                position[0] + size[0] - margin[1],
-                                                                line_position,
-                                                            )
-                                                            .origin_size(width, line_length),
-                                                    ))
-                                                    .alpha(Some(resource_panel.scroll_bar_alpha[1]))
-                                                    .border_alpha(Some(
-                                                        resource_panel.scroll_bar_alpha[1],
-                                                    )),
-                                            )
-                                        }
-                                        BackgroundType::Image(config) => BackgroundType::Image(
-                                            config
-                                                .clip_rect(Some(Some(
-                                                    PositionSizeConfig::default()
-                                                        .origin_position(position[0], position[1])
-                                                        .origin_size(size[0], size[1]),
-                                                )))
-                                                .ignore_render_layer(Some(true))
-                                                .hidden(Some(resource_panel.hidden))
-                                                .position_size_config(Some(
-                                                    PositionSizeConfig::default()
-                                                        .display_method(
-                                                            HorizontalAlign::Right,
-                                                            VerticalAlign::Top,
-                                                        )
-                                                        .origin_position(
-                                                            position[0] + size[0] - margin[1],
-                                                            line_position,
-                                                        )
-                                                        .origin_size(width, line_length),
-                                                ))
-                                                .alpha(Some(resource_panel.scroll_bar_alpha[1]))
-                                                .background_alpha(Some(
-                                                    resource_panel.scroll_bar_alpha[1],
-                                                ))
-                                                .overlay_alpha(Some(
-                                                    resource_panel.scroll_bar_alpha[1],
-                                                )),
-                                        ),
-                                    })),
-                                )),
-                                ui,
-                            )?;
+                            self.use_resource(
+                                &build_id(format!("{}YScroll", &id.name), "Background"),
+                                Some(Box::new(
+                                    BackgroundConfig::default().background_type(Some(match config
+                                        .clone()
+                                    {
+                                        BackgroundType::CustomRect(config) => {
+                                            BackgroundType::CustomRect(
+                                                config
+                                                    .clip_rect(Some(Some(
+                                                        PositionSizeConfig::default()
+                                                            .origin_position(
+                                                                position[0],
+                                                                position[1],
+                                                            )
+                                                            .origin_size(size[0], size[1]),
+                                                    )))
+                                                    .ignore_render_layer(Some(true))
+                                                    .hidden(Some(resource_panel.hidden))
+                                                    .position_size_config(Some(
+                                                        PositionSizeConfig::default()
+                                                            .display_method(
+                                                                HorizontalAlign::Right,
+                                                                VerticalAlign::Top,
+                                                            )
+                                                            .origin_position(
+                                                                position[0] + size[0] - margin[1],
+                                                                line_position,
+                                                            )
+                                                            .origin_size(width, line_length),
+                                                    ))
+                                                    .alpha(Some(resource_panel.scroll_bar_alpha[1]))
+                                                    .border_alpha(Some(
+                                                        resource_panel.scroll_bar_alpha[1],
+                                                    )),
+                                            )
+                                        }
+                                        BackgroundType::Image(config) => BackgroundType::Image(
+                                            config
+                                                .clip_rect(Some(Some(
+                                                    PositionSizeConfig::default()
+                                                        .origin_position(position[0], position[1])
+                                                        .origin_size(size[0], size[1]),
+                                                )))
+                                                .ignore_render_layer(Some(true))
+                                                .hidden(Some(resource_panel.hidden))
+                                                .position_size_config(Some(
+                                                    PositionSizeConfig::default()
+                                                        .display_method(
+                                                            HorizontalAlign::Right,
+                                                            VerticalAlign::Top,
+                                                        )
+                                                        .origin_position(
+                                                            position[0] + size[0] - margin[1],
+                                                            line_position,
+                                                        )
+                                                        .origin_size(width, line_length),
+                                                ))
+                                                .alpha(Some(resource_panel.scroll_bar_alpha[1]))
+                                                .background_alpha(Some(
+                                                    resource_panel.scroll_bar_alpha[1],
+                                                ))
+                                                .overlay_alpha(Some(
+                                                    resource_panel.scroll_bar_alpha[1],
+                                                )),
+                                        ),
+                                    })),
+                                )),
+                                ui,
+                            )?;
+                        }
+                        ScrollBarDisplayMethod::Hidden => {}
+                    };
+                    self.replace_resource(&id.name, resource_panel.clone())?;
+                }
+                _ => {}
+            };
+            Ok(())
+        } else {
+            error!(
+                "[ResourceNotFound]use_resource: Resource '{}({})' not found.",
+                id.name, id.discern_type
+            );
+            Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
+                description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
+            })
+        }
+    }
+
+    /// Switches to a different page and resets page-specific state.
+    ///
+    /// 切换到不同页面并重置页面特定状态。
+    ///
+    /// The transition of the target page is played, or the app-wide `page_transition` when the
+    /// page has none.
+    ///
+    /// 会播放目标页面的过渡，页面没有过渡时播放全局的`page_transition`。
+    pub fn switch_page(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let page_data = self.get_resource::<PageData>(&build_id(name, "PageData"))?;
+        if let Some((transition, duration)) = page_data.transition.or(self.page_transition) {
+            self.switch_page_animated(name, transition, duration)
+        } else {
+            self.finish_page_transition();
+            self.enter_page(name)
+        }
+    }
+
+    /// Switches to a page and remembers the current one so `pop_page` can return to it.
+    ///
+    /// 切换到某个页面并记住当前页面，以便`pop_page`返回。
+    pub fn push_page(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let previous_page = self.current_page.clone();
+        self.switch_page(name)?;
+        self.page_history.push(previous_page);
+        Ok(())
+    }
+
+    /// Returns to the page left by the latest `push_page` and re-runs its enter lifecycle.
+    ///
+    /// 返回最近一次`push_page`离开的页面，并重新执行其进入流程。
+    pub fn pop_page(&mut self) -> Result<(), RustConstructorError> {
+        let Some(previous_page) = self.page_history.last().cloned() else {
+            error!("[PageStackEmpty]pop_page: There is no page to go back to.");
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::PAGE_STACK_EMPTY.to_string(),
+                description: "There is no page to go back to.".to_string(),
+            });
+        };
+        self.switch_page(&previous_page)?;
+        self.page_history.pop();
+        Ok(())
+    }
+
+    /// Returns the pages that `pop_page` would go back through, the next one last.
+    ///
+    /// 返回`pop_page`会依次返回的页面，下一个在最后。
+    pub fn page_stack(&self) -> &[String] {
+        &self.page_history
+    }
+
+    /// Switches to a different page while playing a transition.
+    ///
+    /// 切换到不同页面并播放过渡。
+    ///
+    /// The outgoing page keeps being drawn from its last render queue while it animates out, so
+    /// only the incoming page has to be used by the caller. `check_enter_updated` reports false
+    /// for the incoming page until the transition ends.
+    ///
+    /// 离开的页面会按其最后的渲染队列继续绘制并播放退出动画，因此调用方只需要使用进入的页面。
+    /// 过渡结束前，`check_enter_updated`对进入的页面返回false。
+    pub fn switch_page_animated(
+        &mut self,
+        name: &str,
+        transition: Transition,
+        duration: u128,
+    ) -> Result<(), RustConstructorError> {
+        self.get_resource::<PageData>(&build_id(name, "PageData"))?;
+        self.finish_page_transition();
+        self.active_page_transition = if name != self.current_page && duration > 0 {
+            Some(PageTransition {
+                transition,
+                from_page: self.current_page.clone(),
+                to_page: name.to_string(),
+                start_time: self.timer.total_time,
+                duration,
+                outgoing: self.render_list.clone(),
+            })
+        } else {
+            None
+        };
+        self.enter_page(name)
+    }
+
+    /// Makes a page current and resets page-specific state.
+    ///
+    /// 将页面设为当前页面并重置页面特定状态。
+    fn enter_page(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let page_data = self.get_resource_mut::<PageData>(&build_id(name, "PageData"))?;
+        page_data.enter_page_updated = false;
+        self.timer.start_time = self.timer.total_time;
+        let previous_page = std::mem::replace(&mut self.current_page, name.to_string());
+        self.focus_index = None;
+        self.update_timer();
+        // 有过渡动画时，离开页面的资源在动画结束后才清理
+        if previous_page != name && self.active_page_transition.is_none() {
+            self.clear_page_resources(&previous_page);
+        };
+        Ok(())
+    }
+
+    /// Ends the playing page transition and cleans up the page it left.
+    ///
+    /// 结束正在播放的页面过渡并清理其离开的页面。
+    fn finish_page_transition(&mut self) {
+        if let Some(page_transition) = self.active_page_transition.take()
+            && page_transition.from_page != self.current_page
+        {
+            self.clear_page_resources(&page_transition.from_page);
+        };
+    }
+
+    /// Removes the resources owned by a page and returns how many were removed.
+    ///
+    /// 移除页面拥有的资源并返回被移除的数量。
+    ///
+    /// A resource is owned by a page when it carries the tag `["page", page_name]`, the
+    /// pages left through `switch_page` are cleaned up automatically. Resources also tagged
+    /// `["persistent", ""]` are kept. Child resources of removed composite resources go with
+    /// them even without the page tag. Afterwards the page is marked as not loaded, so
+    /// `check_updated` lets it add its resources again the next time it is entered.
+    ///
+    /// 资源带有`["page", 页面名称]`标签时即被该页面拥有，通过`switch_page`离开的页面会被自动清理。
+    /// 同时带有`["persistent", ""]`标签的资源会被保留。被移除的复合资源的子资源即使没有页面标签也会一同移除。
+    /// 清理后页面会被标记为未加载，因此下次进入时`check_updated`会让它重新添加资源。
+    pub fn clear_page_resources(&mut self, page: &str) -> usize {
+        let removed_id_list = self.with_composite_children(
+            self.rust_constructor_resource
+                .iter()
+                .filter(|x| {
+                    let tags = x.content.display_tags();
+                    x.id.discern_type != "PageData"
+                        && get_tag("page", &tags).is_some_and(|x| x.1 == page)
+                        && get_tag("persistent", &tags).is_none()
+                })
+                .map(|x| x.id.clone())
+                .collect(),
+        );
+        self.rust_constructor_resource
+            .retain(|x| !removed_id_list.contains(&x.id));
+        for id in &removed_id_list {
+            self.prune_resource_references(id);
+        }
+        if !removed_id_list.is_empty()
+            && let Ok(page_data) = self.get_resource_mut::<PageData>(&build_id(page, "PageData"))
+        {
+            page_data.change_page_updated = false;
+        };
+        removed_id_list.len()
+    }
+
+    /// Stores a copy of the front resources tagged to the current page, replacing the snapshot
+    /// with the same name.
+    ///
+    /// 保存标记到当前页面的前端资源的副本，会替换同名快照。
+    ///
+    /// Like `serialize_page_layout`, a resource belongs to a page when it carries the tag
+    /// `["page", page_name]`. The copy stays in memory and keeps every field, including values
+    /// that are not serialized.
+    ///
+    /// 与`serialize_page_layout`相同，资源带有`["page", 页面名称]`标签时即属于该页面。
+    /// 副本保存在内存中并保留所有字段，包括不会被序列化的值。
+    pub fn snapshot(&mut self, name: &str) {
+        let resources = self
+            .rust_constructor_resource
+            .iter()
+            .filter(|x| {
+                get_tag("page", &x.content.display_tags()).is_some_and(|x| x.1 == self.current_page)
+            })
+            .filter_map(|x| {
+                x.content.convert_to_front().map(|front| {
+                    RustConstructorResourceBox::new(
+                        &x.id.name,
+                        &x.id.discern_type,
+                        front.convert_to_original(),
+                    )
+                })
+            })
+            .collect();
+        self.snapshots.retain(|x| x.0 != name);
+        self.snapshots.push((name.to_string(), resources));
+    }
+
+    /// Writes a snapshot back to the resources it was taken from.
+    ///
+    /// 将快照写回其来源资源。
+    ///
+    /// Resources removed since the snapshot are skipped. Interaction state such as text
+    /// selections, carets, focus and hover flags is reset so nothing stale survives the restore.
+    ///
+    /// 快照之后被移除的资源会被跳过。文本选区、光标、焦点和悬停标记等交互状态会被重置，
+    /// 以免恢复后残留过期状态。
+    pub fn restore_snapshot(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let Some(index) = self.snapshots.iter().position(|x| x.0 == name) else {
+            error!("[SnapshotNotFound]restore_snapshot: Snapshot '{name}' not found.");
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::SNAPSHOT_NOT_FOUND.to_string(),
+                description: format!("Snapshot '{name}' not found."),
+            });
+        };
+        let mut restored = Vec::new();
+        for rcr in &self.snapshots[index].1 {
+            if let Some(front) = rcr.content.convert_to_front() {
+                restored.push((rcr.id.clone(), front.convert_to_original()));
+            };
+        }
+        for (id, mut content) in restored {
+            let Some(index) = self.check_resource_exists(&id) else {
+                continue;
+            };
+            Self::reset_transient_state(&id.discern_type, &mut *content)?;
+            self.rust_constructor_resource[index].content = content;
+        }
+        Ok(())
+    }
+
+    /// Resets the interaction state of a copied resource, such as text selections, carets,
+    /// focus and hover flags.
+    ///
+    /// 重置复制的资源的交互状态，例如文本选区、光标、焦点和悬停标记。
+    fn reset_transient_state(
+        discern_type: &str,
+        content: &mut dyn RustConstructorResource,
+    ) -> Result<(), RustConstructorError> {
+        match discern_type {
+            "Text" => {
+                let text = downcast_resource_mut::<Text>(content)?;
+                text.selection = None;
+                text.caret = None;
+                text.last_frame_content.clear();
+                text.hyperlink_index.clear();
+                text.highlight_index.clear();
+                text.marquee_offset = 0_f32;
+            }
+            "Image" => {
+                downcast_resource_mut::<Image>(content)?.hover_progress = 0_f32;
+            }
+            "CustomRect" => {
+                downcast_resource_mut::<CustomRect>(content)?.hover_progress = 0_f32;
+            }
+            "Switch" => {
+                let switch = downcast_resource_mut::<Switch>(content)?;
+                switch.last_frame_hovered = false;
+                switch.last_frame_clicked = None;
+                switch.switched = false;
+            }
+            "TextInput" => {
+                let text_input = downcast_resource_mut::<TextInput>(content)?;
+                text_input.caret = text_input.content.chars().count();
+                text_input.focused = false;
+                text_input.changed = false;
+            }
+            "Slider" => {
+                let slider = downcast_resource_mut::<Slider>(content)?;
+                slider.dragging = false;
+                slider.changed = false;
+            }
+            _ => {}
+        };
+        Ok(())
+    }
+
+    /// Draws the render queue while a page transition is playing.
+    ///
+    /// 在页面过渡播放期间绘制渲染队列。
+    fn draw_page_transition(&mut self, ui: &mut Ui) -> Result<(), RustConstructorError> {
+        let Some(page_transition) = self.active_page_transition.clone() else {
+            return Ok(());
+        };
+        let elapsed = self
+            .timer
+            .total_time
+            .saturating_sub(page_transition.start_time);
+        let progress =
+            Easing::EaseInOut.apply(elapsed as f32 / page_transition.duration.max(1) as f32);
+        let width = ui.ctx().content_rect().width();
+        // 分别为离开页面和进入页面的[不透明度, 水平偏移]
+        let (outgoing_effect, incoming_effect) = match page_transition.transition {
+            Transition::Fade => (
+                [(1_f32 - 2_f32 * progress).max(0_f32), 0_f32],
+                [(2_f32 * progress - 1_f32).max(0_f32), 0_f32],
+            ),
+            Transition::Crossfade => ([1_f32 - progress, 0_f32], [progress, 0_f32]),
+            Transition::SlideLeft => (
+                [1_f32, -width * progress],
+                [1_f32, width * (1_f32 - progress)],
+            ),
+            Transition::SlideRight => (
+                [1_f32, width * progress],
+                [1_f32, -width * (1_f32 - progress)],
+            ),
+        };
+        // 两个页面共用的资源不参与过渡，只绘制一次
+        let outgoing: Vec<(RustConstructorId, Option<RustConstructorId>)> = page_transition
+            .outgoing
+            .iter()
+            .filter(|x| {
+                !self.render_list.iter().any(|y| y.0 == x.0)
+                    && self.check_resource_exists(&x.0).is_some()
+            })
+            .cloned()
+            .collect();
+        let render_list = std::mem::replace(&mut self.render_list, outgoing);
+        let mut result = Ok(());
+        for i in 0..self.render_list.len() {
+            if self.in_secondary_window(&self.render_list[i].0) {
+                continue;
+            };
+            result = self.draw_with_visual_effect(
+                ui,
+                i,
+                outgoing_effect[0],
+                Vec2::new(outgoing_effect[1], 0_f32),
+            );
+            if result.is_err() {
+                break;
+            };
+        }
+        self.render_list = render_list;
+        result?;
+        for i in 0..self.render_list.len() {
+            if self.in_secondary_window(&self.render_list[i].0) {
+                continue;
+            } else if page_transition
+                .outgoing
+                .iter()
+                .any(|x| x.0 == self.render_list[i].0)
+            {
+                self.draw_resource_by_index(ui, i)?;
+            } else {
+                self.draw_with_visual_effect(
+                    ui,
+                    i,
+                    incoming_effect[0],
+                    Vec2::new(incoming_effect[1], 0_f32),
+                )?;
+            };
+        }
+        if elapsed >= page_transition.duration {
+            self.finish_page_transition();
+        } else {
+            self.animation_active = true;
+            ui.ctx().request_repaint();
+        };
+        Ok(())
+    }
+
+    /// Draws a resource in the render queue with an opacity factor and horizontal offset applied.
+    ///
+    /// 绘制渲染队列中的资源，并应用不透明度系数和水平偏移。
+    fn draw_with_visual_effect(
+        &mut self,
+        ui: &mut Ui,
+        index: usize,
+        opacity: f32,
+        offset: Vec2,
+    ) -> Result<(), RustConstructorError> {
+        if opacity <= 0_f32 {
+            return Ok(());
+        };
+        ui.with_visual_transform(TSTransform::from_translation(offset), |ui| {
+            ui.multiply_opacity(opacity);
+            self.draw_resource_by_index(ui, index)
+        })
+        .inner
+    }
+
+    /// Removes a snapshot.
+    ///
+    /// 移除快照。
+    pub fn remove_snapshot(&mut self, name: &str) {
+        self.snapshots.retain(|x| x.0 != name);
+    }
+
+    /// Serializes the Image, Text and CustomRect resources tagged to a page into JSON.
+    ///
+    /// 将所有标记到某个页面的Image、Text和CustomRect资源序列化为JSON。
+    ///
+    /// A resource belongs to a page when it carries the tag `["page", page_name]`. Entries use
+    /// the same form as `resources_to_json`, so the result can be loaded back with
+    /// `resources_from_json`. Only available with the `serde` feature.
+    ///
+    /// 资源带有`["page", 页面名称]`标签时即属于该页面。条目的格式与`resources_to_json`相同，
+    /// 因此结果可以通过`resources_from_json`重新加载。仅在启用`serde`特性时可用。
+    #[cfg(feature = "serde")]
+    pub fn serialize_page_layout(&self, page: &str) -> Result<String, RustConstructorError> {
+        self.get_resource::<PageData>(&build_id(page, "PageData"))?;
+        let mut resources = Vec::new();
+        for rcr in &self.rust_constructor_resource {
+            if ["Image", "Text", "CustomRect"].contains(&&*rcr.id.discern_type)
+                && get_tag("page", &rcr.content.display_tags()).is_some_and(|x| x.1 == page)
+                && let Some(item) = Self::resource_to_json(rcr)?
+            {
+                resources.push(item);
+            };
+        }
+        Ok(json!({ "page": page, "resources": resources }).to_string())
+    }
+
+    /// Serializes all Image, Text, CustomRect, Switch and Variable resources into JSON.
+    ///
+    /// 将所有Image、Text、CustomRect、Switch和Variable资源序列化为JSON。
+    ///
+    /// Resources are written with serde, fields that only hold runtime state (textures,
+    /// galleys, selection, hover progress and the like) are skipped. Images using
+    /// `ImageLoadMethod::ByTexture` only record the variant name. Variables are exported when
+    /// they hold a bool, i32, i64, u32, u64, usize, f32, f64 or String. Non-finite numbers are
+    /// written as `null`.
+    ///
+    /// 资源通过serde写入，仅保存运行时状态的字段（纹理、排版结果、选区、悬停进度等）会被跳过。
+    /// 使用`ImageLoadMethod::ByTexture`的图像只记录变体名称。变量仅在存储bool、i32、i64、u32、
+    /// u64、usize、f32、f64或String时导出。非有限数值会被写为`null`。
+    #[cfg(feature = "serde")]
+    pub fn resources_to_json(&self) -> Result<String, RustConstructorError> {
+        let mut resources = Vec::new();
+        for rcr in &self.rust_constructor_resource {
+            if let Some(item) = Self::resource_to_json(rcr)? {
+                resources.push(item);
+            };
+        }
+        Ok(json!({ "resources": resources }).to_string())
+    }
+
+    #[cfg(feature = "serde")]
+    fn resource_to_json(
+        rcr: &RustConstructorResourceBox,
+    ) -> Result<Option<Value>, RustConstructorError> {
+        let value = match &*rcr.id.discern_type {
+            "Image" => serde_json::to_value(downcast_resource::<Image>(&*rcr.content)?),
+            "Text" => serde_json::to_value(downcast_resource::<Text>(&*rcr.content)?),
+            "CustomRect" => serde_json::to_value(downcast_resource::<CustomRect>(&*rcr.content)?),
+            "Switch" => serde_json::to_value(downcast_resource::<Switch>(&*rcr.content)?),
+            "Variable" => {
+                let any = rcr.content.as_any();
+                let mut value = None;
+                macro_rules! read_variable {
+                    ($($ty:ty => $value_type:literal),*) => {
+                        $(
+                            if let Some(variable) = any.downcast_ref::<Variable<$ty>>() {
+                                value = Some(($value_type, json!(variable.value)));
+                            };
+                        )*
+                    };
+                }
+                read_variable!(
+                    bool => "bool", i32 => "i32", i64 => "i64", u32 => "u32", u64 => "u64",
+                    usize => "usize", f32 => "f32", f64 => "f64", String => "String"
+                );
+                return Ok(value.map(|(value_type, value)| {
+                    json!({
+                        "name": rcr.id.name,
+                        "type": rcr.id.discern_type,
+                        "tags": rcr.content.display_tags(),
+                        "value_type": value_type,
+                        "value": value,
+                    })
+                }));
+            }
+            _ => return Ok(None),
+        };
+        match value {
+            Ok(mut value) => {
+                Self::extend_json_object(
+                    &mut value,
+                    json!({ "name": rcr.id.name, "type": rcr.id.discern_type }),
+                );
+                Ok(Some(value))
+            }
+            Err(e) => {
+                error!(
+                    "[ResourceSaveFailed]resource_to_json: Failed to serialize '{}({})': {e}.",
+                    rcr.id.name, rcr.id.discern_type
+                );
+                Err(RustConstructorError {
+                    error_id: RustConstructorError::RESOURCE_SAVE_FAILED.to_string(),
+                    description: format!(
+                        "Failed to serialize '{}({})': {e}.",
+                        rcr.id.name, rcr.id.discern_type
+                    ),
+                })
+            }
+        }
+    }
+
+    /// Saves all serializable resources to a JSON file, see `resources_to_json`.
+    ///
+    /// 将所有可序列化的资源保存到JSON文件，参见`resources_to_json`。
+    #[cfg(feature = "serde")]
+    pub fn save_resources(&self, path: &str) -> Result<(), RustConstructorError> {
+        let json = self.resources_to_json()?;
+        if let Err(e) = std::fs::write(path, json) {
+            error!("[ResourceSaveFailed]save_resources: Failed to write '{path}': {e}.");
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_SAVE_FAILED.to_string(),
+                description: format!("Failed to write '{path}': {e}."),
+            });
+        };
+        Ok(())
+    }
+
+    /// Restores resources from JSON written by `resources_to_json` or `serialize_page_layout`.
+    ///
+    /// 从`resources_to_json`或`serialize_page_layout`写入的JSON还原资源。
+    ///
+    /// Missing resources are created, missing Switches are rebuilt from their stored config
+    /// together with their child resources. Existing resources are replaced, fields that are
+    /// missing from an entry keep their current value. Entries that fail to parse, have an
+    /// unknown value type or name a Variable stored with a different type are logged and
+    /// skipped, the other entries are still restored. Runtime-only state is reset rather than
+    /// restored: galleys, selection, caret and hover progress start over, and images whose
+    /// load method changed reload their texture. Images stored with
+    /// `ImageLoadMethod::ByTexture` keep their current load method and texture.
+    ///
+    /// 缺失的资源会被创建，缺失的Switch会根据存储的配置连同其子资源一起重建。已存在的资源会被替换，
+    /// 条目中缺失的字段保持当前值。无法解析、值类型未知或与已存储变量类型不符的条目会被记录并跳过，
+    /// 其余条目仍会被还原。运行时状态会被重置而不是还原：排版结果、选区、光标和悬停进度从头开始，
+    /// 加载方式改变的图像会重新加载纹理。以`ImageLoadMethod::ByTexture`存储的图像保留当前的加载方式和纹理。
+    #[cfg(feature = "serde")]
+    pub fn resources_from_json(&mut self, json: &str) -> Result<(), RustConstructorError> {
+        let json = match serde_json::from_str::<Value>(json) {
+            Ok(json) => json,
+            Err(e) => {
+                error!("[JsonParseFailed]resources_from_json: {e}.");
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::JSON_PARSE_FAILED.to_string(),
+                    description: format!("{e}."),
+                });
+            }
+        };
+        let Some(resources) = json.get("resources").and_then(|x| x.as_array()) else {
+            error!("[ResourceLoadFailed]resources_from_json: Missing resource list.");
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_LOAD_FAILED.to_string(),
+                description: "Missing resource list.".to_string(),
+            });
+        };
+        // 资源名称已包含作用域前缀，还原时不再叠加当前作用域
+        let previous_scope = self.page_scope.take();
+        // 先还原开关，由开关创建的子资源随后再被其各自的条目覆盖
+        let (switches, others): (Vec<&Value>, Vec<&Value>) = resources
+            .iter()
+            .partition(|x| x.get("type").and_then(|x| x.as_str()) == Some("Switch"));
+        for item in switches.into_iter().chain(others) {
+            // 无法还原的条目已记录错误，跳过它继续还原其余条目
+            let _ = self.restore_resource(item);
+        }
+        self.page_scope = previous_scope;
+        Ok(())
+    }
+
+    /// Restores resources from a JSON file written by `save_resources`.
+    ///
+    /// 从`save_resources`写入的JSON文件还原资源。
+    #[cfg(feature = "serde")]
+    pub fn load_resources(&mut self, path: &str) -> Result<(), RustConstructorError> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => self.resources_from_json(&json),
+            Err(e) => {
+                error!("[ResourceLoadFailed]load_resources: Failed to read '{path}': {e}.");
+                Err(RustConstructorError {
+                    error_id: RustConstructorError::RESOURCE_LOAD_FAILED.to_string(),
+                    description: format!("Failed to read '{path}': {e}."),
+                })
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn extend_json_object(target: &mut Value, fields: Value) {
+        if let (Value::Object(target), Value::Object(fields)) = (target, fields) {
+            target.extend(fields);
+        };
+    }
+
+    #[cfg(feature = "serde")]
+    fn read_json_field<T: DeserializeOwned>(item: &Value, key: &str) -> Option<T> {
+        T::deserialize(item.get(key)?).ok()
+    }
+
+    #[cfg(feature = "serde")]
+    fn remove_texture_load_methods(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                if map.get("image_load_method").and_then(|x| x.as_str()) == Some("ByTexture") {
+                    map.remove("image_load_method");
+                };
+                map.values_mut().for_each(Self::remove_texture_load_methods);
+            }
+            Value::Array(list) => list.iter_mut().for_each(Self::remove_texture_load_methods),
+            _ => {}
+        };
+    }
+
+    #[cfg(feature = "serde")]
+    fn restore_resource(&mut self, item: &Value) -> Result<(), RustConstructorError> {
+        let (Some(name), Some(discern_type)) = (
+            Self::read_json_field::<String>(item, "name"),
+            Self::read_json_field::<String>(item, "type"),
+        ) else {
+            error!("[ResourceLoadFailed]restore_resource: Resource entry without name or type.");
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_LOAD_FAILED.to_string(),
+                description: "Resource entry without name or type.".to_string(),
+            });
+        };
+        let id = build_id(&name, &discern_type);
+        match &*discern_type {
+            "Image" => {
+                let mut image = self.deserialize_resource::<Image>(&id, item)?;
+                if let Some(index) = self.check_resource_exists(&id) {
+                    let current = downcast_resource::<Image>(
+                        &*self.rust_constructor_resource[index].content,
+                    )?;
+                    // 纹理无法还原，加载方式未变或以纹理存储时沿用当前纹理，否则下一帧重新加载
+                    if item
+                        .get("image_load_method")
+                        .is_none_or(|x| x.as_str() == Some("ByTexture"))
+                        || image.image_load_method == current.image_load_method
+                    {
+                        image.image_load_method = current.image_load_method.clone();
+                        image.texture = current.texture.clone();
+                        image.texture_list = current.texture_list.clone();
+                        image.gif = current.gif.clone();
+                        image.last_frame_path = current.last_frame_path.clone();
+                    };
+                };
+                self.insert_restored_resource(&id, image)
+            }
+            "Text" => {
+                let text = self.deserialize_resource::<Text>(&id, item)?;
+                self.insert_restored_resource(&id, text)
+            }
+            "CustomRect" => {
+                let custom_rect = self.deserialize_resource::<CustomRect>(&id, item)?;
+                self.insert_restored_resource(&id, custom_rect)
+            }
+            "Switch" => {
+                let mut switch = self.deserialize_resource::<Switch>(&id, item)?;
+                switch.state = switch
+                    .state
+                    .min(switch.state_amount.saturating_sub(1) as usize);
+                self.insert_restored_resource(&id, switch)
+            }
+            "Variable" => {
+                let value_type = Self::read_json_field::<String>(item, "value_type");
+                macro_rules! restore_variable {
+                    ($($ty:ty => $value_type:literal),*) => {
+                        match value_type.as_deref() {
+                            $(
+                                Some($value_type) => self.restore_variable::<$ty>(&name, item),
+                            )*
+                            _ => {
+                                error!(
+                                    "[ResourceLoadFailed]restore_resource: Variable '{name}' has an unknown value type."
+                                );
+                                Err(RustConstructorError {
+                                    error_id: RustConstructorError::RESOURCE_LOAD_FAILED
+                                        .to_string(),
+                                    description: format!(
+                                        "Variable '{name}' has an unknown value type."
+                                    ),
+                                })
+                            }
                         }
-                        ScrollBarDisplayMethod::Hidden => {}
                     };
-                    self.replace_resource(&id.name, resource_panel.clone())?;
                 }
-                _ => {}
-            };
-            Ok(())
-        } else {
+                restore_variable!(
+                    bool => "bool", i32 => "i32", i64 => "i64", u32 => "u32", u64 => "u64",
+                    usize => "usize", f32 => "f32", f64 => "f64", String => "String"
+                )
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn deserialize_resource<T: RustConstructorResource + Serialize + DeserializeOwned + 'static>(
+        &self,
+        id: &RustConstructorId,
+        item: &Value,
+    ) -> Result<T, RustConstructorError> {
+        // 以当前资源为底，条目中缺失的字段保持当前值
+        let mut value = match self.check_resource_exists(id) {
+            Some(index) => serde_json::to_value(downcast_resource::<T>(
+                &*self.rust_constructor_resource[index].content,
+            )?)
+            .unwrap_or_else(|_| json!({})),
+            None => json!({}),
+        };
+        Self::extend_json_object(&mut value, item.clone());
+        Self::remove_texture_load_methods(&mut value);
+        T::deserialize(value).map_err(|e| {
             error!(
-                "[ResourceNotFound]use_resource: Resource '{}({})' not found.",
+                "[ResourceLoadFailed]restore_resource: Failed to restore '{}({})': {e}.",
                 id.name, id.discern_type
             );
-            Err(RustConstructorError {
-                error_id: "ResourceNotFound".to_string(),
-                description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
-            })
+            RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_LOAD_FAILED.to_string(),
+                description: format!("Failed to restore '{}({})': {e}.", id.name, id.discern_type),
+            }
+        })
+    }
+
+    #[cfg(feature = "serde")]
+    fn insert_restored_resource<T: RustConstructorResource + 'static>(
+        &mut self,
+        id: &RustConstructorId,
+        resource: T,
+    ) -> Result<(), RustConstructorError> {
+        if let Some(index) = self.check_resource_exists(id) {
+            self.rust_constructor_resource[index] =
+                RustConstructorResourceBox::new(&id.name, &id.discern_type, Box::new(resource));
+            Ok(())
+        } else {
+            self.add_resource(&id.name, resource)
         }
     }
 
-    /// Switches to a different page and resets page-specific state.
-    ///
-    /// 切换到不同页面并重置页面特定状态。
-    pub fn switch_page(&mut self, name: &str) -> Result<(), RustConstructorError> {
-        let page_data = self.get_resource_mut::<PageData>(&build_id(name, "PageData"))?;
-        page_data.enter_page_updated = false;
-        self.timer.start_time = self.timer.total_time;
-        self.current_page = name.to_string();
-        self.update_timer();
+    #[cfg(feature = "serde")]
+    fn restore_variable<T: DeserializeOwned + Debug + Send + Sync + 'static>(
+        &mut self,
+        name: &str,
+        item: &Value,
+    ) -> Result<(), RustConstructorError> {
+        let Some(value) = Self::read_json_field::<Option<T>>(item, "value") else {
+            error!("[ResourceLoadFailed]restore_variable: Variable '{name}' has no valid value.");
+            return Err(RustConstructorError {
+                error_id: RustConstructorError::RESOURCE_LOAD_FAILED.to_string(),
+                description: format!("Variable '{name}' has no valid value."),
+            });
+        };
+        let id = build_id(name, "Variable");
+        if self.check_resource_exists(&id).is_some() {
+            // 已存储的变量类型不同时返回错误且不做任何修改
+            self.modify_variable(name, value)?;
+        } else {
+            self.add_resource(name, Variable::default().value(value))?;
+        };
+        if let Some(tags) = Self::read_json_field::<Vec<[String; 2]>>(item, "tags") {
+            self.get_box_resource_mut(&id)?.modify_tags(&tags, true);
+        };
         Ok(())
     }
 
+    /// Sets the order in which fonts are tried when a glyph is missing, it takes effect on the
+    /// next `register_all_fonts` call.
+    ///
+    /// 设置缺失字形时尝试字体的顺序，在下一次调用`register_all_fonts`时生效。
+    ///
+    /// Registered fonts that are not listed are tried after the listed ones in registration
+    /// order, followed by egui's built-in fonts. A text with its own `font` tries that font
+    /// first and then the same chain. An empty list keeps the default behavior where the last
+    /// registered font comes first.
+    ///
+    /// 未列出的已注册字体会按注册顺序排在列出的字体之后，最后是egui的内置字体。
+    /// 指定了`font`的文本会先尝试该字体，然后使用同一回退链。列表为空时保持默认行为，即最后注册的字体优先。
+    pub fn set_font_fallback(&mut self, fonts: Vec<String>) {
+        self.font_fallback = fonts;
+    }
+
     /// Registers all fonts.
     ///
     /// 注册所有字体。
@@ -4744,6 +12640,13 @@ impl App {
     /// text display.
     ///
     /// 此方法加载并注册所有字体到egui渲染系统中，用于文本显示。
+    ///
+    /// Bold and italic variants are registered as separate fonts named `{font}-Bold`,
+    /// `{font}-Italic` and `{font}-BoldItalic`, texts using `{font}` with `bold` or `italic`
+    /// pick them automatically.
+    ///
+    /// 粗体和斜体变体作为单独的字体注册，命名为`{font}-Bold`、`{font}-Italic`和`{font}-BoldItalic`，
+    /// 使用`{font}`并设置了`bold`或`italic`的文本会自动选用它们。
     pub fn register_all_fonts(
         &mut self,
         ui: &mut Ui,
@@ -4800,11 +12703,45 @@ impl App {
                     font_info[1]
                 );
                 return Err(RustConstructorError {
-                    error_id: "FontLoadFailed".to_string(),
+                    error_id: RustConstructorError::FONT_LOAD_FAILED.to_string(),
                     description: format!("Failed to load a font from the path '{}'.", font_info[1]),
                 });
             }
         }
+        // 按回退顺序重建字体族，使缺失的字形依次回退到后面的字体
+        if !self.font_fallback.is_empty() {
+            let default_families = FontDefinitions::default().families;
+            let loaded_names: Vec<String> = loaded_fonts.iter().map(|x| x[0].to_string()).collect();
+            let mut chain: Vec<String> = self
+                .font_fallback
+                .iter()
+                .filter(|x| loaded_names.contains(x))
+                .cloned()
+                .collect();
+            for name in &loaded_names {
+                if !chain.contains(name) {
+                    chain.push(name.clone());
+                };
+            }
+            for family in [FontFamily::Proportional, FontFamily::Monospace] {
+                let mut fonts = chain.clone();
+                fonts.extend(default_families.get(&family).cloned().unwrap_or_default());
+                font_definitions_amount.families.insert(family, fonts);
+            }
+            for name in &loaded_names {
+                let mut fonts = vec![name.clone()];
+                fonts.extend(chain.iter().filter(|x| *x != name).cloned());
+                fonts.extend(
+                    default_families
+                        .get(&FontFamily::Proportional)
+                        .cloned()
+                        .unwrap_or_default(),
+                );
+                font_definitions_amount
+                    .families
+                    .insert(FontFamily::Name(name.as_str().into()), fonts);
+            }
+        };
         self.loading_fonts = loaded_fonts
             .iter()
             .map(|x| [x[0].to_string(), x[1].to_string()])
@@ -4830,7 +12767,15 @@ impl App {
     ///
     /// 检查页面是否已完成进入过渡。
     pub fn check_enter_updated(&mut self, name: &str) -> Result<bool, RustConstructorError> {
+        // 过渡动画播放期间页面尚未完成进入
+        let transitioning = self
+            .active_page_transition
+            .as_ref()
+            .is_some_and(|x| x.to_page == name);
         let page_data = self.get_resource_mut::<PageData>(&build_id(name, "PageData"))?;
+        if transitioning {
+            return Ok(false);
+        };
         let enter_page_updated = page_data.enter_page_updated;
         page_data.enter_page_updated = true;
         Ok(enter_page_updated)
@@ -4851,6 +12796,81 @@ impl App {
         Ok(())
     }
 
+    /// Draws a panel in the top-left corner with the frame rate, the current page, the input
+    /// state from `input_summary` and the number of resources by type.
+    ///
+    /// 在左上角绘制一个面板，显示帧率、当前页面、`input_summary`给出的输入状态和各类型资源的数量。
+    ///
+    /// The panel is painted on the debug layer, it is not part of the render queue and does not
+    /// take the mouse focus. Set `debug_overlay` to draw it automatically every frame.
+    ///
+    /// 面板绘制在调试层上，不属于渲染队列，也不会获取鼠标焦点。设置`debug_overlay`可以每帧自动绘制。
+    pub fn draw_debug_overlay(&self, ui: &Ui) {
+        const PADDING: f32 = 8_f32;
+        let mut lines = vec![
+            format!(
+                "FPS: {:.1} ({:.1} ms, 1% low {:.1} ms)",
+                self.current_fps(),
+                self.average_frame_time(),
+                self.one_percent_low_frame_time()
+            ),
+            format!(
+                "Page: {} ({:.1} s)",
+                self.current_page,
+                self.timer.now_time as f32 / 1000_f32
+            ),
+            format!(
+                "Resources: {} (rendering {}, culled {})",
+                self.rust_constructor_resource.len(),
+                self.render_list.len(),
+                self.culled_resources
+            ),
+        ];
+        let input_summary = self.input_summary(ui);
+        lines.extend([
+            format!(
+                "Pointer: {:?} [primary, secondary, middle]: {:?}",
+                input_summary.pointer_position, input_summary.pointer_buttons_down
+            ),
+            format!(
+                "Modifiers [ctrl, shift, alt, command]: {:?}",
+                input_summary.modifiers
+            ),
+            format!(
+                "Keys: {:?} Scroll: {:?}",
+                input_summary.keys_down, input_summary.scroll_delta
+            ),
+        ]);
+        // 按类型统计资源数量，数量多的在前
+        let mut type_count: Vec<(&str, usize)> = Vec::new();
+        for rcr in &self.rust_constructor_resource {
+            if let Some(count) = type_count.iter_mut().find(|x| x.0 == rcr.id.discern_type) {
+                count.1 += 1;
+            } else {
+                type_count.push((&rcr.id.discern_type, 1));
+            };
+        }
+        type_count.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        lines.extend(
+            type_count
+                .iter()
+                .map(|(discern_type, count)| format!("  {discern_type}: {count}")),
+        );
+        let painter = ui.ctx().layer_painter(LayerId::new(
+            Order::Debug,
+            Id::new("rust_constructor_debug_overlay"),
+        ));
+        let galley =
+            painter.layout_no_wrap(lines.join("\n"), FontId::monospace(12_f32), Color32::WHITE);
+        let position = ui.ctx().content_rect().min + Vec2::splat(PADDING);
+        painter.rect_filled(
+            Rect::from_min_size(position, galley.size() + Vec2::splat(PADDING * 2_f32)),
+            CornerRadius::same(4),
+            Color32::from_black_alpha(180),
+        );
+        painter.galley(position + Vec2::splat(PADDING), galley, Color32::WHITE);
+    }
+
     /// Updates frame timing statistics for performance monitoring.
     ///
     /// 更新帧数统计信息用于性能监控。
@@ -4860,43 +12880,174 @@ impl App {
     ///
     /// 此方法维护帧时间的滚动窗口并计算帧率等性能指标。
     pub fn update_frame_stats(&mut self) {
-        let current_time = self.timer.total_time;
+        // 使用真实时间，避免暂停时帧时间为0
+        let current_time = self.timer.timer.elapsed().as_millis();
         if let Some(last) = self.last_frame_time {
             let delta = current_time - last;
             self.frame_times.push(delta);
-            if self.frame_times.len() > 120 {
-                self.frame_times.drain(0..120);
+            if self.frame_times.len() > Self::MAX_FRAME_SAMPLES {
+                self.frame_times
+                    .drain(0..self.frame_times.len() - Self::MAX_FRAME_SAMPLES);
             }
         }
         self.last_frame_time = Some(current_time);
     }
 
-    /// Update the frame rate.
+    /// Update the frame rate.
+    ///
+    /// 更新帧数。
+    ///
+    /// This method is used to obtain the number of program frames and conduct analysis.
+    ///
+    /// 此方法用于获取程序帧数并进行分析。
+    pub fn current_fps(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            0.0
+        } else {
+            1000_f32 / self.average_frame_time()
+        }
+    }
+
+    /// Returns the average frame time over the recorded samples in milliseconds, 0 without
+    /// samples.
+    ///
+    /// 返回记录的样本中的平均帧时间（毫秒），没有样本时返回0。
+    pub fn average_frame_time(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            0_f32
+        } else {
+            self.frame_times.iter().sum::<u128>() as f32 / self.frame_times.len() as f32
+        }
+    }
+
+    /// Returns the frame time in milliseconds that `percentile` percent (0-100) of the recorded
+    /// frames do not exceed, 0 without samples.
+    ///
+    /// 返回记录的帧中有`percentile`%（0-100）不超过的帧时间（毫秒），没有样本时返回0。
+    pub fn frame_time_percentile(&self, percentile: f32) -> f32 {
+        if self.frame_times.is_empty() {
+            return 0_f32;
+        };
+        let mut frame_times = self.frame_times.clone();
+        frame_times.sort_unstable();
+        // 使用最近秩法
+        let rank =
+            (percentile.clamp(0_f32, 100_f32) / 100_f32 * frame_times.len() as f32).ceil() as usize;
+        frame_times[rank.max(1) - 1] as f32
+    }
+
+    /// Returns the average time of the slowest 1% of the recorded frames in milliseconds (at
+    /// least one frame), 0 without samples.
     ///
-    /// 更新帧数。
+    /// 返回记录的帧中最慢的1%（至少一帧）的平均帧时间（毫秒），没有样本时返回0。
     ///
-    /// This method is used to obtain the number of program frames and conduct analysis.
+    /// A value far above `average_frame_time` points to stutter rather than a low overall
+    /// frame rate.
     ///
-    /// 此方法用于获取程序帧数并进行分析。
-    pub fn current_fps(&self) -> f32 {
+    /// 该值远高于`average_frame_time`时，说明存在卡顿而非整体帧率偏低。
+    pub fn one_percent_low_frame_time(&self) -> f32 {
         if self.frame_times.is_empty() {
-            0.0
-        } else {
-            1000_f32
-                / (self.frame_times.iter().sum::<u128>() as f32 / self.frame_times.len() as f32)
-        }
+            return 0_f32;
+        };
+        let mut frame_times = self.frame_times.clone();
+        frame_times.sort_unstable_by(|a, b| b.cmp(a));
+        let count = frame_times.len().div_ceil(100);
+        frame_times[..count].iter().sum::<u128>() as f32 / count as f32
     }
 
     /// Resets the split time for a specific resource.
     ///
     /// 重置特定资源的分段计时器。
     pub fn reset_split_time(&mut self, name: &str) -> Result<(), RustConstructorError> {
-        let new_time = [self.timer.now_time, self.timer.total_time];
+        let timer = self
+            .get_resource::<SplitTime>(&build_id(name, "SplitTime"))?
+            .timer
+            .clone();
+        let new_time = self.split_time_now(timer.as_deref())?;
         let split_time = self.get_resource_mut::<SplitTime>(&build_id(name, "SplitTime"))?;
         split_time.time = new_time;
         Ok(())
     }
 
+    /// Returns the time passed since a split time was recorded, on the clock it reads.
+    ///
+    /// 返回自分段计时器记录以来经过的时间，以其读取的时钟为准。
+    pub fn split_time_elapsed(&self, name: &str) -> Result<u128, RustConstructorError> {
+        let split_time = self.get_resource::<SplitTime>(&build_id(name, "SplitTime"))?;
+        let now = self.split_time_now(split_time.timer.as_deref())?;
+        Ok(now[1].saturating_sub(split_time.time[1]))
+    }
+
+    fn split_time_now(&self, timer: Option<&str>) -> Result<[u128; 2], RustConstructorError> {
+        match timer {
+            Some(timer) => {
+                let elapsed = self.timer_elapsed(timer)?;
+                Ok([elapsed, elapsed])
+            }
+            None => Ok([self.timer.now_time, self.timer.total_time]),
+        }
+    }
+
+    /// Adds an independent named timer, it starts running immediately.
+    ///
+    /// 添加一个独立的命名计时器，它会立即开始运行。
+    ///
+    /// Named timers run on real time and ignore `pause_timer`, pause them separately with
+    /// `pause_named_timer`. Split times created with `SplitTime::timer` read them instead of
+    /// the application timer.
+    ///
+    /// 命名计时器按真实时间运行并且不受`pause_timer`影响，需要用`pause_named_timer`单独暂停。
+    /// 使用`SplitTime::timer`创建的分段计时器会读取命名计时器而不是应用程序计时器。
+    #[inline]
+    pub fn add_timer(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        self.add_resource(name, NamedTimer::default())
+    }
+
+    /// Returns the elapsed time of a named timer, in milliseconds.
+    ///
+    /// 返回命名计时器的已用时间（毫秒）。
+    pub fn timer_elapsed(&self, name: &str) -> Result<u128, RustConstructorError> {
+        let named_timer = self.get_resource::<NamedTimer>(&build_id(name, "NamedTimer"))?;
+        Ok(named_timer.elapsed_at(self.timer.timer.elapsed().as_millis()))
+    }
+
+    /// Resets a named timer to zero, a paused timer stays paused.
+    ///
+    /// 将命名计时器归零，已暂停的计时器保持暂停。
+    pub fn reset_timer(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let real_time = self.timer.timer.elapsed().as_millis();
+        let named_timer = self.get_resource_mut::<NamedTimer>(&build_id(name, "NamedTimer"))?;
+        named_timer.elapsed = 0;
+        named_timer.start_time = real_time;
+        Ok(())
+    }
+
+    /// Pauses a named timer.
+    ///
+    /// 暂停命名计时器。
+    pub fn pause_named_timer(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let real_time = self.timer.timer.elapsed().as_millis();
+        let named_timer = self.get_resource_mut::<NamedTimer>(&build_id(name, "NamedTimer"))?;
+        if !named_timer.paused {
+            named_timer.elapsed = named_timer.elapsed_at(real_time);
+            named_timer.paused = true;
+        };
+        Ok(())
+    }
+
+    /// Resumes a paused named timer.
+    ///
+    /// 恢复已暂停的命名计时器。
+    pub fn resume_named_timer(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let real_time = self.timer.timer.elapsed().as_millis();
+        let named_timer = self.get_resource_mut::<NamedTimer>(&build_id(name, "NamedTimer"))?;
+        if named_timer.paused {
+            named_timer.start_time = real_time;
+            named_timer.paused = false;
+        };
+        Ok(())
+    }
+
     /// Retrieves the timing information from a split time resource.
     ///
     /// 获取分段计时器资源的时间信息。
@@ -4905,6 +13056,34 @@ impl App {
         Ok(split_time.time)
     }
 
+    /// Restarts a countdown from its full duration.
+    ///
+    /// 从完整时长重新开始倒计时。
+    pub fn reset_countdown(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let total_time = self.timer.total_time;
+        let countdown = self.get_resource_mut::<Countdown>(&build_id(name, "Countdown"))?;
+        countdown.start_time = total_time;
+        Ok(())
+    }
+
+    /// Returns the remaining time of a countdown in milliseconds, it stops at zero.
+    ///
+    /// 返回倒计时的剩余时间（毫秒），到零时停止。
+    pub fn countdown_remaining(&self, name: &str) -> Result<u128, RustConstructorError> {
+        let countdown = self.get_resource::<Countdown>(&build_id(name, "Countdown"))?;
+        Ok(countdown
+            .duration
+            .saturating_sub(self.timer.total_time.saturating_sub(countdown.start_time)))
+    }
+
+    /// Checks whether a countdown has reached zero.
+    ///
+    /// 检查倒计时是否已归零。
+    #[inline]
+    pub fn countdown_finished(&self, name: &str) -> Result<bool, RustConstructorError> {
+        Ok(self.countdown_remaining(name)? == 0)
+    }
+
     /// Updates the application timer with current timing information.
     ///
     /// 更新应用程序计时器的当前时间信息。
@@ -4913,11 +13092,49 @@ impl App {
     ///
     /// 此方法更新总运行时间和当前页面运行时间。
     pub fn update_timer(&mut self) {
-        let elapsed = self.timer.timer.elapsed();
-        self.timer.total_time = elapsed.as_millis();
+        if !self.timer.paused {
+            let elapsed = self.timer.timer.elapsed();
+            self.timer.total_time = elapsed.as_millis() - self.timer.paused_duration;
+        };
         self.timer.now_time = self.timer.total_time - self.timer.start_time
     }
 
+    /// Pauses the application timer.
+    ///
+    /// 暂停应用程序计时器。
+    ///
+    /// While paused, `total_time` and `now_time` stop advancing, so animations and any logic
+    /// based on split times halt until `resume_timer` is called.
+    ///
+    /// 暂停期间`total_time`和`now_time`停止增长，因此动画和所有基于分段计时器的逻辑都会停止，直到调用`resume_timer`。
+    pub fn pause_timer(&mut self) {
+        if !self.timer.paused {
+            self.update_timer();
+            self.timer.pause_start_time = self.timer.timer.elapsed().as_millis();
+            self.timer.paused = true;
+        };
+    }
+
+    /// Resumes the application timer, excluding the paused span from the runtime.
+    ///
+    /// 恢复应用程序计时器，暂停的时长不计入运行时间。
+    pub fn resume_timer(&mut self) {
+        if self.timer.paused {
+            self.timer.paused_duration +=
+                self.timer.timer.elapsed().as_millis() - self.timer.pause_start_time;
+            self.timer.paused = false;
+            self.update_timer();
+        };
+    }
+
+    /// Checks whether the application timer is paused.
+    ///
+    /// 检查应用程序计时器是否已暂停。
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.timer.paused
+    }
+
     /// Modifies the value of a variable resource.
     ///
     /// 修改变量资源的值。
@@ -4927,7 +13144,138 @@ impl App {
         value: Option<T>,
     ) -> Result<(), RustConstructorError> {
         let variable = self.get_resource_mut::<Variable<T>>(&build_id(name, "Variable"))?;
-        variable.value = value;
+        let previous_value = std::mem::replace(&mut variable.value, value);
+        self.update_computed_variables(name)?;
+        let watchers: Vec<VariableWatcher> = self
+            .variable_watchers
+            .iter()
+            .filter(|x| x.name == name)
+            .cloned()
+            .collect();
+        for watcher in watchers {
+            (watcher.callback)(self, &previous_value)?;
+        }
+        Ok(())
+    }
+
+    /// Registers a callback invoked with the old and new value whenever a variable is modified.
+    ///
+    /// 注册一个回调，每当变量被修改时以旧值和新值调用。
+    ///
+    /// Callbacks only fire after a successful `modify_variable` (including the typed
+    /// `modify_variable_*` helpers), changing the value directly through `get_resource_mut`
+    /// or recomputing a computed variable does not notify them.
+    ///
+    /// 回调只在`modify_variable`（包括带类型的`modify_variable_*`辅助方法）成功后触发，
+    /// 通过`get_resource_mut`直接修改值或重新计算计算变量不会通知回调。
+    pub fn watch_variable<T: Debug + Clone + Send + Sync + 'static>(
+        &mut self,
+        name: &str,
+        callback: impl Fn(&mut App, Option<T>, Option<T>) + Send + Sync + 'static,
+    ) -> Result<(), RustConstructorError> {
+        self.get_variable::<T>(name)?;
+        let variable_name = name.to_string();
+        self.variable_watchers.push(VariableWatcher {
+            name: name.to_string(),
+            callback: Arc::new(move |app: &mut App, previous_value: &dyn Any| {
+                let previous_value = previous_value
+                    .downcast_ref::<Option<T>>()
+                    .cloned()
+                    .flatten();
+                let value = app.get_variable::<T>(&variable_name)?;
+                callback(app, previous_value, value);
+                Ok(())
+            }),
+        });
+        Ok(())
+    }
+
+    /// Removes all callbacks registered for a variable.
+    ///
+    /// 移除为变量注册的所有回调。
+    #[inline]
+    pub fn unwatch_variable(&mut self, name: &str) {
+        self.variable_watchers.retain(|x| x.name != name);
+    }
+
+    /// Adds a variable whose value is computed from other variables.
+    ///
+    /// 添加一个由其他变量计算得出的变量。
+    ///
+    /// The value is computed once when added and again whenever one of `dependencies` is
+    /// changed through `modify_variable`, read it with `get_variable` like any variable.
+    /// Dependencies that would form a cycle are rejected.
+    ///
+    /// 添加时计算一次值，之后每当`dependencies`中的变量通过`modify_variable`修改时重新计算，
+    /// 可以像普通变量一样用`get_variable`读取。会形成循环的依赖会被拒绝。
+    pub fn add_computed_variable<T: Debug + Send + Sync + 'static>(
+        &mut self,
+        name: &str,
+        dependencies: &[String],
+        compute: fn(&App) -> T,
+    ) -> Result<(), RustConstructorError> {
+        // 检查依赖链中是否会回到自身
+        let mut pending = dependencies.to_vec();
+        let mut checked = Vec::new();
+        while let Some(dependency) = pending.pop() {
+            if dependency == name {
+                warn!(
+                    "[ComputedVariableCycle]add_computed_variable: Variable '{name}' depends on itself."
+                );
+                return Err(RustConstructorError {
+                    error_id: RustConstructorError::COMPUTED_VARIABLE_CYCLE.to_string(),
+                    description: format!("Variable '{name}' depends on itself."),
+                });
+            };
+            if let Some(computed_variable) = self
+                .computed_variables
+                .iter()
+                .find(|x| x.name == dependency)
+            {
+                pending.extend(
+                    computed_variable
+                        .dependencies
+                        .iter()
+                        .filter(|x| !checked.contains(*x))
+                        .cloned(),
+                );
+            };
+            checked.push(dependency);
+        }
+        self.add_resource(name, Variable::default().value(Some(compute(self))))?;
+        let variable_name = name.to_string();
+        self.computed_variables.push(ComputedVariable {
+            name: name.to_string(),
+            dependencies: dependencies.to_vec(),
+            recompute: Arc::new(move |app: &mut App| {
+                let value = compute(app);
+                app.get_resource_mut::<Variable<T>>(&build_id(&variable_name, "Variable"))?
+                    .value = Some(value);
+                Ok(())
+            }),
+        });
+        Ok(())
+    }
+
+    /// Recomputes every computed variable that depends on `name`, directly or indirectly.
+    ///
+    /// 重新计算直接或间接依赖`name`的所有计算变量。
+    ///
+    /// `modify_variable` calls this automatically, call it yourself after changing a variable
+    /// through `get_resource_mut`.
+    ///
+    /// `modify_variable`会自动调用此方法，通过`get_resource_mut`修改变量后需要手动调用。
+    pub fn update_computed_variables(&mut self, name: &str) -> Result<(), RustConstructorError> {
+        let dependents: Vec<ComputedVariable> = self
+            .computed_variables
+            .iter()
+            .filter(|x| x.dependencies.iter().any(|x| x == name))
+            .cloned()
+            .collect();
+        for computed_variable in dependents {
+            (computed_variable.recompute)(self)?;
+            self.update_computed_variables(&computed_variable.name)?;
+        }
         Ok(())
     }
 
@@ -4946,7 +13294,7 @@ impl App {
         {
             error!("[ResourceNotFound]get_variable: Resource '{name}(Variable<T>)' not found.");
             Err(RustConstructorError {
-                error_id: "ResourceNotFound".to_string(),
+                error_id: RustConstructorError::RESOURCE_NOT_FOUND.to_string(),
                 description: format!("Resource '{name}(Variable<T>)' not found."),
             })
         } else {
@@ -4954,7 +13302,7 @@ impl App {
                 "[ResourceGenericMismatch]get_variable: The generic type of the resource '{name}(Variable<T>)' is mismatched."
             );
             Err(RustConstructorError {
-                error_id: "ResourceGenericMismatch".to_string(),
+                error_id: RustConstructorError::RESOURCE_GENERIC_MISMATCH.to_string(),
                 description: format!(
                     "The generic type of the resource '{name}(Variable<T>)' is mismatched."
                 ),
@@ -4962,6 +13310,86 @@ impl App {
         }
     }
 
+    /// Takes an `i64` variable out of the list, see `get_variable`.
+    ///
+    /// 从列表中取出`i64`变量，参见`get_variable`。
+    #[inline]
+    pub fn get_variable_i64(&self, name: &str) -> Result<Option<i64>, RustConstructorError> {
+        self.get_variable::<i64>(name)
+    }
+
+    /// Takes an `f64` variable out of the list, see `get_variable`.
+    ///
+    /// 从列表中取出`f64`变量，参见`get_variable`。
+    #[inline]
+    pub fn get_variable_f64(&self, name: &str) -> Result<Option<f64>, RustConstructorError> {
+        self.get_variable::<f64>(name)
+    }
+
+    /// Takes a `bool` variable out of the list, see `get_variable`.
+    ///
+    /// 从列表中取出`bool`变量，参见`get_variable`。
+    #[inline]
+    pub fn get_variable_bool(&self, name: &str) -> Result<Option<bool>, RustConstructorError> {
+        self.get_variable::<bool>(name)
+    }
+
+    /// Takes a `String` variable out of the list, see `get_variable`.
+    ///
+    /// 从列表中取出`String`变量，参见`get_variable`。
+    #[inline]
+    pub fn get_variable_string(&self, name: &str) -> Result<Option<String>, RustConstructorError> {
+        self.get_variable::<String>(name)
+    }
+
+    /// Modifies an `i64` variable, see `modify_variable`.
+    ///
+    /// 修改`i64`变量，参见`modify_variable`。
+    #[inline]
+    pub fn modify_variable_i64(
+        &mut self,
+        name: &str,
+        value: Option<i64>,
+    ) -> Result<(), RustConstructorError> {
+        self.modify_variable::<i64>(name, value)
+    }
+
+    /// Modifies an `f64` variable, see `modify_variable`.
+    ///
+    /// 修改`f64`变量，参见`modify_variable`。
+    #[inline]
+    pub fn modify_variable_f64(
+        &mut self,
+        name: &str,
+        value: Option<f64>,
+    ) -> Result<(), RustConstructorError> {
+        self.modify_variable::<f64>(name, value)
+    }
+
+    /// Modifies a `bool` variable, see `modify_variable`.
+    ///
+    /// 修改`bool`变量，参见`modify_variable`。
+    #[inline]
+    pub fn modify_variable_bool(
+        &mut self,
+        name: &str,
+        value: Option<bool>,
+    ) -> Result<(), RustConstructorError> {
+        self.modify_variable::<bool>(name, value)
+    }
+
+    /// Modifies a `String` variable, see `modify_variable`.
+    ///
+    /// 修改`String`变量，参见`modify_variable`。
+    #[inline]
+    pub fn modify_variable_string(
+        &mut self,
+        name: &str,
+        value: Option<String>,
+    ) -> Result<(), RustConstructorError> {
+        self.modify_variable::<String>(name, value)
+    }
+
     /// Modify the enable status of the switch.
     ///
     /// 修改开关的启用状态。
@@ -4987,6 +13415,30 @@ impl App {
         })
     }
 
+    /// Check the state of a text input.
+    ///
+    /// 查看文本输入框的状态。
+    pub fn check_text_input_data(&self, name: &str) -> Result<TextInputData, RustConstructorError> {
+        let text_input = self.get_resource::<TextInput>(&build_id(name, "TextInput"))?;
+        Ok(TextInputData {
+            content: text_input.content.clone(),
+            changed: text_input.changed,
+            focused: text_input.focused,
+        })
+    }
+
+    /// Check the state of a slider.
+    ///
+    /// 查看滑块的状态。
+    pub fn check_slider_data(&self, name: &str) -> Result<SliderData, RustConstructorError> {
+        let slider = self.get_resource::<Slider>(&build_id(name, "Slider"))?;
+        Ok(SliderData {
+            value: slider.value,
+            changed: slider.changed,
+            dragging: slider.dragging,
+        })
+    }
+
     /// Find out which switch in the radio switch group is activated.
     ///
     /// 查找单选开关组中哪个开关被激活了。
@@ -5004,3 +13456,534 @@ impl App {
         activate_switch
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BasicFrontResourceConfig;
+    #[cfg(feature = "rc_bevy")]
+    use egui_bevy::{Context, Event, Key, Modifiers, PointerButton, RawInput, pos2};
+    #[cfg(feature = "rc_standard")]
+    use egui_standard::{Context, Event, Key, Modifiers, PointerButton, RawInput, pos2};
+
+    #[test]
+    fn page_scopes_keep_resources_with_the_same_name_apart() {
+        let mut app = App::default();
+        for page in ["Home", "Settings"] {
+            app.with_page_scope(page, |app| {
+                app.add_resource("title", Text::default().content(page))
+                    .unwrap();
+                // 作用域内使用原名称即可找到带前缀的资源
+                assert_eq!(
+                    app.get_resource::<Text>(&build_id("title", "Text"))
+                        .unwrap()
+                        .content,
+                    page
+                );
+            });
+        }
+        let titles: Vec<&RustConstructorId> = app
+            .rust_constructor_resource
+            .iter()
+            .map(|x| &x.id)
+            .filter(|x| x.discern_type == "Text" && x.name.ends_with("title"))
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                &build_id("Home::title", "Text"),
+                &build_id("Settings::title", "Text")
+            ]
+        );
+        assert!(
+            app.check_resource_exists(&build_id("title", "Text"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn frame_time_percentile_uses_the_nearest_rank() {
+        let mut app = App::default();
+        assert_eq!(app.frame_time_percentile(50_f32), 0_f32);
+        app.frame_times.extend([40, 10, 30, 20]);
+        assert_eq!(app.frame_time_percentile(50_f32), 20_f32);
+        assert_eq!(app.frame_time_percentile(75_f32), 30_f32);
+        assert_eq!(app.frame_time_percentile(100_f32), 40_f32);
+        assert_eq!(app.frame_time_percentile(0_f32), 10_f32);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn restored_text_keeps_its_styling() {
+        let mut app = App::default();
+        app.add_resource(
+            "Title",
+            Text::default()
+                .content("Hello")
+                .bold(true)
+                .text_align(TextAlign::Center),
+        )
+        .unwrap();
+        let json = app.resources_to_json().unwrap();
+        let mut restored = App::default();
+        restored.resources_from_json(&json).unwrap();
+        let text = restored
+            .get_resource::<Text>(&build_id("Title", "Text"))
+            .unwrap();
+        assert_eq!(text.content, "Hello");
+        assert!(text.bold);
+        assert_eq!(text.text_align, TextAlign::Center);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn restoring_variables_skips_entries_that_do_not_match() {
+        let mut app = App::default();
+        app.add_resource("Count", Variable::default().value(Some(3_i32)))
+            .unwrap();
+        app.add_resource("Flag", Variable::default().value(Some(false)))
+            .unwrap();
+        app.resources_from_json(
+            r#"{"resources": [
+                {"name": "Count", "type": "Variable", "value_type": "i32"},
+                {"name": "Count", "type": "Variable", "value_type": "String", "value": "4"},
+                {"name": "Flag", "type": "Variable", "value_type": "bool", "value": true}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(app.get_variable::<i32>("Count").unwrap(), Some(3));
+        assert_eq!(app.get_variable::<bool>("Flag").unwrap(), Some(true));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_page_layout_restores_positions() {
+        let mut app = App::default();
+        app.add_resource("Home", PageData::default()).unwrap();
+        app.with_page_scope("Home", |app| {
+            app.add_resource(
+                "Box",
+                CustomRect::default().basic_front_resource_config(
+                    &BasicFrontResourceConfig::default().position_size_config(
+                        PositionSizeConfig::default()
+                            .origin_position(12_f32, 34_f32)
+                            .origin_size(56_f32, 78_f32),
+                    ),
+                ),
+            )
+            .unwrap();
+            app.add_resource(
+                "Title",
+                Text::default().basic_front_resource_config(
+                    &BasicFrontResourceConfig::default().position_size_config(
+                        PositionSizeConfig::default()
+                            .origin_position(90_f32, 10_f32)
+                            .display_method(HorizontalAlign::Center, VerticalAlign::Top),
+                    ),
+                ),
+            )
+            .unwrap();
+        });
+        let layout = app.serialize_page_layout("Home").unwrap();
+        let mut restored = App::default();
+        restored.resources_from_json(&layout).unwrap();
+        for id in [
+            build_id("Home::Box", "CustomRect"),
+            build_id("Home::Title", "Text"),
+        ] {
+            let original = app
+                .get_box_resource(&id)
+                .unwrap()
+                .convert_to_basic_front_dyn()
+                .unwrap();
+            let loaded = restored
+                .get_box_resource(&id)
+                .unwrap()
+                .convert_to_basic_front_dyn()
+                .unwrap();
+            assert_eq!(
+                loaded.display_position_size_config(),
+                original.display_position_size_config()
+            );
+            assert_eq!(loaded.display_position(), original.display_position());
+        }
+    }
+
+    #[test]
+    fn adaptive_tick_interval_follows_the_animation_state() {
+        let mut app = App::default();
+        app.set_adaptive_tick(16, 200);
+        app.mark_animation_active();
+        assert!(!app.update_tick_interval());
+        assert_eq!(app.tick_interval, 16);
+        assert!(app.update_tick_interval());
+        assert_eq!(app.tick_interval, 200);
+        app.disable_adaptive_tick(50);
+        app.mark_animation_active();
+        assert!(!app.update_tick_interval());
+        assert_eq!(app.tick_interval, 50);
+    }
+
+    #[test]
+    fn input_summary_reflects_the_raw_input() {
+        let app = App::default();
+        let raw_input = RawInput {
+            modifiers: Modifiers::SHIFT,
+            events: vec![
+                Event::PointerMoved(pos2(10_f32, 20_f32)),
+                Event::PointerButton {
+                    pos: pos2(10_f32, 20_f32),
+                    button: PointerButton::Primary,
+                    pressed: true,
+                    modifiers: Modifiers::SHIFT,
+                },
+                Event::Key {
+                    key: Key::A,
+                    physical_key: None,
+                    pressed: true,
+                    repeat: false,
+                    modifiers: Modifiers::SHIFT,
+                },
+            ],
+            ..RawInput::default()
+        };
+        let mut input_summary = InputSummary::default();
+        let _ = Context::default().run_ui(raw_input, |ui| input_summary = app.input_summary(ui));
+        assert_eq!(input_summary.pointer_position, Some([10_f32, 20_f32]));
+        assert_eq!(input_summary.pointer_buttons_down, [true, false, false]);
+        assert_eq!(input_summary.modifiers, [false, true, false, false]);
+        assert_eq!(input_summary.keys_down, ["A"]);
+        assert_eq!(input_summary.scroll_delta, [0_f32, 0_f32]);
+    }
+
+    #[test]
+    fn removing_a_composite_resource_removes_its_children() {
+        let mut app = App::default();
+        app.add_resource("Name", TextInput::default()).unwrap();
+        app.add_resource("Other", TextInput::default()).unwrap();
+        assert!(
+            app.check_resource_exists(&build_id("NameText", "Text"))
+                .is_some()
+        );
+        app.remove_resource(&build_id("Name", "TextInput")).unwrap();
+        assert!(
+            app.check_resource_exists(&build_id("NameText", "Text"))
+                .is_none()
+        );
+        assert!(
+            app.check_resource_exists(&build_id("OtherText", "Text"))
+                .is_some()
+        );
+        assert_eq!(app.remove_resources_by_type("TextInput"), 2);
+        assert!(app.rust_constructor_resource.is_empty());
+    }
+
+    #[test]
+    fn rotated_rect_mesh_keeps_its_rounding() {
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::new(100_f32, 50_f32));
+        let fill = RectFill::Solid([255, 255, 255, 255]);
+        let blend_fill =
+            |rgba: [u8; 4]| Color32::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3]);
+        let mesh = App::custom_rect_mesh(rect, [0_f32; 4], &fill, blend_fill, 0_f32, [0_f32; 2]);
+        let rotated = App::custom_rect_mesh(
+            rect,
+            [0_f32; 4],
+            &fill,
+            blend_fill,
+            std::f32::consts::FRAC_PI_2,
+            [0_f32; 2],
+        );
+        // 绕左上角旋转90°后(x, y)变为(-y, x)
+        assert_eq!(mesh.vertices.len(), rotated.vertices.len());
+        for (vertex, rotated_vertex) in mesh.vertices.iter().zip(&rotated.vertices) {
+            assert!((rotated_vertex.pos.x + vertex.pos.y).abs() < 1e-3);
+            assert!((rotated_vertex.pos.y - vertex.pos.x).abs() < 1e-3);
+        }
+        // 旋转不会丢弃圆角
+        let rounded = App::custom_rect_mesh(
+            rect,
+            [10_f32; 4],
+            &fill,
+            blend_fill,
+            std::f32::consts::FRAC_PI_2,
+            [0_f32; 2],
+        );
+        assert!(rounded.vertices.len() > rotated.vertices.len());
+    }
+
+    #[test]
+    fn styled_segments_follow_truncated_characters() {
+        let source: Vec<Option<usize>> = (0..10).map(Some).collect();
+        // "abcdefghij"中间截断后显示为"ab...ij"
+        let display_source = App::truncated_source(&source, 3, TruncateMode::Middle, 4);
+        assert_eq!(display_source.len(), 7);
+        assert_eq!(
+            App::display_segment_ranges(&display_source, [1, 9]),
+            vec![[1, 2], [5, 6]]
+        );
+        // 开头截断后显示为"...ghij"，越界的范围被截断
+        let display_source = App::truncated_source(&source, 3, TruncateMode::Start, 4);
+        assert_eq!(
+            App::display_segment_ranges(&display_source, [8, 20]),
+            vec![[5, 7]]
+        );
+        assert!(App::display_segment_ranges(&display_source, [0, 6]).is_empty());
+    }
+
+    #[test]
+    fn clicking_show_more_expands_collapsed_text() {
+        let mut app = App::default().current_page("Home");
+        app.add_resource("Home", PageData::default()).unwrap();
+        app.add_resource(
+            "Description",
+            Text::default()
+                .content(&"word ".repeat(80))
+                .collapsible(Some(
+                    crate::basic_front::CollapsibleConfig::default().collapsed_lines(2),
+                ))
+                .basic_front_resource_config(
+                    &BasicFrontResourceConfig::default().position_size_config(
+                        PositionSizeConfig::default().origin_size(200_f32, 1000_f32),
+                    ),
+                ),
+        )
+        .unwrap();
+        let id = build_id("Description", "Text");
+        let ctx = Context::default();
+        let frame = |app: &mut App, events: Vec<Event>| {
+            let _ = ctx.run_ui(
+                RawInput {
+                    screen_rect: Some(Rect::from_min_size(
+                        Pos2::ZERO,
+                        Vec2::new(800_f32, 1200_f32),
+                    )),
+                    events,
+                    ..RawInput::default()
+                },
+                |ui| {
+                    app.use_resource(&id, None, ui).unwrap();
+                    app.use_resource(&build_id("Home", "PageData"), None, ui)
+                        .unwrap();
+                },
+            );
+        };
+        let rows = |app: &App| {
+            app.text_layout_cache
+                .iter()
+                .find(|x| x.target == id)
+                .map_or(0, |x| x.galley.rows.len())
+        };
+        frame(&mut app, Vec::new());
+        let collapsed_rows = rows(&app);
+        assert_eq!(collapsed_rows, 2);
+        // 点击折叠文本末尾的"Show more"标签
+        let text = app.get_resource::<Text>(&id).unwrap();
+        let cache = app
+            .text_layout_cache
+            .iter()
+            .find(|x| x.target == id)
+            .unwrap();
+        let end = cache.display_content.chars().count();
+        let label_start = cache.galley.pos_from_cursor(CCursor::new(end - 4));
+        let label_position = label_start.center() + Vec2::from(text.position);
+        frame(
+            &mut app,
+            vec![
+                Event::PointerMoved(label_position),
+                Event::PointerButton {
+                    pos: label_position,
+                    button: PointerButton::Primary,
+                    pressed: true,
+                    modifiers: Modifiers::NONE,
+                },
+            ],
+        );
+        frame(
+            &mut app,
+            vec![Event::PointerButton {
+                pos: label_position,
+                button: PointerButton::Primary,
+                pressed: false,
+                modifiers: Modifiers::NONE,
+            }],
+        );
+        assert!(app.get_resource::<Text>(&id).unwrap().expanded);
+        frame(&mut app, Vec::new());
+        assert!(rows(&app) > collapsed_rows);
+    }
+
+    #[test]
+    fn polygon_convexity_rejects_concave_and_self_intersecting_shapes() {
+        let triangle = [pos2(0_f32, 0_f32), pos2(10_f32, 0_f32), pos2(5_f32, 10_f32)];
+        let arrow = [
+            pos2(0_f32, 4_f32),
+            pos2(6_f32, 4_f32),
+            pos2(6_f32, 0_f32),
+            pos2(10_f32, 5_f32),
+            pos2(6_f32, 10_f32),
+            pos2(6_f32, 6_f32),
+            pos2(0_f32, 6_f32),
+        ];
+        // 五角星按隔一个顶点的顺序连接，每个顶点处转向相同
+        let star: Vec<Pos2> = (0..5)
+            .map(|x| {
+                let angle = x as f32 * 4_f32 * std::f32::consts::PI / 5_f32;
+                pos2(angle.cos() * 10_f32, angle.sin() * 10_f32)
+            })
+            .collect();
+        assert!(App::polygon_is_convex(&triangle));
+        assert!(!App::polygon_is_convex(&arrow));
+        assert!(!App::polygon_is_convex(&star));
+    }
+
+    #[test]
+    fn polygon_triangulation_covers_the_whole_area() {
+        let area = |points: &[Pos2], triangles: &[[u32; 3]]| {
+            triangles
+                .iter()
+                .map(|[a, b, c]| {
+                    let [a, b, c] = [
+                        points[*a as usize],
+                        points[*b as usize],
+                        points[*c as usize],
+                    ];
+                    ((b - a).x * (c - a).y - (b - a).y * (c - a).x).abs() / 2_f32
+                })
+                .sum::<f32>()
+        };
+        let triangle = [pos2(0_f32, 0_f32), pos2(10_f32, 0_f32), pos2(5_f32, 10_f32)];
+        let triangles = App::triangulate_polygon(&triangle);
+        assert_eq!(triangles.len(), 1);
+        assert_eq!(area(&triangle, &triangles), 50_f32);
+        let arrow = [
+            pos2(0_f32, 4_f32),
+            pos2(6_f32, 4_f32),
+            pos2(6_f32, 0_f32),
+            pos2(10_f32, 5_f32),
+            pos2(6_f32, 10_f32),
+            pos2(6_f32, 6_f32),
+            pos2(0_f32, 6_f32),
+        ];
+        let triangles = App::triangulate_polygon(&arrow);
+        assert_eq!(triangles.len(), 5);
+        assert_eq!(area(&arrow, &triangles), 32_f32);
+        // L形的底边上有一个共线顶点
+        let l_shape = [
+            pos2(0_f32, 0_f32),
+            pos2(4_f32, 0_f32),
+            pos2(4_f32, 6_f32),
+            pos2(10_f32, 6_f32),
+            pos2(10_f32, 10_f32),
+            pos2(5_f32, 10_f32),
+            pos2(0_f32, 10_f32),
+        ];
+        let triangles = App::triangulate_polygon(&l_shape);
+        assert_eq!(triangles.len(), 4);
+        assert_eq!(area(&l_shape, &triangles), 64_f32);
+    }
+
+    #[test]
+    fn middle_truncation_keeps_the_head_and_tail_of_a_path() {
+        let mut app = App::default().current_page("Home");
+        app.add_resource("Home", PageData::default()).unwrap();
+        let path = "/Users/someone/projects/rust_constructor/src/very/deep/folder/file.txt";
+        app.add_resource(
+            "Path",
+            Text::default()
+                .content(path)
+                .ellipsis_position(crate::basic_front::EllipsisPosition::Middle)
+                .basic_front_resource_config(
+                    &BasicFrontResourceConfig::default().position_size_config(
+                        PositionSizeConfig::default().origin_size(200_f32, 30_f32),
+                    ),
+                ),
+        )
+        .unwrap();
+        let id = build_id("Path", "Text");
+        let _ = Context::default().run_ui(RawInput::default(), |ui| {
+            app.use_resource(&id, None, ui).unwrap();
+            app.use_resource(&build_id("Home", "PageData"), None, ui)
+                .unwrap();
+        });
+        let display_content = &app
+            .text_layout_cache
+            .iter()
+            .find(|x| x.target == id)
+            .unwrap()
+            .display_content;
+        let (head, tail) = display_content.split_once("...").unwrap();
+        assert!(!head.is_empty() && path.starts_with(head));
+        assert!(!tail.is_empty() && path.ends_with(tail));
+        assert!(display_content.len() < path.len());
+    }
+
+    #[test]
+    fn constraining_a_rect_pulls_it_back_into_the_window() {
+        let mut app = App::default();
+        app.add_resource(
+            "Panel",
+            CustomRect::default().basic_front_resource_config(
+                &BasicFrontResourceConfig::default().position_size_config(
+                    PositionSizeConfig::default()
+                        .origin_position(2000_f32, 100_f32)
+                        .origin_size(100_f32, 50_f32),
+                ),
+            ),
+        )
+        .unwrap();
+        let id = build_id("Panel", "CustomRect");
+        let raw_input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(800_f32, 600_f32))),
+            ..RawInput::default()
+        };
+        let _ = Context::default().run_ui(raw_input, |ui| {
+            app.constrain_resource_to_window(&id, 20_f32, ui).unwrap();
+        });
+        // 超出右边缘的矩形被移回，只保留20像素可见
+        assert_eq!(
+            app.get_resource::<CustomRect>(&id)
+                .unwrap()
+                .basic_front_resource_config
+                .position_size_config
+                .origin_position,
+            [780_f32, 100_f32]
+        );
+    }
+
+    #[test]
+    fn pausing_the_timer_freezes_the_total_time() {
+        let mut app = App::default();
+        app.pause_timer();
+        let paused_total = app.timer.total_time;
+        // 将计时器的起点提前一秒来模拟暂停期间经过的时间
+        app.timer.timer = app
+            .timer
+            .timer
+            .checked_sub(std::time::Duration::from_secs(1))
+            .unwrap();
+        app.update_timer();
+        assert_eq!(app.timer.total_time, paused_total);
+        // 恢复后暂停的一秒不计入总运行时间
+        app.resume_timer();
+        assert!(app.timer.paused_duration >= 1000);
+        assert!(app.timer.total_time < paused_total + 500);
+    }
+
+    #[test]
+    fn toast_disappears_after_its_duration() {
+        let mut app = App::default();
+        let ctx = Context::default();
+        app.toast("hi", 2_f32, ToastStyle::default());
+        let _ = ctx.run_ui(RawInput::default(), |ui| app.draw_toasts(ui));
+        assert_eq!(app.toasts.len(), 1);
+        assert_eq!(app.toasts[0].message, "hi");
+        // 将计时器的起点提前两秒多来模拟提示消息的持续时间
+        app.timer.timer = app
+            .timer
+            .timer
+            .checked_sub(std::time::Duration::from_millis(2100))
+            .unwrap();
+        let _ = ctx.run_ui(RawInput::default(), |ui| app.draw_toasts(ui));
+        assert!(app.toasts.is_empty());
+    }
+}
@@ -1,8 +1,15 @@
 //! This file contains backend resources, which can store some key data and be called upon when needed.
 //!
 //! 此文件包含后端资源，后端资源可以存储一些关键数据并在有需要时调用。
-use crate::{BasicFrontResource, FrontResource, RustConstructorResource};
-use std::{any::Any, fmt::Debug};
+use crate::{
+    BasicFrontResource, FrontResource, RustConstructorError, RustConstructorId,
+    RustConstructorResource, Transition, app::App,
+};
+use std::{
+    any::Any,
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
 
 /// Storage Rust Constructor resource for page-specific data and state management.
 ///
@@ -28,6 +35,12 @@ pub struct PageData {
     /// 指示是否已加载进入该页所需的资源。
     pub enter_page_updated: bool,
 
+    /// Transition played when switching to this page as (animation, duration in milliseconds),
+    /// None falls back to the app-wide `page_transition`.
+    ///
+    /// 切换到该页面时播放的过渡，为(动画, 时长（毫秒）)，为None时使用全局的`page_transition`。
+    pub transition: Option<(Transition, u128)>,
+
     /// Key-value pairs for categorization and metadata storage.
     ///
     /// 用于分类和元数据存储的键值对。
@@ -91,6 +104,7 @@ impl Default for PageData {
             forced_update: true,
             change_page_updated: false,
             enter_page_updated: false,
+            transition: None,
             tags: Vec::new(),
         }
     }
@@ -103,6 +117,12 @@ impl PageData {
         self
     }
 
+    #[inline]
+    pub fn transition(mut self, transition: Option<(Transition, u128)>) -> Self {
+        self.transition = transition;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
         if replace {
@@ -218,6 +238,240 @@ impl<T> Variable<T> {
     }
 }
 
+/// Group of switches where turning one on resets the others to state 0.
+///
+/// 开关组，打开其中一个开关时其他开关会重置为状态0。
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SwitchGroup {
+    /// Names of the switches in the group.
+    ///
+    /// 组内开关的名称。
+    pub switches: Vec<String>,
+
+    /// Key-value pairs for categorization and metadata storage.
+    ///
+    /// 用于分类和元数据存储的键值对。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for SwitchGroup {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        None
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl SwitchGroup {
+    #[inline]
+    pub fn switches(mut self, switches: &[String]) -> Self {
+        self.switches = switches.to_owned();
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Ordered group of resources that can be moved, hidden and drawn together.
+///
+/// 可以一起移动、隐藏和绘制的有序资源组。
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ResourceGroup {
+    /// Members of the group in drawing order, a member can be another `ResourceGroup`.
+    ///
+    /// 按绘制顺序排列的组成员，成员可以是另一个`ResourceGroup`。
+    pub members: Vec<RustConstructorId>,
+
+    /// Key-value pairs for categorization and metadata storage.
+    ///
+    /// 用于分类和元数据存储的键值对。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for ResourceGroup {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        None
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl ResourceGroup {
+    #[inline]
+    pub fn members(mut self, members: &[RustConstructorId]) -> Self {
+        self.members = members.to_owned();
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Recompute rule of a variable derived from other variables.
+///
+/// 由其他变量派生的变量的重新计算规则。
+#[derive(Clone)]
+pub struct ComputedVariable {
+    /// Name of the computed variable.
+    ///
+    /// 计算变量的名称。
+    pub name: String,
+
+    /// Names of the variables it is computed from.
+    ///
+    /// 计算所依赖的变量名称。
+    pub dependencies: Vec<String>,
+
+    /// Computes the value and stores it in the variable.
+    ///
+    /// 计算值并将其存入变量。
+    pub recompute: Arc<dyn Fn(&mut App) -> Result<(), RustConstructorError> + Send + Sync>,
+}
+
+impl Debug for ComputedVariable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComputedVariable")
+            .field("name", &self.name)
+            .field("dependencies", &self.dependencies)
+            .finish()
+    }
+}
+
+/// Callback invoked after a variable is modified through `modify_variable`.
+///
+/// 变量通过`modify_variable`修改后调用的回调。
+#[derive(Clone)]
+pub struct VariableWatcher {
+    /// Name of the watched variable.
+    ///
+    /// 被监听的变量名称。
+    pub name: String,
+
+    /// Receives the app and the previous value, stored as `Option<T>`.
+    ///
+    /// 接收应用程序和以`Option<T>`形式存储的旧值。
+    pub callback: Arc<dyn Fn(&mut App, &dyn Any) -> Result<(), RustConstructorError> + Send + Sync>,
+}
+
+impl Debug for VariableWatcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VariableWatcher")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
 /// Time segmentation resource for tracking and managing timing information.
 ///
 /// 时间分段资源，用于跟踪和管理时间信息。
@@ -233,6 +487,15 @@ pub struct SplitTime {
     /// 时间点：[页面运行时间, 总运行时间]，单位为秒。
     pub time: [u128; 2],
 
+    /// Name of the named timer the split time reads, `None` reads the application timer.
+    ///
+    /// 分段计时器读取的命名计时器名称，为`None`时读取应用程序计时器。
+    ///
+    /// With a named timer both entries of `time` hold that timer's elapsed time.
+    ///
+    /// 使用命名计时器时`time`的两项都为该计时器的已用时间。
+    pub timer: Option<String>,
+
     /// Key-value pairs for categorization and metadata storage.
     ///
     /// 用于分类和元数据存储的键值对标签。
@@ -291,6 +554,223 @@ impl RustConstructorResource for SplitTime {
 }
 
 impl SplitTime {
+    #[inline]
+    pub fn timer(mut self, timer: &str) -> Self {
+        self.timer = Some(timer.to_string());
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Countdown resource that counts down from a set duration on the application timer.
+///
+/// 基于应用程序计时器从设定时长开始倒数的倒计时资源。
+///
+/// The countdown reads `total_time`, so it freezes while the application timer is paused.
+///
+/// 倒计时读取`total_time`，因此在应用程序计时器暂停时会停止。
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Countdown {
+    /// Length of the countdown, in milliseconds.
+    ///
+    /// 倒计时时长（毫秒）。
+    pub duration: u128,
+
+    /// Total runtime when the countdown started, in milliseconds.
+    ///
+    /// 倒计时开始时的总运行时间（毫秒）。
+    pub start_time: u128,
+
+    /// Key-value pairs for categorization and metadata storage.
+    ///
+    /// 用于分类和元数据存储的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for Countdown {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        None
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl Countdown {
+    #[inline]
+    pub fn duration(mut self, duration: u128) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Independent clock that can be paused and reset without affecting the application timer.
+///
+/// 可独立暂停和重置而不影响应用程序计时器的时钟。
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NamedTimer {
+    /// Time accumulated before the current run, in milliseconds.
+    ///
+    /// 本次运行之前累计的时间（毫秒）。
+    pub elapsed: u128,
+
+    /// Real time when the current run started, in milliseconds.
+    ///
+    /// 本次运行开始时的真实时间（毫秒）。
+    pub start_time: u128,
+
+    /// Whether the timer is paused.
+    ///
+    /// 计时器是否已暂停。
+    pub paused: bool,
+
+    /// Key-value pairs for categorization and metadata storage.
+    ///
+    /// 用于分类和元数据存储的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for NamedTimer {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        None
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        None
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        None
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        None
+    }
+}
+
+impl NamedTimer {
+    /// Returns the elapsed time at the given real time, in milliseconds.
+    ///
+    /// 返回给定真实时间下的已用时间（毫秒）。
+    #[inline]
+    pub fn elapsed_at(&self, real_time: u128) -> u128 {
+        if self.paused {
+            self.elapsed
+        } else {
+            self.elapsed + real_time.saturating_sub(self.start_time)
+        }
+    }
+
+    #[inline]
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
         if replace {
@@ -3,12 +3,18 @@
 //! 此文件包含基本前端资源。基本前端资源可以单独使用，也可被用于创建高级前端资源。
 use crate::{
     BasicFrontResource, BasicFrontResourceConfig, Config, DisplayInfo, FrontResource,
-    PositionSizeConfig, RustConstructorResource,
+    PositionSizeConfig, RustConstructorId, RustConstructorResource,
 };
 #[cfg(feature = "rc_bevy")]
-use egui_bevy::{ColorImage, TextureHandle};
+use egui_bevy::{
+    Color32, ColorImage, CornerRadius, Galley, Shadow, TextureHandle,
+    epaint::textures::{TextureFilter as EguiTextureFilter, TextureOptions},
+};
 #[cfg(feature = "rc_standard")]
-use egui_standard::{ColorImage, TextureHandle};
+use egui_standard::{
+    Color32, ColorImage, CornerRadius, Galley, Shadow, TextureHandle,
+    epaint::textures::{TextureFilter as EguiTextureFilter, TextureOptions},
+};
 use std::{
     any::Any,
     collections::HashMap,
@@ -20,6 +26,7 @@ use std::{
 ///
 /// 定义边框相对于元素边界的放置方式。
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BorderKind {
     /// Border is drawn inside the element's bounds, reducing the content area.
     ///
@@ -36,6 +43,542 @@ pub enum BorderKind {
     Outside,
 }
 
+/// Pattern used to draw a border.
+///
+/// 绘制边框所用的图案。
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BorderStyle {
+    /// A continuous line.
+    ///
+    /// 连续的线。
+    #[default]
+    Solid,
+    /// Dashes of length `dash` separated by `gap`.
+    ///
+    /// 长度为`dash`、间隔为`gap`的虚线。
+    Dashed { dash: f32, gap: f32 },
+    /// Round dots spaced by twice the border width.
+    ///
+    /// 间距为边框宽度两倍的圆点。
+    Dotted,
+}
+
+/// Lightweight visual feedback applied while the pointer is over a resource.
+///
+/// 指针悬停在资源上时应用的轻量视觉反馈。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HoverEffect {
+    /// Blends the color towards [R, G, B, A], where A is the strength of the blend.
+    ///
+    /// 将颜色向[R, G, B, A]混合，A为混合强度。
+    Tint([u8; 4]),
+    /// Scales the resource around its center by the given factor.
+    ///
+    /// 以资源中心为基准按指定倍数缩放。
+    Scale(f32),
+    /// Multiplies the brightness by the given factor, values above 1 brighten.
+    ///
+    /// 按指定倍数调整亮度，大于1时变亮。
+    Brightness(f32),
+}
+
+impl HoverEffect {
+    /// Applies the effect to a color according to the hover progress (0.0-1.0).
+    ///
+    /// 根据悬停进度（0.0-1.0）将效果应用到颜色上。
+    pub fn apply_to_color(&self, color: [u8; 3], progress: f32) -> [u8; 3] {
+        match self {
+            HoverEffect::Tint(tint) => {
+                let strength = tint[3] as f32 / 255_f32 * progress;
+                [0, 1, 2].map(|i| {
+                    (color[i] as f32 + (tint[i] as f32 - color[i] as f32) * strength) as u8
+                })
+            }
+            HoverEffect::Brightness(brightness) => {
+                let factor = 1_f32 + (brightness - 1_f32) * progress;
+                color.map(|x| (x as f32 * factor).clamp(0_f32, 255_f32) as u8)
+            }
+            HoverEffect::Scale(_) => color,
+        }
+    }
+
+    /// Applies the effect to a [position, size] rectangle according to the hover progress (0.0-1.0).
+    ///
+    /// 根据悬停进度（0.0-1.0）将效果应用到[位置, 尺寸]矩形上。
+    pub fn apply_to_rect(&self, rect: [[f32; 2]; 2], progress: f32) -> [[f32; 2]; 2] {
+        if let HoverEffect::Scale(scale) = self {
+            let factor = 1_f32 + (scale - 1_f32) * progress;
+            let [position, size] = rect;
+            let new_size = [size[0] * factor, size[1] * factor];
+            [
+                [
+                    position[0] - (new_size[0] - size[0]) / 2_f32,
+                    position[1] - (new_size[1] - size[1]) / 2_f32,
+                ],
+                new_size,
+            ]
+        } else {
+            rect
+        }
+    }
+}
+
+/// Corner rounding of a rectangle.
+///
+/// 矩形的圆角。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// The same radius for all four corners.
+    ///
+    /// 四个角使用相同的半径。
+    Uniform(f32),
+    /// Separate radii as [nw, ne, sw, se].
+    ///
+    /// 分别指定的半径，格式为[左上, 右上, 左下, 右下]。
+    Corners([f32; 4]),
+}
+
+impl Default for Rounding {
+    fn default() -> Self {
+        Rounding::Uniform(0_f32)
+    }
+}
+
+impl From<f32> for Rounding {
+    fn from(rounding: f32) -> Self {
+        Rounding::Uniform(rounding)
+    }
+}
+
+impl Rounding {
+    /// Returns the radius of each corner as [nw, ne, sw, se].
+    ///
+    /// 返回每个角的半径，格式为[左上, 右上, 左下, 右下]。
+    pub fn corners(&self) -> [f32; 4] {
+        match *self {
+            Rounding::Uniform(rounding) => [rounding; 4],
+            Rounding::Corners(corners) => corners,
+        }
+    }
+
+    /// Converts the rounding into an egui corner radius.
+    ///
+    /// 将圆角转换为egui的角半径。
+    pub fn to_corner_radius(&self) -> CornerRadius {
+        let [nw, ne, sw, se] = self
+            .corners()
+            .map(|x| x.round().clamp(0_f32, 255_f32) as u8);
+        CornerRadius { nw, ne, sw, se }
+    }
+}
+
+/// Drop shadow painted behind a rectangle.
+///
+/// 绘制在矩形后方的阴影。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ShadowConfig {
+    /// Offset of the shadow from the rectangle as [x, y].
+    ///
+    /// 阴影相对于矩形的偏移，为[x, y]。
+    pub offset: [f32; 2],
+
+    /// Width of the blurred edge.
+    ///
+    /// 模糊边缘的宽度。
+    pub blur: f32,
+
+    /// How far the shadow is expanded beyond the rectangle.
+    ///
+    /// 阴影超出矩形的扩展距离。
+    pub spread: f32,
+
+    /// Color of the shadow as [R, G, B, A].
+    ///
+    /// 阴影颜色，为[R, G, B, A]。
+    pub color: [u8; 4],
+}
+
+impl Default for ShadowConfig {
+    fn default() -> Self {
+        ShadowConfig {
+            offset: [0_f32, 4_f32],
+            blur: 12_f32,
+            spread: 0_f32,
+            color: [0, 0, 0, 80],
+        }
+    }
+}
+
+impl ShadowConfig {
+    #[inline]
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = [x, y];
+        self
+    }
+
+    #[inline]
+    pub fn blur(mut self, blur: f32) -> Self {
+        self.blur = blur;
+        self
+    }
+
+    #[inline]
+    pub fn spread(mut self, spread: f32) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    #[inline]
+    pub fn color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Returns how far the shadow can reach beyond the rectangle on any side.
+    ///
+    /// 返回阴影在任意一侧最多可超出矩形的距离。
+    pub fn extent(&self) -> f32 {
+        self.offset[0].abs().max(self.offset[1].abs()) + self.blur + self.spread
+    }
+
+    /// Converts the config into an egui shadow, scaling its opacity by `alpha` (0-255).
+    ///
+    /// 将配置转换为egui阴影，并按`alpha`（0-255）缩放其不透明度。
+    pub fn to_shadow(&self, alpha: u8) -> Shadow {
+        Shadow {
+            offset: self
+                .offset
+                .map(|x| x.round().clamp(i8::MIN as f32, i8::MAX as f32) as i8),
+            blur: self.blur.round().clamp(0_f32, 255_f32) as u8,
+            spread: self.spread.round().clamp(0_f32, 255_f32) as u8,
+            color: Color32::from_rgba_unmultiplied(
+                self.color[0],
+                self.color[1],
+                self.color[2],
+                (self.color[3] as f32 * alpha as f32 / 255_f32) as u8,
+            ),
+        }
+    }
+}
+
+/// Fill of a custom rectangle.
+///
+/// 自定义矩形的填充方式。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RectFill {
+    /// A single color as [R, G, B, A].
+    ///
+    /// 单一颜色，格式为[R, G, B, A]。
+    Solid([u8; 4]),
+    /// Colors blended along a direction, the angle is in radians and 0 runs from left to right.
+    ///
+    /// 沿某一方向混合的颜色，角度以弧度为单位，0表示从左到右。
+    LinearGradient {
+        from: [u8; 4],
+        to: [u8; 4],
+        angle: f32,
+    },
+    /// Colors blended from the center to the edges.
+    ///
+    /// 从中心向边缘混合的颜色。
+    RadialGradient { inner: [u8; 4], outer: [u8; 4] },
+}
+
+impl RectFill {
+    /// Returns the color at an offset from the rectangle center, given the half size of the
+    /// rectangle.
+    ///
+    /// 根据矩形的半尺寸返回距矩形中心指定偏移处的颜色。
+    pub fn color_at(&self, offset: [f32; 2], half_size: [f32; 2]) -> [u8; 4] {
+        let (start, end, progress) = match *self {
+            RectFill::Solid(color) => return color,
+            RectFill::LinearGradient { from, to, angle } => {
+                let direction = [angle.cos(), angle.sin()];
+                // 矩形在渐变方向上的半长度
+                let half_length =
+                    (half_size[0] * direction[0]).abs() + (half_size[1] * direction[1]).abs();
+                let progress = if half_length > 0_f32 {
+                    0.5 + (offset[0] * direction[0] + offset[1] * direction[1])
+                        / (2_f32 * half_length)
+                } else {
+                    0.5
+                };
+                (from, to, progress)
+            }
+            RectFill::RadialGradient { inner, outer } => {
+                let relative = |offset: f32, half: f32| {
+                    if half > 0_f32 { offset / half } else { 0_f32 }
+                };
+                let progress = (relative(offset[0], half_size[0]).powi(2)
+                    + relative(offset[1], half_size[1]).powi(2))
+                .sqrt();
+                (inner, outer, progress)
+            }
+        };
+        let progress = progress.clamp(0_f32, 1_f32);
+        std::array::from_fn(|i| {
+            (start[i] as f32 + (end[i] as f32 - start[i] as f32) * progress).round() as u8
+        })
+    }
+}
+
+/// How an image texture is scaled into the image's box.
+///
+/// 图像纹理缩放到图像区域内的方式。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScaleMode {
+    /// Fills the box, ignoring the aspect ratio.
+    ///
+    /// 填满区域，忽略宽高比。
+    #[default]
+    Stretch,
+    /// Fits the whole texture inside the box, leaving empty bars.
+    ///
+    /// 将整个纹理放入区域内，留出空白。
+    Contain,
+    /// Covers the whole box, cropping the overflowing part of the texture.
+    ///
+    /// 覆盖整个区域，裁剪纹理超出的部分。
+    Cover,
+    /// Matches the box width, the height follows the aspect ratio.
+    ///
+    /// 与区域宽度一致，高度按宽高比计算。
+    FitWidth,
+    /// Matches the box height, the width follows the aspect ratio.
+    ///
+    /// 与区域高度一致，宽度按宽高比计算。
+    FitHeight,
+}
+
+impl ScaleMode {
+    /// Returns the drawn position, size and uv region inside the box for a texture region of
+    /// `texture_size` pixels.
+    ///
+    /// 返回像素尺寸为`texture_size`的纹理区域在区域内绘制的位置、尺寸和采样区域。
+    ///
+    /// The texture is centered in the box, parts larger than the box are cropped through the uv
+    /// region.
+    ///
+    /// 纹理在区域内居中，超出区域的部分通过采样区域裁剪。
+    pub fn apply(
+        self,
+        position: [f32; 2],
+        size: [f32; 2],
+        uv_rect: [f32; 4],
+        texture_size: [f32; 2],
+    ) -> ([f32; 2], [f32; 2], [f32; 4]) {
+        if texture_size[0] <= 0_f32 || texture_size[1] <= 0_f32 {
+            return (position, size, uv_rect);
+        };
+        let scale = [size[0] / texture_size[0], size[1] / texture_size[1]];
+        let scale = match self {
+            ScaleMode::Stretch => return (position, size, uv_rect),
+            ScaleMode::Contain => scale[0].min(scale[1]),
+            ScaleMode::Cover => scale[0].max(scale[1]),
+            ScaleMode::FitWidth => scale[0],
+            ScaleMode::FitHeight => scale[1],
+        };
+        let fit = |axis: usize| {
+            let scaled = texture_size[axis] * scale;
+            let visible = scaled.min(size[axis]);
+            // 超出区域的部分从两侧均匀裁剪
+            let crop = (uv_rect[axis + 2] - uv_rect[axis]) * (1_f32 - visible / scaled) / 2_f32;
+            (
+                position[axis] + (size[axis] - visible) / 2_f32,
+                visible,
+                uv_rect[axis] + crop,
+                uv_rect[axis + 2] - crop,
+            )
+        };
+        let (x, width, uv_left, uv_right) = fit(0);
+        let (y, height, uv_top, uv_bottom) = fit(1);
+        (
+            [x, y],
+            [width, height],
+            [uv_left, uv_top, uv_right, uv_bottom],
+        )
+    }
+}
+
+/// Filtering used when an image texture is uploaded.
+///
+/// 上传图像纹理时使用的过滤方式。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextureFilter {
+    /// Nearest-neighbor sampling, keeps pixel art crisp.
+    ///
+    /// 最近邻采样，使像素画保持清晰。
+    Nearest,
+    /// Linear sampling without mipmaps.
+    ///
+    /// 不带多级纹理的线性采样。
+    #[default]
+    Linear,
+    /// Linear sampling with generated mipmaps, smooths heavily downscaled images.
+    ///
+    /// 生成多级纹理的线性采样，使大幅缩小的图像更平滑。
+    LinearMipmap,
+}
+
+impl TextureFilter {
+    /// Converts the filter into egui texture options.
+    ///
+    /// 将过滤方式转换为egui纹理选项。
+    pub fn to_texture_options(self) -> TextureOptions {
+        match self {
+            TextureFilter::Nearest => TextureOptions::NEAREST,
+            TextureFilter::Linear => TextureOptions::LINEAR,
+            TextureFilter::LinearMipmap => {
+                TextureOptions::LINEAR.with_mipmap_mode(Some(EguiTextureFilter::Linear))
+            }
+        }
+    }
+}
+
+/// Frame animation played from a texture divided into a grid.
+///
+/// 从划分为网格的纹理中播放的帧动画。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct SpriteSheet {
+    /// Number of frames, frames are read row by row from the top-left cell.
+    ///
+    /// 帧数，从左上角的格子开始逐行读取。
+    pub frame_count: usize,
+
+    /// Number of columns in the grid.
+    ///
+    /// 网格的列数。
+    pub columns: usize,
+
+    /// Number of rows in the grid.
+    ///
+    /// 网格的行数。
+    pub rows: usize,
+
+    /// Playback speed in frames per second.
+    ///
+    /// 播放速度（每秒帧数）。
+    pub fps: f32,
+
+    /// Whether playback restarts after the last frame, otherwise it stops there.
+    ///
+    /// 播放到最后一帧后是否重新开始，否则停在最后一帧。
+    pub looping: bool,
+
+    /// Total runtime at which playback started, set when the image is first drawn.
+    ///
+    /// 开始播放时的总运行时间，在图像首次绘制时设置。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub start_time: Option<u128>,
+
+    /// Index of the frame currently displayed.
+    ///
+    /// 当前显示的帧索引。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub current_frame: usize,
+
+    /// Whether a non-looping playback reached its last frame.
+    ///
+    /// 非循环播放是否已到达最后一帧。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub finished: bool,
+}
+
+impl Default for SpriteSheet {
+    fn default() -> Self {
+        SpriteSheet {
+            frame_count: 1,
+            columns: 1,
+            rows: 1,
+            fps: 12_f32,
+            looping: true,
+            start_time: None,
+            current_frame: 0,
+            finished: false,
+        }
+    }
+}
+
+impl SpriteSheet {
+    #[inline]
+    pub fn frame_count(mut self, frame_count: usize) -> Self {
+        self.frame_count = frame_count;
+        self
+    }
+
+    #[inline]
+    pub fn grid(mut self, columns: usize, rows: usize) -> Self {
+        self.columns = columns;
+        self.rows = rows;
+        self
+    }
+
+    #[inline]
+    pub fn fps(mut self, fps: f32) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    #[inline]
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Advances playback to the given total runtime in milliseconds.
+    ///
+    /// 将播放推进到给定的总运行时间（毫秒）。
+    pub fn update(&mut self, total_time: u128) {
+        let start_time = *self.start_time.get_or_insert(total_time);
+        let frame_count = self.frame_count.clamp(1, (self.columns * self.rows).max(1));
+        let frame = (total_time.saturating_sub(start_time) as f32 / 1000_f32 * self.fps.max(0_f32))
+            as usize;
+        if self.looping {
+            self.current_frame = frame % frame_count;
+        } else {
+            self.current_frame = frame.min(frame_count - 1);
+            self.finished = frame >= frame_count - 1;
+        };
+    }
+
+    /// Returns the texture region of the current frame within the given region as
+    /// [min_x, min_y, max_x, max_y].
+    ///
+    /// 返回当前帧在给定区域内的纹理区域，格式为[min_x, min_y, max_x, max_y]。
+    pub fn frame_uv(&self, region: [f32; 4]) -> [f32; 4] {
+        let columns = self.columns.max(1);
+        let rows = self.rows.max(1);
+        let cell_size = [
+            (region[2] - region[0]) / columns as f32,
+            (region[3] - region[1]) / rows as f32,
+        ];
+        let column = (self.current_frame % columns) as f32;
+        let row = ((self.current_frame / columns).min(rows - 1)) as f32;
+        [
+            region[0] + cell_size[0] * column,
+            region[1] + cell_size[1] * row,
+            region[0] + cell_size[0] * (column + 1_f32),
+            region[1] + cell_size[1] * (row + 1_f32),
+        ]
+    }
+}
+
 /// Config options for custom rectangles.
 ///
 /// 矩形的可配置选项。
@@ -45,6 +588,11 @@ pub enum BorderKind {
 ///
 /// 该结构体包含用于创建和修改具有各种视觉属性的矩形UI元素的所有可配置属性。
 #[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct CustomRectConfig {
     /// Config for position, size, and layout of the rectangle.
     ///
@@ -66,10 +614,15 @@ pub struct CustomRectConfig {
     /// 如果为true，矩形忽略渲染层。
     pub ignore_render_layer: Option<bool>,
 
+    /// If true, the rectangle is drawn even when render culling finds it offscreen.
+    ///
+    /// 如果为true，即使渲染剔除判定矩形在屏幕外也会绘制。
+    pub always_draw: Option<bool>,
+
     /// Radius for rounded corners. Zero for sharp corners.
     ///
     /// 圆角半径。零表示直角。
-    pub rounding: Option<f32>,
+    pub rounding: Option<Rounding>,
 
     /// Fill color of the rectangle as [R, G, B].
     ///
@@ -121,6 +674,41 @@ pub struct CustomRectConfig {
     /// 边框相对于矩形边界的放置方式。
     pub border_kind: Option<BorderKind>,
 
+    /// Visual effect applied while the pointer is over the resource, None disables it.
+    ///
+    /// 指针悬停在资源上时应用的视觉效果，为None时不启用。
+    pub hover_effect: Option<Option<HoverEffect>>,
+
+    /// Rotation angle of the rectangle, in radians like the image rotation.
+    ///
+    /// 矩形的旋转角度，与图像旋转一样以弧度为单位。
+    ///
+    /// Only the painting is rotated, hover and click detection keep using the unrotated,
+    /// axis-aligned rectangle.
+    ///
+    /// 只有绘制结果会旋转，悬停和点击检测仍使用未旋转的轴对齐矩形。
+    pub rotate_angle: Option<f32>,
+
+    /// Center point for rotation, relative to the top-left corner of the rectangle.
+    ///
+    /// 旋转中心点，相对于矩形左上角。
+    pub rotate_center: Option<[f32; 2]>,
+
+    /// Fill of the rectangle, None fills it with `color` and `alpha`.
+    ///
+    /// 矩形的填充方式，为None时使用`color`和`alpha`填充。
+    pub fill: Option<Option<RectFill>>,
+
+    /// Drop shadow painted behind the rectangle, it is not drawn while the rectangle is rotated.
+    ///
+    /// 绘制在矩形后方的阴影，矩形旋转时不绘制。
+    pub shadow: Option<Option<ShadowConfig>>,
+
+    /// Pattern of the border, rounded corners of dashed and dotted borders stay solid.
+    ///
+    /// 边框的图案，虚线和点线边框的圆角部分保持实线。
+    pub border_style: Option<BorderStyle>,
+
     /// Key-value pairs for categorization and metadata.
     ///
     /// 用于分类和元数据的键值对标签。
@@ -156,6 +744,7 @@ impl CustomRectConfig {
             clip_rect: Some(resource.basic_front_resource_config.clip_rect),
             hidden: Some(resource.display_info.hidden),
             ignore_render_layer: Some(resource.display_info.ignore_render_layer),
+            always_draw: Some(resource.display_info.always_draw),
             rounding: Some(resource.rounding),
             color: Some(resource.color),
             alpha: Some(resource.alpha),
@@ -167,6 +756,12 @@ impl CustomRectConfig {
             overlay_border_color: Some(resource.overlay_border_color),
             overlay_border_alpha: Some(resource.overlay_border_alpha),
             border_kind: Some(resource.border_kind),
+            hover_effect: Some(resource.hover_effect),
+            rotate_angle: Some(resource.rotate_angle),
+            rotate_center: Some(resource.rotate_center),
+            fill: Some(resource.fill),
+            shadow: Some(resource.shadow),
+            border_style: Some(resource.border_style),
             tags: Some(resource.tags.clone()),
         }
     }
@@ -198,9 +793,21 @@ impl CustomRectConfig {
         self
     }
 
+    #[inline]
+    pub fn always_draw(mut self, always_draw: Option<bool>) -> Self {
+        self.always_draw = always_draw;
+        self
+    }
+
     #[inline]
     pub fn rounding(mut self, rounding: Option<f32>) -> Self {
-        self.rounding = rounding;
+        self.rounding = rounding.map(Rounding::Uniform);
+        self
+    }
+
+    #[inline]
+    pub fn corner_rounding(mut self, corner_rounding: Option<[f32; 4]>) -> Self {
+        self.rounding = corner_rounding.map(Rounding::Corners);
         self
     }
 
@@ -264,6 +871,42 @@ impl CustomRectConfig {
         self
     }
 
+    #[inline]
+    pub fn hover_effect(mut self, hover_effect: Option<Option<HoverEffect>>) -> Self {
+        self.hover_effect = hover_effect;
+        self
+    }
+
+    #[inline]
+    pub fn rotate_angle(mut self, rotate_angle: Option<f32>) -> Self {
+        self.rotate_angle = rotate_angle;
+        self
+    }
+
+    #[inline]
+    pub fn rotate_center(mut self, rotate_center: Option<[f32; 2]>) -> Self {
+        self.rotate_center = rotate_center;
+        self
+    }
+
+    #[inline]
+    pub fn fill(mut self, fill: Option<Option<RectFill>>) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    #[inline]
+    pub fn shadow(mut self, shadow: Option<Option<ShadowConfig>>) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    #[inline]
+    pub fn border_style(mut self, border_style: Option<BorderStyle>) -> Self {
+        self.border_style = border_style;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
         self.tags = tags;
@@ -275,6 +918,11 @@ impl CustomRectConfig {
 ///
 /// 自定义矩形资源，用于绘制具有各种视觉属性的矩形。
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct CustomRect {
     /// Config for basic front resource properties.
     ///
@@ -299,7 +947,7 @@ pub struct CustomRect {
     /// Radius for rounded corners.
     ///
     /// 圆角。
-    pub rounding: f32,
+    pub rounding: Rounding,
 
     /// Fill color of the rectangle as [R, G, B].
     ///
@@ -351,22 +999,63 @@ pub struct CustomRect {
     /// 边框相对于矩形边界的位置。
     pub border_kind: BorderKind,
 
-    /// Key-value pairs for categorization and metadata.
+    /// Visual effect applied while the pointer is over the resource, None disables it.
     ///
-    /// 用于分类和元数据的键值对标签。
-    pub tags: Vec<[String; 2]>,
-}
+    /// 指针悬停在资源上时应用的视觉效果，为None时不启用。
+    pub hover_effect: Option<HoverEffect>,
 
-impl RustConstructorResource for CustomRect {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
+    /// Progress of the hover effect transition (0.0-1.0).
+    ///
+    /// 悬停效果过渡的进度（0.0-1.0）。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub hover_progress: f32,
 
-    fn as_any_mut(&mut self) -> &mut dyn Any {
-        self
-    }
+    /// Rotation angle of the rectangle, in radians like the image rotation.
+    ///
+    /// 矩形的旋转角度，与图像旋转一样以弧度为单位。
+    ///
+    /// Only the painting is rotated, hover and click detection keep using the unrotated,
+    /// axis-aligned rectangle.
+    ///
+    /// 只有绘制结果会旋转，悬停和点击检测仍使用未旋转的轴对齐矩形。
+    pub rotate_angle: f32,
 
-    fn display_tags(&self) -> Vec<[String; 2]> {
+    /// Center point for rotation, relative to the top-left corner of the rectangle.
+    ///
+    /// 旋转中心点，相对于矩形左上角。
+    pub rotate_center: [f32; 2],
+
+    /// Fill of the rectangle, None fills it with `color` and `alpha`.
+    ///
+    /// 矩形的填充方式，为None时使用`color`和`alpha`填充。
+    pub fill: Option<RectFill>,
+
+    /// Drop shadow painted behind the rectangle, it is not drawn while the rectangle is rotated.
+    ///
+    /// 绘制在矩形后方的阴影，矩形旋转时不绘制。
+    pub shadow: Option<ShadowConfig>,
+
+    /// Pattern of the border, rounded corners of dashed and dotted borders stay solid.
+    ///
+    /// 边框的图案，虚线和点线边框的圆角部分保持实线。
+    pub border_style: BorderStyle,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for CustomRect {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
         self.tags.clone()
     }
 
@@ -522,7 +1211,7 @@ impl Default for CustomRect {
             position: [0_f32, 0_f32],
             size: [0_f32, 0_f32],
             display_info: DisplayInfo::default(),
-            rounding: 2_f32,
+            rounding: Rounding::Uniform(2_f32),
             color: [255, 255, 255],
             alpha: 255,
             overlay_border_color: [255, 255, 255],
@@ -533,6 +1222,13 @@ impl Default for CustomRect {
             overlay_color: [255, 255, 255],
             overlay_border_alpha: None,
             border_kind: BorderKind::default(),
+            hover_effect: None,
+            hover_progress: 0_f32,
+            rotate_angle: 0_f32,
+            rotate_center: [0_f32, 0_f32],
+            fill: None,
+            shadow: None,
+            border_style: BorderStyle::Solid,
             tags: Vec::new(),
         }
     }
@@ -552,6 +1248,9 @@ impl CustomRect {
         if let Some(ignore_render_layer) = config.ignore_render_layer {
             self.display_info.ignore_render_layer = ignore_render_layer;
         };
+        if let Some(always_draw) = config.always_draw {
+            self.display_info.always_draw = always_draw;
+        };
         if let Some(rounding) = config.rounding {
             self.rounding = rounding;
         };
@@ -585,6 +1284,24 @@ impl CustomRect {
         if let Some(border_kind) = config.border_kind {
             self.border_kind = border_kind;
         };
+        if let Some(hover_effect) = config.hover_effect {
+            self.hover_effect = hover_effect;
+        };
+        if let Some(rotate_angle) = config.rotate_angle {
+            self.rotate_angle = rotate_angle;
+        };
+        if let Some(rotate_center) = config.rotate_center {
+            self.rotate_center = rotate_center;
+        };
+        if let Some(fill) = config.fill {
+            self.fill = fill;
+        };
+        if let Some(shadow) = config.shadow {
+            self.shadow = shadow;
+        };
+        if let Some(border_style) = config.border_style {
+            self.border_style = border_style;
+        };
         if let Some(ref tags) = config.tags {
             self.tags = tags.clone();
         };
@@ -612,9 +1329,21 @@ impl CustomRect {
         self
     }
 
+    #[inline]
+    pub fn always_draw(mut self, always_draw: bool) -> Self {
+        self.display_info.always_draw = always_draw;
+        self
+    }
+
     #[inline]
     pub fn rounding(mut self, rounding: f32) -> Self {
-        self.rounding = rounding;
+        self.rounding = Rounding::Uniform(rounding);
+        self
+    }
+
+    #[inline]
+    pub fn corner_rounding(mut self, corner_rounding: [f32; 4]) -> Self {
+        self.rounding = Rounding::Corners(corner_rounding);
         self
     }
 
@@ -678,6 +1407,42 @@ impl CustomRect {
         self
     }
 
+    #[inline]
+    pub fn hover_effect(mut self, hover_effect: Option<HoverEffect>) -> Self {
+        self.hover_effect = hover_effect;
+        self
+    }
+
+    #[inline]
+    pub fn rotate_angle(mut self, rotate_angle: f32) -> Self {
+        self.rotate_angle = rotate_angle;
+        self
+    }
+
+    #[inline]
+    pub fn rotate_center(mut self, x: f32, y: f32) -> Self {
+        self.rotate_center = [x, y];
+        self
+    }
+
+    #[inline]
+    pub fn fill(mut self, fill: Option<RectFill>) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    #[inline]
+    pub fn shadow(mut self, shadow: Option<ShadowConfig>) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    #[inline]
+    pub fn border_style(mut self, border_style: BorderStyle) -> Self {
+        self.border_style = border_style;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
         if replace {
@@ -694,77 +1459,14 @@ impl CustomRect {
     }
 }
 
-/// Wrapper for TextureHandle that supports Debug trait derivation.
-///
-/// 支持Debug特征派生的TextureHandle包装器。
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct DebugTextureHandle {
-    pub path: String,
-    pub texture_handle: TextureHandle,
-}
-
-impl Debug for DebugTextureHandle {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        // 只输出类型信息，不输出具体纹理数据
-        f.debug_struct("DebugTextureHandle").finish()
-    }
-}
-
-/// Request sent to the background worker thread to load an image from disk.
-///
-/// 发送到后台工作线程的图片加载请求。
-/// Result returned from the background worker thread after loading an image.
-///
-/// 后台工作线程完成图片加载后返回的结果。
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
-pub struct LoadedImageData {
-    /// The path of the image file.
-    ///
-    /// 图片的路径。
-    pub path: String,
-
-    /// Decoded image data ready for texture upload on the main thread.
-    ///
-    /// 已解码的图像数据，可在主线程直接上传为纹理。
-    pub color_image: ColorImage,
-}
-
-/// Manages the background image loading infrastructure.
-///
-/// 管理后台图片加载基础设施。
-#[derive(Debug, Default, Clone)]
-pub struct ImageLoader {
-    /// Completed loads from worker threads, keyed by resource name.
-    /// Each frame, completed loads are drained to create egui textures.
-    ///
-    /// 工作线程完成的加载结果，按资源名称索引。每帧消耗以创建 egui 纹理。
-    pub completed: Arc<Mutex<HashMap<String, LoadedImageData>>>,
-}
-
-/// Methods for loading images into the resource.
-///
-/// 将图像加载到资源中的方法。
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ImageLoadMethod {
-    /// Load image from a file path.
-    ///
-    /// 从文件路径加载图像。
-    ByPath((String, [bool; 2])),
-
-    /// Use an existing TextureHandle for the image.
-    ///
-    /// 使用现有的TextureHandle作为图像。
-    ByTexture(DebugTextureHandle),
-}
-
-/// Config options for image resources.
+/// Config options for polygons.
 ///
-/// 图像资源的配置选项。
-#[derive(Debug, Default, Clone, PartialEq)]
-pub struct ImageConfig {
-    /// Config for position, size, and layout.
+/// 多边形的可配置选项。
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+pub struct PolygonConfig {
+    /// Config for position, size, and layout of the polygon.
     ///
-    /// 位置、尺寸和布局配置。
+    /// 多边形的位置、尺寸和布局配置。
     pub position_size_config: Option<PositionSizeConfig>,
 
     /// Optional clipping rectangle that defines the visible area.
@@ -772,55 +1474,50 @@ pub struct ImageConfig {
     /// 定义可见区域的可选裁剪矩形。
     pub clip_rect: Option<Option<PositionSizeConfig>>,
 
-    /// Controls whether the image is visible or hidden.
+    /// Controls whether the polygon is visible or hidden.
     ///
-    /// 控制图像是否可见或隐藏。
+    /// 控制多边形是否可见或隐藏。
     pub hidden: Option<bool>,
 
-    /// If true, the image ignores render layer.
+    /// If true, the polygon ignores render layer.
     ///
-    /// 如果为true，图像忽略渲染层。
+    /// 如果为true，多边形忽略渲染层。
     pub ignore_render_layer: Option<bool>,
 
-    /// Opacity of the image (0-255).
-    ///
-    /// 图像的不透明度（0-255）。
-    pub alpha: Option<u8>,
-
-    /// Color overlay applied to the image as [R, G, B].
+    /// If true, the polygon is drawn even when render culling finds it offscreen.
     ///
-    /// 应用于图像的色彩覆盖，格式为[R, G, B]。
-    pub overlay_color: Option<[u8; 3]>,
+    /// 如果为true，即使渲染剔除判定多边形在屏幕外也会绘制。
+    pub always_draw: Option<bool>,
 
-    /// Opacity of the overlay (0-255).
+    /// Vertices of the polygon in local coordinates.
     ///
-    /// 覆盖层的不透明度（0-255）。
-    pub overlay_alpha: Option<u8>,
+    /// 多边形在局部坐标中的顶点。
+    pub points: Option<Vec<[f32; 2]>>,
 
-    /// Background color behind the image as [R, G, B].
+    /// Fill color of the polygon as [R, G, B].
     ///
-    /// 图像背后的背景颜色，格式为[R, G, B]。
-    pub background_color: Option<[u8; 3]>,
+    /// 多边形的填充颜色，格式为[R, G, B]。
+    pub fill_color: Option<[u8; 3]>,
 
-    /// Opacity of the background (0-255).
+    /// Opacity of the polygon (0-255).
     ///
-    /// 背景的不透明度（0-255）。
-    pub background_alpha: Option<u8>,
+    /// 多边形的不透明度（0-255）。
+    pub alpha: Option<u8>,
 
-    /// Rotation angle of the image in degrees.
+    /// Width of the border.
     ///
-    /// 图像的旋转角度（度）。
-    pub rotate_angle: Option<f32>,
+    /// 边框宽度。
+    pub border_width: Option<f32>,
 
-    /// Center point for rotation, compare it with the actual size to obtain as [width, height].
+    /// Color of the border as [R, G, B].
     ///
-    /// 旋转中心点，通过与实际大小的比得出，为[width, height]。
-    pub rotate_center: Option<[f32; 2]>,
+    /// 边框颜色，格式为[R, G, B]。
+    pub border_color: Option<[u8; 3]>,
 
-    /// Method used to load the image.
+    /// Opacity of the border (0-255).
     ///
-    /// 用于加载图像的方法。
-    pub image_load_method: Option<ImageLoadMethod>,
+    /// 边框的不透明度（0-255）。
+    pub border_alpha: Option<u8>,
 
     /// Key-value pairs for categorization and metadata.
     ///
@@ -828,7 +1525,7 @@ pub struct ImageConfig {
     pub tags: Option<Vec<[String; 2]>>,
 }
 
-impl Config for ImageConfig {
+impl Config for PolygonConfig {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -838,33 +1535,32 @@ impl Config for ImageConfig {
     }
 
     fn convert_to_resource(&self) -> Box<dyn FrontResource> {
-        Box::new(Image::default().from_config(self))
+        Box::new(Polygon::default().from_config(self))
     }
 
     fn convert_from_resource(&self, resource: Box<dyn FrontResource>) -> Option<Box<dyn Config>> {
-        if let Some(resource) = resource.as_any().downcast_ref::<Image>() {
-            Some(Box::new(ImageConfig::from_resource(resource)))
+        if let Some(resource) = resource.as_any().downcast_ref::<Polygon>() {
+            Some(Box::new(PolygonConfig::from_resource(resource)))
         } else {
             None
         }
     }
 }
 
-impl ImageConfig {
-    pub fn from_resource(resource: &Image) -> Self {
+impl PolygonConfig {
+    pub fn from_resource(resource: &Polygon) -> Self {
         Self {
             position_size_config: Some(resource.basic_front_resource_config.position_size_config),
             clip_rect: Some(resource.basic_front_resource_config.clip_rect),
             hidden: Some(resource.display_info.hidden),
             ignore_render_layer: Some(resource.display_info.ignore_render_layer),
+            always_draw: Some(resource.display_info.always_draw),
+            points: Some(resource.points.clone()),
+            fill_color: Some(resource.fill_color),
             alpha: Some(resource.alpha),
-            overlay_color: Some(resource.overlay_color),
-            overlay_alpha: Some(resource.overlay_alpha),
-            background_color: Some(resource.background_color),
-            background_alpha: Some(resource.background_alpha),
-            rotate_angle: Some(resource.rotate_angle),
-            rotate_center: Some(resource.rotate_center),
-            image_load_method: Some(resource.image_load_method.clone()),
+            border_width: Some(resource.border_width),
+            border_color: Some(resource.border_color),
+            border_alpha: Some(resource.border_alpha),
             tags: Some(resource.tags.clone()),
         }
     }
@@ -897,50 +1593,44 @@ impl ImageConfig {
     }
 
     #[inline]
-    pub fn alpha(mut self, alpha: Option<u8>) -> Self {
-        self.alpha = alpha;
-        self
-    }
-
-    #[inline]
-    pub fn overlay_color(mut self, overlay_color: Option<[u8; 3]>) -> Self {
-        self.overlay_color = overlay_color;
+    pub fn always_draw(mut self, always_draw: Option<bool>) -> Self {
+        self.always_draw = always_draw;
         self
     }
 
     #[inline]
-    pub fn overlay_alpha(mut self, overlay_alpha: Option<u8>) -> Self {
-        self.overlay_alpha = overlay_alpha;
+    pub fn points(mut self, points: Option<Vec<[f32; 2]>>) -> Self {
+        self.points = points;
         self
     }
 
     #[inline]
-    pub fn background_color(mut self, background_color: Option<[u8; 3]>) -> Self {
-        self.background_color = background_color;
+    pub fn fill_color(mut self, fill_color: Option<[u8; 3]>) -> Self {
+        self.fill_color = fill_color;
         self
     }
 
     #[inline]
-    pub fn background_alpha(mut self, background_alpha: Option<u8>) -> Self {
-        self.background_alpha = background_alpha;
+    pub fn alpha(mut self, alpha: Option<u8>) -> Self {
+        self.alpha = alpha;
         self
     }
 
     #[inline]
-    pub fn rotate_angle(mut self, rotate_angle: Option<f32>) -> Self {
-        self.rotate_angle = rotate_angle;
+    pub fn border_width(mut self, border_width: Option<f32>) -> Self {
+        self.border_width = border_width;
         self
     }
 
     #[inline]
-    pub fn rotate_center(mut self, rotate_center: Option<[f32; 2]>) -> Self {
-        self.rotate_center = rotate_center;
+    pub fn border_color(mut self, border_color: Option<[u8; 3]>) -> Self {
+        self.border_color = border_color;
         self
     }
 
     #[inline]
-    pub fn image_load_method(mut self, image_load_method: Option<ImageLoadMethod>) -> Self {
-        self.image_load_method = image_load_method;
+    pub fn border_alpha(mut self, border_alpha: Option<u8>) -> Self {
+        self.border_alpha = border_alpha;
         self
     }
 
@@ -951,24 +1641,29 @@ impl ImageConfig {
     }
 }
 
-/// Image resource for displaying graphical content in the GUI.
+/// Polygon resource for drawing triangles, arrows and other convex or concave shapes.
 ///
-/// 用于在GUI中显示图形内容的图像资源。
-#[derive(Debug, Clone, PartialEq)]
-pub struct Image {
+/// 多边形资源，用于绘制三角形、箭头以及其他凸或凹的形状。
+///
+/// The bounding box of `points` is stretched over the position and size given by the
+/// position config, so the points can use any local unit.
+///
+/// `points`的包围盒会被拉伸到位置配置给出的位置和尺寸上，因此顶点可以使用任意局部单位。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Polygon {
     /// Config for basic front resource properties.
     ///
     /// 基本前端资源属性配置。
     pub basic_front_resource_config: BasicFrontResourceConfig,
 
-    /// Current display position of the image as [x, y].
+    /// Current display position of the polygon's bounding box as [x, y].
     ///
-    /// 图像的当前显示位置，坐标为[x, y]。
+    /// 多边形包围盒的当前显示位置，为[x, y]。
     pub position: [f32; 2],
 
-    /// Current display size of the image as [width, height].
+    /// Current display size of the polygon's bounding box as [width, height].
     ///
-    /// 图像的当前显示尺寸，为[width, height]。
+    /// 多边形包围盒的当前显示尺寸，为[width, height]。
     pub size: [f32; 2],
 
     /// Display info controlling visibility and rendering.
@@ -976,60 +1671,35 @@ pub struct Image {
     /// 显示信息，控制可见性和渲染。
     pub display_info: DisplayInfo,
 
-    /// Handle to the loaded texture, if available.
+    /// Vertices of the polygon in local coordinates, in drawing order.
     ///
-    /// 已加载纹理的句柄（如果可用）。
-    pub texture: Option<DebugTextureHandle>,
+    /// 多边形在局部坐标中的顶点，按绘制顺序排列。
+    pub points: Vec<[f32; 2]>,
 
-    /// Opacity of the image (0-255).
+    /// Fill color of the polygon as [R, G, B].
     ///
-    /// 图像的不透明度（0-255）。
-    pub alpha: u8,
+    /// 多边形的填充颜色，为[R, G, B]。
+    pub fill_color: [u8; 3],
 
-    /// Color overlay applied to the image as [R, G, B].
+    /// Opacity of the polygon (0-255).
     ///
-    /// 应用于图像的色彩覆盖，格式为[R, G, B]。
-    pub overlay_color: [u8; 3],
+    /// 多边形的不透明度（0-255）。
+    pub alpha: u8,
 
-    /// Opacity of the overlay (0-255).
+    /// Width of the border.
     ///
-    /// 覆盖层的不透明度（0-255）。
-    pub overlay_alpha: u8,
+    /// 边框宽度。
+    pub border_width: f32,
 
-    /// Background color behind the image as [R, G, B].
-    ///
-    /// 图像背后的背景颜色，格式为[R, G, B]。
-    pub background_color: [u8; 3],
-
-    /// Opacity of the background (0-255).
-    ///
-    /// 背景的不透明度（0-255）。
-    pub background_alpha: u8,
-
-    /// Rotation angle of the image in degrees.
-    ///
-    /// 图像的旋转角度（度）。
-    pub rotate_angle: f32,
-
-    /// Center point for rotation, compare it with the actual size to obtain as [width, height].
-    ///
-    /// 旋转中心点，通过与实际大小的比得出，为[width, height]。
-    pub rotate_center: [f32; 2],
-
-    /// Method used to load the image.
-    ///
-    /// 用于加载图像的方法。
-    pub image_load_method: ImageLoadMethod,
-
-    /// A storage list of all loaded textures.
+    /// Color of the border as [R, G, B].
     ///
-    /// 所有已加载纹理的存储列表。
-    pub texture_list: Vec<DebugTextureHandle>,
+    /// 边框颜色，为[R, G, B]。
+    pub border_color: [u8; 3],
 
-    /// The path for loading the image in the previous frame.
+    /// Opacity of the border (0-255).
     ///
-    /// 上一帧加载图片的路径。
-    pub last_frame_path: String,
+    /// 边框的不透明度（0-255）。
+    pub border_alpha: u8,
 
     /// Key-value pairs for categorization and metadata.
     ///
@@ -1037,7 +1707,7 @@ pub struct Image {
     pub tags: Vec<[String; 2]>,
 }
 
-impl RustConstructorResource for Image {
+impl RustConstructorResource for Polygon {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -1088,13 +1758,13 @@ impl RustConstructorResource for Image {
     }
 }
 
-impl FrontResource for Image {
+impl FrontResource for Polygon {
     fn convert_to_config(&self) -> Box<dyn Config> {
-        Box::new(ImageConfig::from_resource(self))
+        Box::new(PolygonConfig::from_resource(self))
     }
 
     fn convert_from_config(&mut self, config: Box<dyn Config>) -> Option<Box<dyn FrontResource>> {
-        if let Some(config) = config.as_any().downcast_ref::<ImageConfig>() {
+        if let Some(config) = config.as_any().downcast_ref::<PolygonConfig>() {
             Some(Box::new(self.clone().from_config(config)))
         } else {
             None
@@ -1126,7 +1796,7 @@ impl FrontResource for Image {
     }
 }
 
-impl BasicFrontResource for Image {
+impl BasicFrontResource for Polygon {
     fn display_basic_front_resource_config(&self) -> BasicFrontResourceConfig {
         self.basic_front_resource_config.clone()
     }
@@ -1178,48 +1848,43 @@ impl BasicFrontResource for Image {
         Box::new(self.clone())
     }
 
-    fn convert_to_original_dyn(&self) -> &dyn RustConstructorResource {
+    fn convert_to_front_dyn(&self) -> &dyn FrontResource {
         self
     }
 
-    fn convert_to_original_dyn_mut(&mut self) -> &mut dyn RustConstructorResource {
+    fn convert_to_front_dyn_mut(&mut self) -> &mut dyn FrontResource {
         self
     }
 
-    fn convert_to_front_dyn(&self) -> &dyn FrontResource {
+    fn convert_to_original_dyn(&self) -> &dyn RustConstructorResource {
         self
     }
 
-    fn convert_to_front_dyn_mut(&mut self) -> &mut dyn FrontResource {
+    fn convert_to_original_dyn_mut(&mut self) -> &mut dyn RustConstructorResource {
         self
     }
 }
 
-impl Default for Image {
+impl Default for Polygon {
     fn default() -> Self {
         Self {
             basic_front_resource_config: BasicFrontResourceConfig::default(),
             position: [0_f32, 0_f32],
             size: [0_f32, 0_f32],
             display_info: DisplayInfo::default(),
-            texture: None,
+            points: Vec::new(),
+            fill_color: [255, 255, 255],
             alpha: 255,
-            overlay_color: [255, 255, 255],
-            overlay_alpha: 255,
-            background_color: [0, 0, 0],
-            background_alpha: 0,
-            rotate_angle: 0_f32,
-            rotate_center: [0_f32, 0_f32],
-            image_load_method: ImageLoadMethod::ByPath((String::new(), [false, false])),
-            texture_list: Vec::new(),
-            last_frame_path: String::new(),
+            border_width: 0_f32,
+            border_color: [0, 0, 0],
+            border_alpha: 255,
             tags: Vec::new(),
         }
     }
 }
 
-impl Image {
-    pub fn from_config(mut self, config: &ImageConfig) -> Self {
+impl Polygon {
+    pub fn from_config(mut self, config: &PolygonConfig) -> Self {
         if let Some(position_size_config) = config.position_size_config {
             self.basic_front_resource_config.position_size_config = position_size_config;
         };
@@ -1232,29 +1897,26 @@ impl Image {
         if let Some(ignore_render_layer) = config.ignore_render_layer {
             self.display_info.ignore_render_layer = ignore_render_layer;
         };
-        if let Some(alpha) = config.alpha {
-            self.alpha = alpha;
-        };
-        if let Some(overlay_color) = config.overlay_color {
-            self.overlay_color = overlay_color;
+        if let Some(always_draw) = config.always_draw {
+            self.display_info.always_draw = always_draw;
         };
-        if let Some(overlay_alpha) = config.overlay_alpha {
-            self.overlay_alpha = overlay_alpha;
+        if let Some(ref points) = config.points {
+            self.points = points.clone();
         };
-        if let Some(background_color) = config.background_color {
-            self.background_color = background_color;
+        if let Some(fill_color) = config.fill_color {
+            self.fill_color = fill_color;
         };
-        if let Some(background_alpha) = config.background_alpha {
-            self.background_alpha = background_alpha;
+        if let Some(alpha) = config.alpha {
+            self.alpha = alpha;
         };
-        if let Some(rotate_angle) = config.rotate_angle {
-            self.rotate_angle = rotate_angle;
+        if let Some(border_width) = config.border_width {
+            self.border_width = border_width;
         };
-        if let Some(rotate_center) = config.rotate_center {
-            self.rotate_center = rotate_center;
+        if let Some(border_color) = config.border_color {
+            self.border_color = border_color;
         };
-        if let Some(ref image_load_method) = config.image_load_method {
-            self.image_load_method = image_load_method.clone();
+        if let Some(border_alpha) = config.border_alpha {
+            self.border_alpha = border_alpha;
         };
         if let Some(ref tags) = config.tags {
             self.tags = tags.clone();
@@ -1283,6 +1945,24 @@ impl Image {
         self
     }
 
+    #[inline]
+    pub fn always_draw(mut self, always_draw: bool) -> Self {
+        self.display_info.always_draw = always_draw;
+        self
+    }
+
+    #[inline]
+    pub fn points(mut self, points: &[[f32; 2]]) -> Self {
+        self.points = points.to_vec();
+        self
+    }
+
+    #[inline]
+    pub fn fill_color(mut self, r: u8, g: u8, b: u8) -> Self {
+        self.fill_color = [r, g, b];
+        self
+    }
+
     #[inline]
     pub fn alpha(mut self, alpha: u8) -> Self {
         self.alpha = alpha;
@@ -1290,82 +1970,1712 @@ impl Image {
     }
 
     #[inline]
-    pub fn overlay_color(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.overlay_color = [r, g, b];
+    pub fn border_width(mut self, border_width: f32) -> Self {
+        self.border_width = border_width;
         self
     }
 
     #[inline]
-    pub fn overlay_alpha(mut self, overlay_alpha: u8) -> Self {
-        self.overlay_alpha = overlay_alpha;
+    pub fn border_color(mut self, r: u8, g: u8, b: u8) -> Self {
+        self.border_color = [r, g, b];
         self
     }
 
     #[inline]
-    pub fn background_color(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.background_color = [r, g, b];
+    pub fn border_alpha(mut self, border_alpha: u8) -> Self {
+        self.border_alpha = border_alpha;
         self
     }
 
     #[inline]
-    pub fn background_alpha(mut self, background_alpha: u8) -> Self {
-        self.background_alpha = background_alpha;
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
         self
     }
 
+    /// Maps the local points onto the current position and size.
+    ///
+    /// 将局部坐标中的顶点映射到当前的位置和尺寸上。
+    pub fn screen_points(&self) -> Vec<[f32; 2]> {
+        if self.points.is_empty() {
+            return Vec::new();
+        };
+        let mut min = self.points[0];
+        let mut max = self.points[0];
+        for point in &self.points {
+            min = [min[0].min(point[0]), min[1].min(point[1])];
+            max = [max[0].max(point[0]), max[1].max(point[1])];
+        }
+        // 包围盒某一边长度为0时该方向不缩放
+        let scale = [
+            if max[0] > min[0] {
+                self.size[0] / (max[0] - min[0])
+            } else {
+                1_f32
+            },
+            if max[1] > min[1] {
+                self.size[1] / (max[1] - min[1])
+            } else {
+                1_f32
+            },
+        ];
+        self.points
+            .iter()
+            .map(|point| {
+                [
+                    self.position[0] + (point[0] - min[0]) * scale[0],
+                    self.position[1] + (point[1] - min[1]) * scale[1],
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Wrapper for TextureHandle that supports Debug trait derivation.
+///
+/// 支持Debug特征派生的TextureHandle包装器。
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DebugTextureHandle {
+    pub path: String,
+    pub texture_handle: TextureHandle,
+}
+
+impl Debug for DebugTextureHandle {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        // 只输出类型信息，不输出具体纹理数据
+        f.debug_struct("DebugTextureHandle").finish()
+    }
+}
+
+/// Frames of an animated GIF played by swapping the texture of an image.
+///
+/// 通过切换图像纹理播放的GIF动画帧。
+#[derive(Debug, Clone, PartialEq)]
+pub struct GifAnimation {
+    /// Texture of every frame.
+    ///
+    /// 每一帧的纹理。
+    pub frames: Vec<DebugTextureHandle>,
+
+    /// Display time of every frame in milliseconds.
+    ///
+    /// 每一帧的显示时间（毫秒）。
+    pub delays: Vec<u128>,
+
+    /// Whether playback restarts after the last frame, otherwise it stops there.
+    ///
+    /// 播放到最后一帧后是否重新开始，否则停在最后一帧。
+    pub looping: bool,
+
+    /// Whether the animation is playing.
+    ///
+    /// 动画是否正在播放。
+    pub playing: bool,
+
+    /// Total runtime at which playback was last started or resumed.
+    ///
+    /// 上次开始或恢复播放时的总运行时间。
+    pub start_time: Option<u128>,
+
+    /// Time played before the last pause in milliseconds.
+    ///
+    /// 上次暂停前已播放的时间（毫秒）。
+    pub accumulated_time: u128,
+
+    /// Index of the frame currently displayed.
+    ///
+    /// 当前显示的帧索引。
+    pub current_frame: usize,
+
+    /// Whether a non-looping playback reached its last frame.
+    ///
+    /// 非循环播放是否已到达最后一帧。
+    pub finished: bool,
+}
+
+impl Default for GifAnimation {
+    fn default() -> Self {
+        GifAnimation {
+            frames: Vec::new(),
+            delays: Vec::new(),
+            looping: true,
+            playing: true,
+            start_time: None,
+            accumulated_time: 0,
+            current_frame: 0,
+            finished: false,
+        }
+    }
+}
+
+impl GifAnimation {
     #[inline]
-    pub fn rotate_angle(mut self, rotate_angle: f32) -> Self {
-        self.rotate_angle = rotate_angle;
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
         self
     }
 
     #[inline]
-    pub fn rotate_center(mut self, x: f32, y: f32) -> Self {
-        self.rotate_center = [x, y];
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playing = playing;
         self
     }
 
-    #[inline]
-    pub fn image_load_method(mut self, image_load_method: &ImageLoadMethod) -> Self {
-        self.image_load_method = image_load_method.clone();
+    /// Returns the time played at the given total runtime in milliseconds.
+    ///
+    /// 返回在给定总运行时间（毫秒）时已播放的时间。
+    pub fn played_time(&self, total_time: u128) -> u128 {
+        self.accumulated_time
+            + match self.start_time {
+                Some(start_time) if self.playing => total_time.saturating_sub(start_time),
+                _ => 0,
+            }
+    }
+
+    /// Advances playback to the given total runtime in milliseconds.
+    ///
+    /// 将播放推进到给定的总运行时间（毫秒）。
+    pub fn update(&mut self, total_time: u128) {
+        if self.frames.is_empty() {
+            return;
+        };
+        if self.playing {
+            self.start_time.get_or_insert(total_time);
+        };
+        let duration: u128 = self.delays.iter().sum::<u128>().max(1);
+        let played_time = self.played_time(total_time);
+        let mut remaining = if self.looping {
+            played_time % duration
+        } else {
+            played_time.min(duration - 1)
+        };
+        self.finished = !self.looping && played_time >= duration;
+        // 按每帧各自的延迟找到当前帧
+        self.current_frame = self.frames.len() - 1;
+        for (index, delay) in self.delays.iter().enumerate() {
+            if remaining < *delay {
+                self.current_frame = index.min(self.frames.len() - 1);
+                break;
+            };
+            remaining -= delay;
+        }
+    }
+
+    /// Pauses playback at the given total runtime, the current frame stays displayed.
+    ///
+    /// 在给定的总运行时间暂停播放，保持显示当前帧。
+    pub fn pause(&mut self, total_time: u128) {
+        if self.playing {
+            self.accumulated_time = self.played_time(total_time);
+            self.start_time = None;
+            self.playing = false;
+        };
+    }
+
+    /// Resumes playback from the current frame.
+    ///
+    /// 从当前帧恢复播放。
+    pub fn play(&mut self) {
+        if !self.playing {
+            self.start_time = None;
+            self.playing = true;
+        };
+    }
+
+    /// Jumps back to the first frame.
+    ///
+    /// 跳回第一帧。
+    pub fn reset(&mut self) {
+        self.start_time = None;
+        self.accumulated_time = 0;
+        self.current_frame = 0;
+        self.finished = false;
+    }
+}
+
+/// Request sent to the background worker thread to load an image from disk.
+///
+/// 发送到后台工作线程的图片加载请求。
+/// Result returned from the background worker thread after loading an image.
+///
+/// 后台工作线程完成图片加载后返回的结果。
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoadedImageData {
+    /// The path of the image file.
+    ///
+    /// 图片的路径。
+    pub path: String,
+
+    /// Decoded image data ready for texture upload on the main thread.
+    ///
+    /// 已解码的图像数据，可在主线程直接上传为纹理。
+    pub color_image: ColorImage,
+}
+
+/// Manages the background image loading infrastructure.
+///
+/// 管理后台图片加载基础设施。
+#[derive(Debug, Default, Clone)]
+pub struct ImageLoader {
+    /// Completed loads from worker threads, keyed by resource name.
+    /// Each frame, completed loads are drained to create egui textures.
+    ///
+    /// 工作线程完成的加载结果，按资源名称索引。每帧消耗以创建 egui 纹理。
+    pub completed: Arc<Mutex<HashMap<String, LoadedImageData>>>,
+
+    /// Failed loads from worker threads as (path, error message), keyed by resource name.
+    ///
+    /// 工作线程加载失败的结果，格式为(路径, 错误信息)，按资源名称索引。
+    pub failed: Arc<Mutex<HashMap<String, (String, String)>>>,
+}
+
+/// Loading progress of an image texture.
+///
+/// 图像纹理的加载进度。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ImageLoadState {
+    /// The texture is being read and decoded in the background.
+    ///
+    /// 纹理正在后台读取和解码。
+    Loading,
+    /// The texture is uploaded and drawn.
+    ///
+    /// 纹理已上传并绘制。
+    Ready,
+    /// Loading failed with the given error message.
+    ///
+    /// 加载失败，附带错误信息。
+    Failed(String),
+}
+
+/// Methods for loading images into the resource.
+///
+/// 将图像加载到资源中的方法。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImageLoadMethod {
+    /// Load image from a file path.
+    ///
+    /// 从文件路径加载图像。
+    ByPath((String, [bool; 2])),
+
+    /// Use an existing TextureHandle for the image.
+    ///
+    /// 使用现有的TextureHandle作为图像。
+    ByTexture(DebugTextureHandle),
+}
+
+/// Textures cannot be serialized, `ByTexture` is written as the bare variant name and
+/// rejected when deserializing.
+///
+/// 纹理无法序列化，`ByTexture`只写入变体名称，反序列化时会被拒绝。
+#[cfg(feature = "serde")]
+impl serde::Serialize for ImageLoadMethod {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ImageLoadMethod::ByPath(path) => {
+                serializer.serialize_newtype_variant("ImageLoadMethod", 0, "ByPath", path)
+            }
+            ImageLoadMethod::ByTexture(_) => {
+                serializer.serialize_unit_variant("ImageLoadMethod", 1, "ByTexture")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ImageLoadMethod {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        enum StoredImageLoadMethod {
+            ByPath((String, [bool; 2])),
+            ByTexture,
+        }
+        match StoredImageLoadMethod::deserialize(deserializer)? {
+            StoredImageLoadMethod::ByPath(path) => Ok(ImageLoadMethod::ByPath(path)),
+            StoredImageLoadMethod::ByTexture => Err(serde::de::Error::custom(
+                "an image texture cannot be deserialized",
+            )),
+        }
+    }
+}
+
+/// Where the data of an SVG image comes from.
+///
+/// SVG图像数据的来源。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SvgSource {
+    /// Read the SVG from a file path.
+    ///
+    /// 从文件路径读取SVG。
+    Path(String),
+
+    /// Use SVG data already in memory.
+    ///
+    /// 使用已在内存中的SVG数据。
+    Bytes(Vec<u8>),
+}
+
+/// Config options for image resources.
+///
+/// 图像资源的配置选项。
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ImageConfig {
+    /// Config for position, size, and layout.
+    ///
+    /// 位置、尺寸和布局配置。
+    pub position_size_config: Option<PositionSizeConfig>,
+
+    /// Optional clipping rectangle that defines the visible area.
+    ///
+    /// 定义可见区域的可选裁剪矩形。
+    pub clip_rect: Option<Option<PositionSizeConfig>>,
+
+    /// Controls whether the image is visible or hidden.
+    ///
+    /// 控制图像是否可见或隐藏。
+    pub hidden: Option<bool>,
+
+    /// If true, the image ignores render layer.
+    ///
+    /// 如果为true，图像忽略渲染层。
+    pub ignore_render_layer: Option<bool>,
+
+    /// If true, the image is drawn even when render culling finds it offscreen.
+    ///
+    /// 如果为true，即使渲染剔除判定图像在屏幕外也会绘制。
+    pub always_draw: Option<bool>,
+
+    /// Opacity of the image (0-255).
+    ///
+    /// 图像的不透明度（0-255）。
+    pub alpha: Option<u8>,
+
+    /// Color overlay applied to the image as [R, G, B].
+    ///
+    /// 应用于图像的色彩覆盖，格式为[R, G, B]。
+    pub overlay_color: Option<[u8; 3]>,
+
+    /// Opacity of the overlay (0-255).
+    ///
+    /// 覆盖层的不透明度（0-255）。
+    pub overlay_alpha: Option<u8>,
+
+    /// Background color behind the image as [R, G, B].
+    ///
+    /// 图像背后的背景颜色，格式为[R, G, B]。
+    pub background_color: Option<[u8; 3]>,
+
+    /// Opacity of the background (0-255).
+    ///
+    /// 背景的不透明度（0-255）。
+    pub background_alpha: Option<u8>,
+
+    /// Rotation angle of the image in degrees.
+    ///
+    /// 图像的旋转角度（度）。
+    pub rotate_angle: Option<f32>,
+
+    /// Center point for rotation, compare it with the actual size to obtain as [width, height].
+    ///
+    /// 旋转中心点，通过与实际大小的比得出，为[width, height]。
+    pub rotate_center: Option<[f32; 2]>,
+
+    /// Method used to load the image.
+    ///
+    /// 用于加载图像的方法。
+    pub image_load_method: Option<ImageLoadMethod>,
+
+    /// Visual effect applied while the pointer is over the resource, None disables it.
+    ///
+    /// 指针悬停在资源上时应用的视觉效果，为None时不启用。
+    pub hover_effect: Option<Option<HoverEffect>>,
+
+    /// Normalized texture region to sample as [min_x, min_y, max_x, max_y], None uses the whole texture.
+    ///
+    /// 要采样的归一化纹理区域，格式为[min_x, min_y, max_x, max_y]，为None时使用整张纹理。
+    pub uv_rect: Option<Option<[f32; 4]>>,
+
+    /// Filtering used when the texture is uploaded, textures already cached keep their filter.
+    ///
+    /// 上传纹理时使用的过滤方式，已缓存的纹理保持原有过滤方式。
+    pub texture_filter: Option<TextureFilter>,
+
+    /// Nine-patch insets in texture pixels as [left, right, top, bottom], None stretches the whole image.
+    ///
+    /// 以纹理像素为单位的九宫格内边距，格式为[左, 右, 上, 下]，为None时拉伸整张图片。
+    pub nine_patch: Option<Option<[f32; 4]>>,
+
+    /// Frame animation played from the texture, None draws the texture as a single frame.
+    ///
+    /// 从纹理播放的帧动画，为None时将纹理作为单帧绘制。
+    pub sprite_sheet: Option<Option<SpriteSheet>>,
+
+    /// How the texture is scaled into the image's box, ignored in nine-patch mode.
+    ///
+    /// 纹理缩放到图像区域内的方式，九宫格模式下忽略。
+    pub scale_mode: Option<ScaleMode>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Option<Vec<[String; 2]>>,
+}
+
+impl Config for ImageConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn convert_to_resource(&self) -> Box<dyn FrontResource> {
+        Box::new(Image::default().from_config(self))
+    }
+
+    fn convert_from_resource(&self, resource: Box<dyn FrontResource>) -> Option<Box<dyn Config>> {
+        if let Some(resource) = resource.as_any().downcast_ref::<Image>() {
+            Some(Box::new(ImageConfig::from_resource(resource)))
+        } else {
+            None
+        }
+    }
+}
+
+impl ImageConfig {
+    pub fn from_resource(resource: &Image) -> Self {
+        Self {
+            position_size_config: Some(resource.basic_front_resource_config.position_size_config),
+            clip_rect: Some(resource.basic_front_resource_config.clip_rect),
+            hidden: Some(resource.display_info.hidden),
+            ignore_render_layer: Some(resource.display_info.ignore_render_layer),
+            always_draw: Some(resource.display_info.always_draw),
+            alpha: Some(resource.alpha),
+            overlay_color: Some(resource.overlay_color),
+            overlay_alpha: Some(resource.overlay_alpha),
+            background_color: Some(resource.background_color),
+            background_alpha: Some(resource.background_alpha),
+            rotate_angle: Some(resource.rotate_angle),
+            rotate_center: Some(resource.rotate_center),
+            image_load_method: Some(resource.image_load_method.clone()),
+            hover_effect: Some(resource.hover_effect),
+            uv_rect: Some(resource.uv_rect),
+            texture_filter: Some(resource.texture_filter),
+            nine_patch: Some(resource.nine_patch),
+            sprite_sheet: Some(resource.sprite_sheet),
+            scale_mode: Some(resource.scale_mode),
+            tags: Some(resource.tags.clone()),
+        }
+    }
+
+    #[inline]
+    pub fn position_size_config(
+        mut self,
+        position_size_config: Option<PositionSizeConfig>,
+    ) -> Self {
+        self.position_size_config = position_size_config;
+        self
+    }
+
+    #[inline]
+    pub fn clip_rect(mut self, clip_rect: Option<Option<PositionSizeConfig>>) -> Self {
+        self.clip_rect = clip_rect;
+        self
+    }
+
+    #[inline]
+    pub fn hidden(mut self, hidden: Option<bool>) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    #[inline]
+    pub fn ignore_render_layer(mut self, ignore_render_layer: Option<bool>) -> Self {
+        self.ignore_render_layer = ignore_render_layer;
+        self
+    }
+
+    #[inline]
+    pub fn always_draw(mut self, always_draw: Option<bool>) -> Self {
+        self.always_draw = always_draw;
+        self
+    }
+
+    #[inline]
+    pub fn alpha(mut self, alpha: Option<u8>) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    #[inline]
+    pub fn overlay_color(mut self, overlay_color: Option<[u8; 3]>) -> Self {
+        self.overlay_color = overlay_color;
+        self
+    }
+
+    #[inline]
+    pub fn overlay_alpha(mut self, overlay_alpha: Option<u8>) -> Self {
+        self.overlay_alpha = overlay_alpha;
+        self
+    }
+
+    #[inline]
+    pub fn background_color(mut self, background_color: Option<[u8; 3]>) -> Self {
+        self.background_color = background_color;
+        self
+    }
+
+    #[inline]
+    pub fn background_alpha(mut self, background_alpha: Option<u8>) -> Self {
+        self.background_alpha = background_alpha;
+        self
+    }
+
+    #[inline]
+    pub fn rotate_angle(mut self, rotate_angle: Option<f32>) -> Self {
+        self.rotate_angle = rotate_angle;
+        self
+    }
+
+    #[inline]
+    pub fn rotate_center(mut self, rotate_center: Option<[f32; 2]>) -> Self {
+        self.rotate_center = rotate_center;
+        self
+    }
+
+    #[inline]
+    pub fn image_load_method(mut self, image_load_method: Option<ImageLoadMethod>) -> Self {
+        self.image_load_method = image_load_method;
+        self
+    }
+
+    #[inline]
+    pub fn hover_effect(mut self, hover_effect: Option<Option<HoverEffect>>) -> Self {
+        self.hover_effect = hover_effect;
+        self
+    }
+
+    #[inline]
+    pub fn uv_rect(mut self, uv_rect: Option<Option<[f32; 4]>>) -> Self {
+        self.uv_rect = uv_rect;
+        self
+    }
+
+    #[inline]
+    pub fn texture_filter(mut self, texture_filter: Option<TextureFilter>) -> Self {
+        self.texture_filter = texture_filter;
+        self
+    }
+
+    #[inline]
+    pub fn nine_patch(mut self, nine_patch: Option<Option<[f32; 4]>>) -> Self {
+        self.nine_patch = nine_patch;
+        self
+    }
+
+    #[inline]
+    pub fn sprite_sheet(mut self, sprite_sheet: Option<Option<SpriteSheet>>) -> Self {
+        self.sprite_sheet = sprite_sheet;
+        self
+    }
+
+    #[inline]
+    pub fn scale_mode(mut self, scale_mode: Option<ScaleMode>) -> Self {
+        self.scale_mode = scale_mode;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+/// Image resource for displaying graphical content in the GUI.
+///
+/// 用于在GUI中显示图形内容的图像资源。
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Image {
+    /// Config for basic front resource properties.
+    ///
+    /// 基本前端资源属性配置。
+    pub basic_front_resource_config: BasicFrontResourceConfig,
+
+    /// Current display position of the image as [x, y].
+    ///
+    /// 图像的当前显示位置，坐标为[x, y]。
+    pub position: [f32; 2],
+
+    /// Current display size of the image as [width, height].
+    ///
+    /// 图像的当前显示尺寸，为[width, height]。
+    pub size: [f32; 2],
+
+    /// Display info controlling visibility and rendering.
+    ///
+    /// 显示信息，控制可见性和渲染。
+    pub display_info: DisplayInfo,
+
+    /// Handle to the loaded texture, if available.
+    ///
+    /// 已加载纹理的句柄（如果可用）。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub texture: Option<DebugTextureHandle>,
+
+    /// Opacity of the image (0-255).
+    ///
+    /// 图像的不透明度（0-255）。
+    pub alpha: u8,
+
+    /// Color overlay applied to the image as [R, G, B].
+    ///
+    /// 应用于图像的色彩覆盖，格式为[R, G, B]。
+    pub overlay_color: [u8; 3],
+
+    /// Opacity of the overlay (0-255).
+    ///
+    /// 覆盖层的不透明度（0-255）。
+    pub overlay_alpha: u8,
+
+    /// Background color behind the image as [R, G, B].
+    ///
+    /// 图像背后的背景颜色，格式为[R, G, B]。
+    pub background_color: [u8; 3],
+
+    /// Opacity of the background (0-255).
+    ///
+    /// 背景的不透明度（0-255）。
+    pub background_alpha: u8,
+
+    /// Rotation angle of the image in degrees.
+    ///
+    /// 图像的旋转角度（度）。
+    pub rotate_angle: f32,
+
+    /// Center point for rotation, compare it with the actual size to obtain as [width, height].
+    ///
+    /// 旋转中心点，通过与实际大小的比得出，为[width, height]。
+    pub rotate_center: [f32; 2],
+
+    /// Method used to load the image.
+    ///
+    /// 用于加载图像的方法。
+    pub image_load_method: ImageLoadMethod,
+
+    /// A storage list of all loaded textures.
+    ///
+    /// 所有已加载纹理的存储列表。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub texture_list: Vec<DebugTextureHandle>,
+
+    /// The path for loading the image in the previous frame.
+    ///
+    /// 上一帧加载图片的路径。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub last_frame_path: String,
+
+    /// Visual effect applied while the pointer is over the resource, None disables it.
+    ///
+    /// 指针悬停在资源上时应用的视觉效果，为None时不启用。
+    pub hover_effect: Option<HoverEffect>,
+
+    /// Progress of the hover effect transition (0.0-1.0).
+    ///
+    /// 悬停效果过渡的进度（0.0-1.0）。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub hover_progress: f32,
+
+    /// Normalized texture region to sample as [min_x, min_y, max_x, max_y], None uses the whole texture.
+    ///
+    /// 要采样的归一化纹理区域，格式为[min_x, min_y, max_x, max_y]，为None时使用整张纹理。
+    pub uv_rect: Option<[f32; 4]>,
+
+    /// Filtering used when the texture is uploaded, textures already cached keep their filter.
+    ///
+    /// 上传纹理时使用的过滤方式，已缓存的纹理保持原有过滤方式。
+    pub texture_filter: TextureFilter,
+
+    /// Nine-patch insets in texture pixels as [left, right, top, bottom], None stretches the whole image.
+    ///
+    /// 以纹理像素为单位的九宫格内边距，格式为[左, 右, 上, 下]，为None时拉伸整张图片。
+    pub nine_patch: Option<[f32; 4]>,
+
+    /// Frame animation played from the texture, None draws the texture as a single frame.
+    ///
+    /// 从纹理播放的帧动画，为None时将纹理作为单帧绘制。
+    pub sprite_sheet: Option<SpriteSheet>,
+
+    /// How the texture is scaled into the image's box, ignored in nine-patch mode.
+    ///
+    /// 纹理缩放到图像区域内的方式，九宫格模式下忽略。
+    pub scale_mode: ScaleMode,
+
+    /// Animated GIF played by swapping the texture, set by `App::load_gif`.
+    ///
+    /// 通过切换纹理播放的GIF动画，由`App::load_gif`设置。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub gif: Option<GifAnimation>,
+
+    /// Key-value pairs for categorization and metadata.
+    ///
+    /// 用于分类和元数据的键值对标签。
+    pub tags: Vec<[String; 2]>,
+}
+
+impl RustConstructorResource for Image {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn display_tags(&self) -> Vec<[String; 2]> {
+        self.tags.clone()
+    }
+
+    fn modify_tags(&mut self, tags: &[[String; 2]], replace: bool) {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+    }
+
+    fn convert_to_front(&self) -> Option<Box<dyn FrontResource>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn convert_to_front_dyn(&self) -> Option<&dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> Option<&mut dyn FrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        Some(self)
+    }
+}
+
+impl FrontResource for Image {
+    fn convert_to_config(&self) -> Box<dyn Config> {
+        Box::new(ImageConfig::from_resource(self))
+    }
+
+    fn convert_from_config(&mut self, config: Box<dyn Config>) -> Option<Box<dyn FrontResource>> {
+        if let Some(config) = config.as_any().downcast_ref::<ImageConfig>() {
+            Some(Box::new(self.clone().from_config(config)))
+        } else {
+            None
+        }
+    }
+
+    fn convert_to_original(&self) -> Box<dyn RustConstructorResource> {
+        Box::new(self.clone())
+    }
+
+    fn convert_to_basic_front(&self) -> Option<Box<dyn BasicFrontResource>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn convert_to_original_dyn(&self) -> &dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_original_dyn_mut(&mut self) -> &mut dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_basic_front_dyn(&self) -> Option<&dyn BasicFrontResource> {
+        Some(self)
+    }
+
+    fn convert_to_basic_front_dyn_mut(&mut self) -> Option<&mut dyn BasicFrontResource> {
+        Some(self)
+    }
+}
+
+impl BasicFrontResource for Image {
+    fn display_basic_front_resource_config(&self) -> BasicFrontResourceConfig {
+        self.basic_front_resource_config.clone()
+    }
+
+    fn display_position_size_config(&self) -> PositionSizeConfig {
+        self.basic_front_resource_config.position_size_config
+    }
+
+    fn display_clip_rect(&self) -> Option<PositionSizeConfig> {
+        self.basic_front_resource_config.clip_rect
+    }
+
+    fn display_display_info(&self) -> DisplayInfo {
+        self.display_info
+    }
+
+    fn display_position(&self) -> [f32; 2] {
+        self.position
+    }
+
+    fn display_size(&self) -> [f32; 2] {
+        self.size
+    }
+
+    fn modify_basic_front_resource_config(
+        &mut self,
+        basic_front_resource_config: BasicFrontResourceConfig,
+    ) {
+        self.basic_front_resource_config = basic_front_resource_config;
+    }
+
+    fn modify_position_size_config(&mut self, position_size_config: PositionSizeConfig) {
+        self.basic_front_resource_config.position_size_config = position_size_config;
+    }
+
+    fn modify_clip_rect(&mut self, clip_rect: Option<PositionSizeConfig>) {
+        self.basic_front_resource_config.clip_rect = clip_rect;
+    }
+
+    fn modify_display_info(&mut self, display_info: DisplayInfo) {
+        self.display_info = display_info;
+    }
+
+    fn convert_to_original(&self) -> Box<dyn RustConstructorResource> {
+        Box::new(self.clone())
+    }
+
+    fn convert_to_front(&self) -> Box<dyn FrontResource> {
+        Box::new(self.clone())
+    }
+
+    fn convert_to_original_dyn(&self) -> &dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_original_dyn_mut(&mut self) -> &mut dyn RustConstructorResource {
+        self
+    }
+
+    fn convert_to_front_dyn(&self) -> &dyn FrontResource {
+        self
+    }
+
+    fn convert_to_front_dyn_mut(&mut self) -> &mut dyn FrontResource {
+        self
+    }
+}
+
+impl Default for Image {
+    fn default() -> Self {
+        Self {
+            basic_front_resource_config: BasicFrontResourceConfig::default(),
+            position: [0_f32, 0_f32],
+            size: [0_f32, 0_f32],
+            display_info: DisplayInfo::default(),
+            texture: None,
+            alpha: 255,
+            overlay_color: [255, 255, 255],
+            overlay_alpha: 255,
+            background_color: [0, 0, 0],
+            background_alpha: 0,
+            rotate_angle: 0_f32,
+            rotate_center: [0_f32, 0_f32],
+            image_load_method: ImageLoadMethod::ByPath((String::new(), [false, false])),
+            texture_list: Vec::new(),
+            last_frame_path: String::new(),
+            hover_effect: None,
+            hover_progress: 0_f32,
+            uv_rect: None,
+            texture_filter: TextureFilter::Linear,
+            nine_patch: None,
+            sprite_sheet: None,
+            scale_mode: ScaleMode::Stretch,
+            gif: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl Image {
+    pub fn from_config(mut self, config: &ImageConfig) -> Self {
+        if let Some(position_size_config) = config.position_size_config {
+            self.basic_front_resource_config.position_size_config = position_size_config;
+        };
+        if let Some(clip_rect) = config.clip_rect {
+            self.basic_front_resource_config.clip_rect = clip_rect;
+        };
+        if let Some(hidden) = config.hidden {
+            self.display_info.hidden = hidden;
+        };
+        if let Some(ignore_render_layer) = config.ignore_render_layer {
+            self.display_info.ignore_render_layer = ignore_render_layer;
+        };
+        if let Some(always_draw) = config.always_draw {
+            self.display_info.always_draw = always_draw;
+        };
+        if let Some(alpha) = config.alpha {
+            self.alpha = alpha;
+        };
+        if let Some(overlay_color) = config.overlay_color {
+            self.overlay_color = overlay_color;
+        };
+        if let Some(overlay_alpha) = config.overlay_alpha {
+            self.overlay_alpha = overlay_alpha;
+        };
+        if let Some(background_color) = config.background_color {
+            self.background_color = background_color;
+        };
+        if let Some(background_alpha) = config.background_alpha {
+            self.background_alpha = background_alpha;
+        };
+        if let Some(rotate_angle) = config.rotate_angle {
+            self.rotate_angle = rotate_angle;
+        };
+        if let Some(rotate_center) = config.rotate_center {
+            self.rotate_center = rotate_center;
+        };
+        if let Some(ref image_load_method) = config.image_load_method {
+            self.image_load_method = image_load_method.clone();
+            self.gif = None;
+        };
+        if let Some(hover_effect) = config.hover_effect {
+            self.hover_effect = hover_effect;
+        };
+        if let Some(uv_rect) = config.uv_rect {
+            self.uv_rect = uv_rect;
+        };
+        if let Some(texture_filter) = config.texture_filter {
+            self.texture_filter = texture_filter;
+        };
+        if let Some(nine_patch) = config.nine_patch {
+            self.nine_patch = nine_patch;
+        };
+        if let Some(sprite_sheet) = config.sprite_sheet {
+            self.sprite_sheet = sprite_sheet;
+        };
+        if let Some(scale_mode) = config.scale_mode {
+            self.scale_mode = scale_mode;
+        };
+        if let Some(ref tags) = config.tags {
+            self.tags = tags.clone();
+        };
+        self
+    }
+
+    #[inline]
+    pub fn basic_front_resource_config(
+        mut self,
+        basic_front_resource_config: &BasicFrontResourceConfig,
+    ) -> Self {
+        self.basic_front_resource_config = basic_front_resource_config.clone();
+        self
+    }
+
+    #[inline]
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.display_info.hidden = hidden;
+        self
+    }
+
+    #[inline]
+    pub fn ignore_render_layer(mut self, ignore_render_layer: bool) -> Self {
+        self.display_info.ignore_render_layer = ignore_render_layer;
+        self
+    }
+
+    #[inline]
+    pub fn always_draw(mut self, always_draw: bool) -> Self {
+        self.display_info.always_draw = always_draw;
+        self
+    }
+
+    #[inline]
+    pub fn alpha(mut self, alpha: u8) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    #[inline]
+    pub fn overlay_color(mut self, r: u8, g: u8, b: u8) -> Self {
+        self.overlay_color = [r, g, b];
+        self
+    }
+
+    #[inline]
+    pub fn overlay_alpha(mut self, overlay_alpha: u8) -> Self {
+        self.overlay_alpha = overlay_alpha;
+        self
+    }
+
+    #[inline]
+    pub fn background_color(mut self, r: u8, g: u8, b: u8) -> Self {
+        self.background_color = [r, g, b];
+        self
+    }
+
+    #[inline]
+    pub fn background_alpha(mut self, background_alpha: u8) -> Self {
+        self.background_alpha = background_alpha;
+        self
+    }
+
+    #[inline]
+    pub fn rotate_angle(mut self, rotate_angle: f32) -> Self {
+        self.rotate_angle = rotate_angle;
+        self
+    }
+
+    #[inline]
+    pub fn rotate_center(mut self, x: f32, y: f32) -> Self {
+        self.rotate_center = [x, y];
+        self
+    }
+
+    #[inline]
+    pub fn image_load_method(mut self, image_load_method: &ImageLoadMethod) -> Self {
+        self.image_load_method = image_load_method.clone();
+        self.gif = None;
+        self
+    }
+
+    #[inline]
+    pub fn hover_effect(mut self, hover_effect: Option<HoverEffect>) -> Self {
+        self.hover_effect = hover_effect;
+        self
+    }
+
+    #[inline]
+    pub fn uv_rect(mut self, uv_rect: Option<[f32; 4]>) -> Self {
+        self.uv_rect = uv_rect;
+        self
+    }
+
+    #[inline]
+    pub fn texture_filter(mut self, texture_filter: TextureFilter) -> Self {
+        self.texture_filter = texture_filter;
+        self
+    }
+
+    #[inline]
+    pub fn nine_patch(mut self, nine_patch: Option<[f32; 4]>) -> Self {
+        self.nine_patch = nine_patch;
+        self
+    }
+
+    #[inline]
+    pub fn sprite_sheet(mut self, sprite_sheet: Option<SpriteSheet>) -> Self {
+        self.sprite_sheet = sprite_sheet;
+        self
+    }
+
+    #[inline]
+    pub fn scale_mode(mut self, scale_mode: ScaleMode) -> Self {
+        self.scale_mode = scale_mode;
+        self
+    }
+
+    #[inline]
+    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
+        if replace {
+            self.tags = tags.to_owned();
+        } else {
+            for tag in tags {
+                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
+                    self.tags.remove(index);
+                };
+            }
+            self.tags.extend(tags.iter().cloned());
+        };
+        self
+    }
+}
+
+/// Control the selection method of hyperlinks.
+///
+/// 控制超链接的选取方法。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HyperlinkSelectMethod {
+    /// Selects all occurrences of the hyperlink text.
+    ///
+    /// 选取所有匹配的超链接文本。
+    All(String),
+    /// Selects specific segments of the hyperlink text with indices.
+    ///
+    /// 选取指定的超链接文本段。
+    Segment(Vec<(usize, String)>),
+}
+
+/// Where the ellipsis is placed when text has to be truncated.
+///
+/// 文本需要截断时省略号放置的位置。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EllipsisPosition {
+    /// Characters are removed from the beginning, e.g. `...file.txt`.
+    ///
+    /// 从开头移除字符，例如`...file.txt`。
+    Start,
+    /// Characters are removed from the center, keeping both the head and the tail.
+    ///
+    /// 从中间移除字符，保留开头和结尾。
+    Middle,
+    /// Characters are removed from the end, e.g. `/Users/...`.
+    ///
+    /// 从末尾移除字符，例如`/Users/...`。
+    #[default]
+    End,
+}
+
+/// Which part of the text is removed when it has to be truncated.
+///
+/// 文本需要截断时移除的部分。
+///
+/// It extends `EllipsisPosition` with `None`, every ellipsis position converts into the mode
+/// of the same name.
+///
+/// 它在`EllipsisPosition`的基础上增加了`None`，每个省略号位置都可以转换为同名的截断方式。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TruncateMode {
+    /// The text is never truncated and may overflow its size.
+    ///
+    /// 文本不会被截断，可能超出其尺寸。
+    None,
+    /// Characters are removed from the beginning, e.g. `...file.txt`.
+    ///
+    /// 从开头移除字符，例如`...file.txt`。
+    Start,
+    /// Characters are removed from the center, keeping both the head and the tail.
+    ///
+    /// 从中间移除字符，保留开头和结尾。
+    Middle,
+    /// Characters are removed from the end, e.g. `/Users/...`.
+    ///
+    /// 从末尾移除字符，例如`/Users/...`。
+    #[default]
+    End,
+}
+
+impl From<EllipsisPosition> for TruncateMode {
+    fn from(ellipsis_position: EllipsisPosition) -> Self {
+        match ellipsis_position {
+            EllipsisPosition::Start => TruncateMode::Start,
+            EllipsisPosition::Middle => TruncateMode::Middle,
+            EllipsisPosition::End => TruncateMode::End,
+        }
+    }
+}
+
+/// Config for truncating text that does not fit its size.
+///
+/// 截断超出尺寸的文本的配置。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct TruncateConfig {
+    /// String inserted where characters were removed.
+    ///
+    /// 在移除字符处插入的字符串。
+    pub ellipsis: String,
+
+    /// Which part of the text is removed.
+    ///
+    /// 移除文本的哪一部分。
+    pub mode: TruncateMode,
+}
+
+impl Default for TruncateConfig {
+    fn default() -> Self {
+        TruncateConfig {
+            ellipsis: String::from("..."),
+            mode: TruncateMode::End,
+        }
+    }
+}
+
+impl TruncateConfig {
+    #[inline]
+    pub fn ellipsis(mut self, ellipsis: &str) -> Self {
+        self.ellipsis = ellipsis.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn mode(mut self, mode: TruncateMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[inline]
+    pub fn ellipsis_position(mut self, ellipsis_position: EllipsisPosition) -> Self {
+        self.mode = ellipsis_position.into();
+        self
+    }
+}
+
+/// Horizontal alignment of the lines of a text inside its wrap box.
+///
+/// 文本各行在换行框内的水平对齐方式。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextAlign {
+    /// Lines start at the left edge.
+    ///
+    /// 各行从左边缘开始。
+    #[default]
+    Left,
+    /// Lines are centered.
+    ///
+    /// 各行居中。
+    Center,
+    /// Lines end at the right edge.
+    ///
+    /// 各行在右边缘结束。
+    Right,
+}
+
+/// Config for collapsible ("show more / show less") text.
+///
+/// 可折叠（“展开/收起”）文本的配置。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct CollapsibleConfig {
+    /// Maximum number of lines shown while collapsed.
+    ///
+    /// 折叠时显示的最大行数。
+    pub collapsed_lines: usize,
+
+    /// Clickable label appended to the collapsed text.
+    ///
+    /// 附加在折叠文本后的可点击标签。
+    pub more_label: String,
+
+    /// Clickable label appended to the expanded text.
+    ///
+    /// 附加在展开文本后的可点击标签。
+    pub less_label: String,
+}
+
+impl Default for CollapsibleConfig {
+    fn default() -> Self {
+        CollapsibleConfig {
+            collapsed_lines: 3,
+            more_label: String::from("Show more"),
+            less_label: String::from("Show less"),
+        }
+    }
+}
+
+impl CollapsibleConfig {
+    #[inline]
+    pub fn collapsed_lines(mut self, collapsed_lines: usize) -> Self {
+        self.collapsed_lines = collapsed_lines;
+        self
+    }
+
+    #[inline]
+    pub fn more_label(mut self, more_label: &str) -> Self {
+        self.more_label = more_label.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn less_label(mut self, less_label: &str) -> Self {
+        self.less_label = less_label.to_string();
+        self
+    }
+}
+
+/// Config for text that scrolls horizontally when it is wider than its box.
+///
+/// 文本宽于文本框时水平滚动显示的配置。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct MarqueeConfig {
+    /// Scrolling speed in pixels per second.
+    ///
+    /// 滚动速度，单位为像素每秒。
+    pub speed: f32,
+
+    /// Space between the end of the text and its next repetition in pixels.
+    ///
+    /// 文本末尾与下一次重复之间的间距，单位为像素。
+    pub gap: f32,
+
+    /// Whether scrolling stops while the pointer is on the text.
+    ///
+    /// 指针位于文本上时是否停止滚动。
+    pub pause_on_hover: bool,
+}
+
+impl Default for MarqueeConfig {
+    fn default() -> Self {
+        MarqueeConfig {
+            speed: 40_f32,
+            gap: 40_f32,
+            pause_on_hover: false,
+        }
+    }
+}
+
+impl MarqueeConfig {
+    #[inline]
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    #[inline]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    #[inline]
+    pub fn pause_on_hover(mut self, pause_on_hover: bool) -> Self {
+        self.pause_on_hover = pause_on_hover;
+        self
+    }
+}
+
+/// Style override applied to a segment of a text resource.
+///
+/// 应用于文本资源中某一段的样式覆盖。
+///
+/// Fields left as None keep the base style of the text.
+///
+/// 为None的字段保持文本的基础样式。
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct TextStyle {
+    /// Color of the segment as [R, G, B, A].
+    ///
+    /// 该段的颜色，格式为[R, G, B, A]。
+    pub color: Option<[u8; 4]>,
+
+    /// Font size of the segment.
+    ///
+    /// 该段的字体大小。
+    pub font_size: Option<f32>,
+
+    /// Name of a loaded font used by the segment.
+    ///
+    /// 该段使用的已加载字体的名称。
+    pub font: Option<String>,
+
+    /// If true, the segment is underlined.
+    ///
+    /// 如果为true，该段带有下划线。
+    pub underline: bool,
+
+    /// If true, the segment is struck through.
+    ///
+    /// 如果为true，该段带有删除线。
+    pub strikethrough: bool,
+
+    /// If true, the segment is bold, see `Text::bold`.
+    ///
+    /// 如果为true，该段为粗体，参见`Text::bold`。
+    pub bold: bool,
+
+    /// If true, the segment is italic, see `Text::italic`.
+    ///
+    /// 如果为true，该段为斜体，参见`Text::italic`。
+    pub italic: bool,
+}
+
+impl TextStyle {
+    #[inline]
+    pub fn color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.color = Some([r, g, b, a]);
+        self
+    }
+
+    #[inline]
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    #[inline]
+    pub fn font(mut self, font: &str) -> Self {
+        self.font = Some(font.to_string());
+        self
+    }
+
+    #[inline]
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    #[inline]
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    #[inline]
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    #[inline]
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+}
+
+/// Search highlight for text, used for find-in-page features.
+///
+/// 文本的搜索高亮，用于页内查找功能。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct TextHighlight {
+    /// Text to search for.
+    ///
+    /// 要搜索的文本。
+    pub query: String,
+
+    /// If false, letters are compared ignoring case.
+    ///
+    /// 如果为false，比较字母时忽略大小写。
+    pub case_sensitive: bool,
+
+    /// If true, only matches not surrounded by letters, digits or underscores count.
+    ///
+    /// 如果为true，只统计两侧不是字母、数字或下划线的匹配。
+    pub whole_word: bool,
+
+    /// Color of the highlight as [R, G, B, A].
+    ///
+    /// 高亮颜色，格式为[R, G, B, A]。
+    pub color: [u8; 4],
+}
+
+impl Default for TextHighlight {
+    fn default() -> Self {
+        TextHighlight {
+            query: String::new(),
+            case_sensitive: false,
+            whole_word: false,
+            color: [255, 210, 0, 120],
+        }
+    }
+}
+
+impl TextHighlight {
+    #[inline]
+    pub fn query(mut self, query: &str) -> Self {
+        self.query = query.to_string();
+        self
+    }
+
+    #[inline]
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    #[inline]
+    pub fn whole_word(mut self, whole_word: bool) -> Self {
+        self.whole_word = whole_word;
+        self
+    }
+
+    #[inline]
+    pub fn color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
         self
     }
 
-    #[inline]
-    pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
-        if replace {
-            self.tags = tags.to_owned();
-        } else {
-            for tag in tags {
-                if let Some(index) = self.tags.iter().position(|x| x[0] == tag[0]) {
-                    self.tags.remove(index);
-                };
+    /// Finds all non-overlapping matches in `content` as character ranges [start, end).
+    ///
+    /// 查找`content`中所有不重叠的匹配，以字符范围[start, end)返回。
+    pub fn find_matches(&self, content: &str) -> Vec<(usize, usize)> {
+        let normalize = |c: char| {
+            if self.case_sensitive {
+                c
+            } else {
+                c.to_lowercase().next().unwrap_or(c)
             }
-            self.tags.extend(tags.iter().cloned());
         };
-        self
+        let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+        let chars: Vec<char> = content.chars().map(normalize).collect();
+        let query: Vec<char> = self.query.chars().map(normalize).collect();
+        let mut matches = Vec::new();
+        if query.is_empty() {
+            return matches;
+        };
+        let mut start = 0;
+        while start + query.len() <= chars.len() {
+            let end = start + query.len();
+            // 全词匹配时检查两侧的字符边界
+            if chars[start..end] == query[..]
+                && (!self.whole_word
+                    || (start == 0 || !is_word_char(chars[start - 1]))
+                        && (end == chars.len() || !is_word_char(chars[end])))
+            {
+                matches.push((start, end));
+                start = end;
+            } else {
+                start += 1;
+            };
+        }
+        matches
     }
 }
 
-/// Control the selection method of hyperlinks.
+/// Inputs that affect the layout of a text, compared between frames to decide whether the
+/// cached galley can be reused.
 ///
-/// 控制超链接的选取方法。
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum HyperlinkSelectMethod {
-    /// Selects all occurrences of the hyperlink text.
+/// 影响文本排版的输入，在帧之间比较以决定能否复用缓存的排版结果。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct TextLayoutKey {
+    pub content: String,
+    pub font: String,
+    pub font_size: f32,
+    pub color: [u8; 3],
+    pub alpha: u8,
+    pub bold: bool,
+    pub italic: bool,
+
+    /// Size the text was given before responsive adjustments.
     ///
-    /// 选取所有匹配的超链接文本。
-    All(String),
-    /// Selects specific segments of the hyperlink text with indices.
+    /// 文本在响应式调整之前设定的尺寸。
+    pub origin_size: [f32; 2],
+    pub truncate_size: [f32; 2],
+    pub truncate_config: TruncateConfig,
+    pub collapsible: Option<CollapsibleConfig>,
+    pub expanded: bool,
+    pub marquee: bool,
+    pub styled_segments: Vec<([usize; 2], TextStyle)>,
+    pub text_align: TextAlign,
+
+    /// Size of the window, any resize invalidates the cache.
     ///
-    /// 选取指定的超链接文本段。
-    Segment(Vec<(usize, String)>),
+    /// 窗口尺寸，任何尺寸变化都会使缓存失效。
+    pub window_size: [f32; 2],
+
+    /// Physical pixels per point, glyphs are rasterized for it.
+    ///
+    /// 每点对应的物理像素数，字形按此进行光栅化。
+    pub pixels_per_point: f32,
+
+    /// Number of loaded fonts, registering a font changes which variants resolve.
+    ///
+    /// 已加载的字体数量，注册字体会改变变体的解析结果。
+    pub loaded_font_count: usize,
+
+    /// Order of the fallback fonts, reordering them changes which glyphs are picked.
+    ///
+    /// 回退字体的顺序，调整顺序会改变选用的字形。
+    pub font_fallback: Vec<String>,
+}
+
+/// Layout of a text kept from the previous frame.
+///
+/// 保留自上一帧的文本排版结果。
+#[derive(Debug, Clone)]
+pub struct TextLayoutCache {
+    /// Text the layout belongs to.
+    ///
+    /// 排版结果所属的文本。
+    pub target: RustConstructorId,
+
+    /// Inputs the layout was computed from.
+    ///
+    /// 计算排版结果时使用的输入。
+    pub key: TextLayoutKey,
+
+    /// Content after collapsing and truncation.
+    ///
+    /// 折叠和截断后的内容。
+    pub display_content: String,
+
+    /// Label appended by a collapsible text, if any.
+    ///
+    /// 可折叠文本附加的标签（如果有）。
+    pub collapsible_label: Option<String>,
+
+    /// Laid out text.
+    ///
+    /// 排版后的文本。
+    pub galley: Arc<Galley>,
+
+    /// Overlay used to fake bold, if any part of the text needs it.
+    ///
+    /// 用于仿粗体的叠加层，仅在文本有部分需要仿粗体时存在。
+    pub bold_galley: Option<Arc<Galley>>,
 }
 
 /// Config options for text resources.
 ///
 /// 文本资源的配置选项。
 #[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct TextConfig {
     /// Config for position, size, and layout.
     ///
@@ -1387,6 +3697,11 @@ pub struct TextConfig {
     /// 如果为true，文本忽略渲染层。
     pub ignore_render_layer: Option<bool>,
 
+    /// If true, the text is drawn even when render culling finds it offscreen.
+    ///
+    /// 如果为true，即使渲染剔除判定文本在屏幕外也会绘制。
+    pub always_draw: Option<bool>,
+
     /// Text content to be displayed.
     ///
     /// 要显示的文本内容。
@@ -1420,7 +3735,7 @@ pub struct TextConfig {
     /// Radius for rounded corners of the background.
     ///
     /// 背景圆角半径。
-    pub background_rounding: Option<f32>,
+    pub background_rounding: Option<Rounding>,
 
     /// The font used for the specified text.
     ///
@@ -1442,6 +3757,68 @@ pub struct TextConfig {
     /// 自动调整尺寸以适应内容。
     pub auto_fit: Option<[bool; 2]>,
 
+    /// How the text is truncated when it does not fit its size.
+    ///
+    /// 文本超出尺寸时的截断方式。
+    pub truncate_config: Option<TruncateConfig>,
+
+    /// Collapses the text to a limited number of lines with a clickable toggle, None disables it.
+    ///
+    /// 将文本折叠为有限行数并附带可点击的切换标签，为None时不启用。
+    pub collapsible: Option<Option<CollapsibleConfig>>,
+
+    /// Search highlight painted behind matching text, None disables it.
+    ///
+    /// 绘制在匹配文本后方的搜索高亮，为None时不启用。
+    pub highlight: Option<Option<TextHighlight>>,
+
+    /// Style overrides for character ranges [start, end) of `content`, later segments win where they overlap.
+    ///
+    /// 针对`content`中字符范围[start, end)的样式覆盖，重叠时后面的段优先。
+    ///
+    /// Ranges follow the characters through truncation and collapsing, an ellipsis or label
+    /// that was inserted keeps the base style. They are stored as `[usize; 2]` rather than
+    /// `Range<usize>` because `Range` does not implement `PartialOrd`, which this resource derives.
+    ///
+    /// 范围会跟随字符经过截断和折叠，插入的省略号或标签保持基础样式。使用`[usize; 2]`而不是
+    /// `Range<usize>`存储，因为`Range`没有实现本资源派生的`PartialOrd`。
+    pub styled_segments: Option<Vec<([usize; 2], TextStyle)>>,
+
+    /// Drop shadow painted behind the text background.
+    ///
+    /// 绘制在文本背景后方的阴影。
+    pub background_shadow: Option<Option<ShadowConfig>>,
+
+    /// Alignment of the lines inside the wrap box, independent of where the text block is placed.
+    ///
+    /// 各行在换行框内的对齐方式，与文本块的放置位置无关。
+    pub text_align: Option<TextAlign>,
+
+    /// Outline drawn around the glyphs as (color, width), None for no outline.
+    ///
+    /// 绘制在字形周围的描边，格式为(颜色, 宽度)，为None时不描边。
+    pub outline: Option<Option<([u8; 4], f32)>>,
+
+    /// Shadow drawn under the glyphs as (color, offset), None for no shadow.
+    ///
+    /// 绘制在字形下方的阴影，格式为(颜色, 偏移)，为None时没有阴影。
+    pub glyph_shadow: Option<Option<([u8; 4], [f32; 2])>>,
+
+    /// If true, the text uses the "{font}-Bold" variant of its font, or a faux bold when it is not registered.
+    ///
+    /// 如果为true，文本使用其字体的"{font}-Bold"变体，未注册该变体时使用仿粗体。
+    pub bold: Option<bool>,
+
+    /// If true, the text uses the "{font}-Italic" variant of its font, or a faux italic when it is not registered.
+    ///
+    /// 如果为true，文本使用其字体的"{font}-Italic"变体，未注册该变体时使用仿斜体。
+    pub italic: Option<bool>,
+
+    /// Scrolling applied when the text is wider than its box, None disables it.
+    ///
+    /// 文本宽于文本框时应用的滚动，为None时禁用。
+    pub marquee: Option<Option<MarqueeConfig>>,
+
     /// Key-value pairs for categorization and metadata.
     ///
     /// 用于分类和元数据的键值对标签。
@@ -1477,6 +3854,7 @@ impl TextConfig {
             clip_rect: Some(resource.basic_front_resource_config.clip_rect),
             hidden: Some(resource.display_info.hidden),
             ignore_render_layer: Some(resource.display_info.ignore_render_layer),
+            always_draw: Some(resource.display_info.always_draw),
             content: Some(resource.content.clone()),
             font_size: Some(resource.font_size),
             color: Some(resource.color),
@@ -1488,6 +3866,17 @@ impl TextConfig {
             selectable: Some(resource.selectable),
             hyperlink_text: Some(resource.hyperlink_text.clone()),
             auto_fit: Some(resource.auto_fit),
+            truncate_config: Some(resource.truncate_config.clone()),
+            collapsible: Some(resource.collapsible.clone()),
+            highlight: Some(resource.highlight.clone()),
+            styled_segments: Some(resource.styled_segments.clone()),
+            background_shadow: Some(resource.background_shadow),
+            text_align: Some(resource.text_align),
+            outline: Some(resource.outline),
+            glyph_shadow: Some(resource.glyph_shadow),
+            bold: Some(resource.bold),
+            italic: Some(resource.italic),
+            marquee: Some(resource.marquee),
             tags: Some(resource.tags.clone()),
         }
     }
@@ -1519,6 +3908,12 @@ impl TextConfig {
         self
     }
 
+    #[inline]
+    pub fn always_draw(mut self, always_draw: Option<bool>) -> Self {
+        self.always_draw = always_draw;
+        self
+    }
+
     #[inline]
     pub fn content(mut self, content: Option<String>) -> Self {
         self.content = content;
@@ -1557,7 +3952,16 @@ impl TextConfig {
 
     #[inline]
     pub fn background_rounding(mut self, background_rounding: Option<f32>) -> Self {
-        self.background_rounding = background_rounding;
+        self.background_rounding = background_rounding.map(Rounding::Uniform);
+        self
+    }
+
+    #[inline]
+    pub fn background_corner_rounding(
+        mut self,
+        background_corner_rounding: Option<[f32; 4]>,
+    ) -> Self {
+        self.background_rounding = background_corner_rounding.map(Rounding::Corners);
         self
     }
 
@@ -1588,6 +3992,90 @@ impl TextConfig {
         self
     }
 
+    #[inline]
+    pub fn truncate_config(mut self, truncate_config: Option<TruncateConfig>) -> Self {
+        self.truncate_config = truncate_config;
+        self
+    }
+
+    /// Sets where the ellipsis is inserted, None leaves the truncation unchanged.
+    ///
+    /// 设置省略号插入的位置，为None时不改变截断方式。
+    #[inline]
+    pub fn ellipsis_position(mut self, ellipsis_position: Option<EllipsisPosition>) -> Self {
+        if let Some(ellipsis_position) = ellipsis_position {
+            self.truncate_config = Some(
+                self.truncate_config
+                    .unwrap_or_default()
+                    .ellipsis_position(ellipsis_position),
+            );
+        };
+        self
+    }
+
+    #[inline]
+    pub fn collapsible(mut self, collapsible: Option<Option<CollapsibleConfig>>) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    #[inline]
+    pub fn highlight(mut self, highlight: Option<Option<TextHighlight>>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    #[inline]
+    pub fn styled_segments(
+        mut self,
+        styled_segments: Option<Vec<([usize; 2], TextStyle)>>,
+    ) -> Self {
+        self.styled_segments = styled_segments;
+        self
+    }
+
+    #[inline]
+    pub fn background_shadow(mut self, background_shadow: Option<Option<ShadowConfig>>) -> Self {
+        self.background_shadow = background_shadow;
+        self
+    }
+
+    #[inline]
+    pub fn text_align(mut self, text_align: Option<TextAlign>) -> Self {
+        self.text_align = text_align;
+        self
+    }
+
+    #[inline]
+    pub fn outline(mut self, outline: Option<Option<([u8; 4], f32)>>) -> Self {
+        self.outline = outline;
+        self
+    }
+
+    #[inline]
+    pub fn glyph_shadow(mut self, glyph_shadow: Option<Option<([u8; 4], [f32; 2])>>) -> Self {
+        self.glyph_shadow = glyph_shadow;
+        self
+    }
+
+    #[inline]
+    pub fn bold(mut self, bold: Option<bool>) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    #[inline]
+    pub fn italic(mut self, italic: Option<bool>) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    #[inline]
+    pub fn marquee(mut self, marquee: Option<Option<MarqueeConfig>>) -> Self {
+        self.marquee = marquee;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: Option<Vec<[String; 2]>>) -> Self {
         self.tags = tags;
@@ -1599,6 +4087,11 @@ impl TextConfig {
 ///
 /// 用于显示和交互文本内容的文本资源。
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Text {
     /// Config for basic front resource properties.
     ///
@@ -1653,7 +4146,7 @@ pub struct Text {
     /// Radius for rounded corners of the background.
     ///
     /// 背景圆角半径。
-    pub background_rounding: f32,
+    pub background_rounding: Rounding,
 
     /// The font used for the specified text.
     ///
@@ -1673,6 +4166,7 @@ pub struct Text {
     /// Hyperlink indices and URLs: (start_index, end_index, url).
     ///
     /// 超链接索引值和链接：(起始索引, 结束索引, 链接)。
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub hyperlink_index: Vec<(usize, usize, String)>,
 
     /// Auto-fit behavior: [horizontal_fit, vertical_fit].
@@ -1683,23 +4177,128 @@ pub struct Text {
     /// Text content from the previous frame for change detection.
     ///
     /// 上一帧的文本内容，用于变化检测。
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub last_frame_content: String,
 
     /// Currently selected text range (start_index, end_index).
     ///
     /// 框选选中的文本范围（起始索引, 结束索引）。
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub selection: Option<(usize, usize)>,
 
     /// Size at which text is truncated for display.
     ///
     /// 文本被截断以供显示的尺寸。
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub truncate_size: [f32; 2],
 
     /// Actual size of the text content.
     ///
     /// 文本内容的实际尺寸。
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub actual_size: [f32; 2],
 
+    /// How the text is truncated when it does not fit its size.
+    ///
+    /// 文本超出尺寸时的截断方式。
+    pub truncate_config: TruncateConfig,
+
+    /// Collapses the text to a limited number of lines with a clickable toggle, None disables it.
+    ///
+    /// 将文本折叠为有限行数并附带可点击的切换标签，为None时不启用。
+    pub collapsible: Option<CollapsibleConfig>,
+
+    /// Whether collapsible text is currently expanded.
+    ///
+    /// 可折叠文本当前是否已展开。
+    pub expanded: bool,
+
+    /// Search highlight painted behind matching text, None disables it.
+    ///
+    /// 绘制在匹配文本后方的搜索高亮，为None时不启用。
+    pub highlight: Option<TextHighlight>,
+
+    /// Character ranges of the current highlight matches, its length is the match count.
+    ///
+    /// 当前高亮匹配的字符范围，其长度即匹配数量。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub highlight_index: Vec<(usize, usize)>,
+
+    /// Character index at which a blinking caret is drawn, used by text inputs.
+    ///
+    /// 绘制闪烁光标的字符索引，由文本输入框使用。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub caret: Option<usize>,
+
+    /// Length of one caret blink cycle in seconds, 0 keeps the caret visible.
+    ///
+    /// 光标一次闪烁周期的秒数，为0时光标始终可见。
+    pub caret_blink_period: f32,
+
+    /// Time the caret last moved, in seconds of egui's input time, the blink cycle starts there.
+    ///
+    /// 光标上次移动的时间（egui输入时间的秒数），闪烁周期从此时开始。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub caret_blink_start: f64,
+
+    /// Style overrides for character ranges [start, end) of `content`, later segments win where they overlap.
+    ///
+    /// 针对`content`中字符范围[start, end)的样式覆盖，重叠时后面的段优先。
+    ///
+    /// Ranges follow the characters through truncation and collapsing, an ellipsis or label
+    /// that was inserted keeps the base style. They are stored as `[usize; 2]` rather than
+    /// `Range<usize>` because `Range` does not implement `PartialOrd`, which this resource derives.
+    ///
+    /// 范围会跟随字符经过截断和折叠，插入的省略号或标签保持基础样式。使用`[usize; 2]`而不是
+    /// `Range<usize>`存储，因为`Range`没有实现本资源派生的`PartialOrd`。
+    pub styled_segments: Vec<([usize; 2], TextStyle)>,
+
+    /// Drop shadow painted behind the text background.
+    ///
+    /// 绘制在文本背景后方的阴影。
+    pub background_shadow: Option<ShadowConfig>,
+
+    /// Alignment of the lines inside the wrap box, independent of where the text block is placed.
+    ///
+    /// 各行在换行框内的对齐方式，与文本块的放置位置无关。
+    pub text_align: TextAlign,
+
+    /// Outline drawn around the glyphs as (color, width), None for no outline.
+    ///
+    /// 绘制在字形周围的描边，格式为(颜色, 宽度)，为None时不描边。
+    pub outline: Option<([u8; 4], f32)>,
+
+    /// Shadow drawn under the glyphs as (color, offset), None for no shadow.
+    ///
+    /// 绘制在字形下方的阴影，格式为(颜色, 偏移)，为None时没有阴影。
+    pub glyph_shadow: Option<([u8; 4], [f32; 2])>,
+
+    /// If true, the text uses the "{font}-Bold" variant of its font, or a faux bold when it is not registered.
+    ///
+    /// 如果为true，文本使用其字体的"{font}-Bold"变体，未注册该变体时使用仿粗体。
+    pub bold: bool,
+
+    /// If true, the text uses the "{font}-Italic" variant of its font, or a faux italic when it is not registered.
+    ///
+    /// 如果为true，文本使用其字体的"{font}-Italic"变体，未注册该变体时使用仿斜体。
+    pub italic: bool,
+
+    /// Scrolling applied when the text is wider than its box, None disables it.
+    ///
+    /// 文本宽于文本框时应用的滚动，为None时禁用。
+    ///
+    /// A marquee text stays on one line and its width is always the configured box width,
+    /// `auto_fit[0]` is ignored.
+    ///
+    /// 跑马灯文本保持单行，其宽度始终为配置的文本框宽度，`auto_fit[0]`会被忽略。
+    pub marquee: Option<MarqueeConfig>,
+
+    /// Current horizontal scrolling offset of the marquee in pixels.
+    ///
+    /// 跑马灯当前的水平滚动偏移，单位为像素。
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub marquee_offset: f32,
+
     /// Key-value pairs for categorization and metadata.
     ///
     /// 用于分类和元数据的键值对标签。
@@ -1877,7 +4476,7 @@ impl Default for Text {
             alpha: 255,
             background_color: [0, 0, 0],
             background_alpha: 0,
-            background_rounding: 2_f32,
+            background_rounding: Rounding::Uniform(2_f32),
             font: String::new(),
             selectable: true,
             auto_fit: [true, true],
@@ -1887,6 +4486,23 @@ impl Default for Text {
             selection: None,
             truncate_size: [0_f32, 0_f32],
             actual_size: [0_f32, 0_f32],
+            truncate_config: TruncateConfig::default(),
+            collapsible: None,
+            expanded: false,
+            highlight: None,
+            highlight_index: Vec::new(),
+            caret: None,
+            caret_blink_period: 1_f32,
+            caret_blink_start: 0_f64,
+            styled_segments: Vec::new(),
+            background_shadow: None,
+            text_align: TextAlign::Left,
+            outline: None,
+            glyph_shadow: None,
+            bold: false,
+            italic: false,
+            marquee: None,
+            marquee_offset: 0_f32,
             tags: Vec::new(),
         }
     }
@@ -1906,6 +4522,9 @@ impl Text {
         if let Some(ignore_render_layer) = config.ignore_render_layer {
             self.display_info.ignore_render_layer = ignore_render_layer;
         };
+        if let Some(always_draw) = config.always_draw {
+            self.display_info.always_draw = always_draw;
+        };
         if let Some(ref content) = config.content {
             self.content = content.clone();
         };
@@ -1939,6 +4558,39 @@ impl Text {
         if let Some(auto_fit) = config.auto_fit {
             self.auto_fit = auto_fit;
         };
+        if let Some(ref truncate_config) = config.truncate_config {
+            self.truncate_config = truncate_config.clone();
+        };
+        if let Some(ref collapsible) = config.collapsible {
+            self.collapsible = collapsible.clone();
+        };
+        if let Some(ref highlight) = config.highlight {
+            self.highlight = highlight.clone();
+        };
+        if let Some(ref styled_segments) = config.styled_segments {
+            self.styled_segments = styled_segments.clone();
+        };
+        if let Some(background_shadow) = config.background_shadow {
+            self.background_shadow = background_shadow;
+        };
+        if let Some(text_align) = config.text_align {
+            self.text_align = text_align;
+        };
+        if let Some(outline) = config.outline {
+            self.outline = outline;
+        };
+        if let Some(glyph_shadow) = config.glyph_shadow {
+            self.glyph_shadow = glyph_shadow;
+        };
+        if let Some(bold) = config.bold {
+            self.bold = bold;
+        };
+        if let Some(italic) = config.italic {
+            self.italic = italic;
+        };
+        if let Some(marquee) = config.marquee {
+            self.marquee = marquee;
+        };
         if let Some(ref tags) = config.tags {
             self.tags = tags.clone();
         };
@@ -1966,6 +4618,12 @@ impl Text {
         self
     }
 
+    #[inline]
+    pub fn always_draw(mut self, always_draw: bool) -> Self {
+        self.display_info.always_draw = always_draw;
+        self
+    }
+
     #[inline]
     pub fn content(mut self, content: &str) -> Self {
         self.content = content.to_string();
@@ -2004,7 +4662,13 @@ impl Text {
 
     #[inline]
     pub fn background_rounding(mut self, background_rounding: f32) -> Self {
-        self.background_rounding = background_rounding;
+        self.background_rounding = Rounding::Uniform(background_rounding);
+        self
+    }
+
+    #[inline]
+    pub fn background_corner_rounding(mut self, background_corner_rounding: [f32; 4]) -> Self {
+        self.background_rounding = Rounding::Corners(background_corner_rounding);
         self
     }
 
@@ -2043,6 +4707,84 @@ impl Text {
         self
     }
 
+    #[inline]
+    pub fn truncate_config(mut self, truncate_config: TruncateConfig) -> Self {
+        self.truncate_config = truncate_config;
+        self
+    }
+
+    #[inline]
+    pub fn ellipsis_position(mut self, ellipsis_position: EllipsisPosition) -> Self {
+        self.truncate_config.mode = ellipsis_position.into();
+        self
+    }
+
+    #[inline]
+    pub fn collapsible(mut self, collapsible: Option<CollapsibleConfig>) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    #[inline]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    #[inline]
+    pub fn highlight(mut self, highlight: Option<TextHighlight>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    #[inline]
+    pub fn styled_segments(mut self, styled_segments: &[([usize; 2], TextStyle)]) -> Self {
+        self.styled_segments = styled_segments.to_owned();
+        self
+    }
+
+    #[inline]
+    pub fn background_shadow(mut self, background_shadow: Option<ShadowConfig>) -> Self {
+        self.background_shadow = background_shadow;
+        self
+    }
+
+    #[inline]
+    pub fn text_align(mut self, text_align: TextAlign) -> Self {
+        self.text_align = text_align;
+        self
+    }
+
+    #[inline]
+    pub fn outline(mut self, outline: Option<([u8; 4], f32)>) -> Self {
+        self.outline = outline;
+        self
+    }
+
+    #[inline]
+    pub fn glyph_shadow(mut self, glyph_shadow: Option<([u8; 4], [f32; 2])>) -> Self {
+        self.glyph_shadow = glyph_shadow;
+        self
+    }
+
+    #[inline]
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    #[inline]
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    #[inline]
+    pub fn marquee(mut self, marquee: Option<MarqueeConfig>) -> Self {
+        self.marquee = marquee;
+        self
+    }
+
     #[inline]
     pub fn tags(mut self, tags: &[[String; 2]], replace: bool) -> Self {
         if replace {
@@ -2058,3 +4800,55 @@ impl Text {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "rc_bevy")]
+    use egui_bevy::Context;
+    #[cfg(feature = "rc_standard")]
+    use egui_standard::Context;
+
+    fn gif_animation(delays: &[u128]) -> GifAnimation {
+        let context = Context::default();
+        let frames = (0..delays.len())
+            .map(|index| DebugTextureHandle {
+                path: format!("frame{index}"),
+                texture_handle: context.load_texture(
+                    format!("frame{index}"),
+                    ColorImage::from_rgba_unmultiplied([1, 1], &[255, 255, 255, 255]),
+                    TextureOptions::default(),
+                ),
+            })
+            .collect();
+        GifAnimation {
+            frames,
+            delays: delays.to_vec(),
+            ..GifAnimation::default()
+        }
+    }
+
+    #[test]
+    fn gif_animation_follows_frame_delays() {
+        let mut gif = gif_animation(&[100, 50, 200]);
+        gif.update(1000);
+        assert_eq!(gif.current_frame, 0);
+        gif.update(1120);
+        assert_eq!(gif.current_frame, 1);
+        gif.update(1200);
+        assert_eq!(gif.current_frame, 2);
+        // 循环播放时回到第一帧
+        gif.update(1360);
+        assert_eq!(gif.current_frame, 0);
+        assert!(!gif.finished);
+    }
+
+    #[test]
+    fn gif_animation_stops_on_the_last_frame_without_looping() {
+        let mut gif = gif_animation(&[100, 100]).looping(false);
+        gif.update(0);
+        gif.update(500);
+        assert_eq!(gif.current_frame, 1);
+        assert!(gif.finished);
+    }
+}
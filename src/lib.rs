@@ -39,9 +39,9 @@ compile_error!(
 );
 use crate::{advance_front::BackgroundType, basic_front::BorderKind};
 #[cfg(feature = "rc_bevy")]
-use egui_bevy::Ui;
+use egui_bevy::{CursorIcon, Key, Modifiers, Ui};
 #[cfg(feature = "rc_standard")]
-use egui_standard::Ui;
+use egui_standard::{CursorIcon, Key, Modifiers, Ui};
 use std::{
     any::{Any, type_name, type_name_of_val},
     error::Error,
@@ -449,6 +449,11 @@ impl RustConstructorResourceBox {
 ///
 /// 这个结构体包含了在GUI中定位、调整大小和裁剪可视元素所需的所有配置。
 #[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct BasicFrontResourceConfig {
     /// Config for position, size, and layout properties.
     ///
@@ -492,6 +497,11 @@ impl BasicFrontResourceConfig {
 ///
 /// 网格系统允许使用可用空间的一部分进行相对定位和大小调整，使布局响应并适应不同的屏幕尺寸。
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct PositionSizeConfig {
     /// Absolute position coordinates in pixels (`[x, y]`).
     ///
@@ -643,6 +653,21 @@ pub struct Timer {
     ///
     /// 当前页面的运行时间（毫秒）。
     pub now_time: u128,
+
+    /// Whether the timer is paused. While paused, `total_time` and `now_time` stay frozen.
+    ///
+    /// 计时器是否已暂停。暂停期间`total_time`和`now_time`保持不变。
+    pub paused: bool,
+
+    /// Real time at which the current pause started, in milliseconds.
+    ///
+    /// 当前暂停开始时的真实时间（毫秒）。
+    pub pause_start_time: u128,
+
+    /// Accumulated length of all finished pauses, in milliseconds. It is excluded from `total_time`.
+    ///
+    /// 所有已结束暂停的累计时长（毫秒），该时长不计入`total_time`。
+    pub paused_duration: u128,
 }
 
 impl Default for Timer {
@@ -652,6 +677,9 @@ impl Default for Timer {
             total_time: 0,
             timer: Instant::now(),
             now_time: 0,
+            paused: false,
+            pause_start_time: 0,
+            paused_duration: 0,
         }
     }
 }
@@ -672,6 +700,176 @@ pub struct RustConstructorError {
     pub description: String,
 }
 
+impl RustConstructorError {
+    /// The requested resource does not exist.
+    ///
+    /// 请求的资源不存在。
+    pub const RESOURCE_NOT_FOUND: &'static str = "ResourceNotFound";
+
+    /// A resource with the same name and type already exists.
+    ///
+    /// 已存在同名同类型的资源。
+    pub const RESOURCE_NAME_REPETITION: &'static str = "ResourceNameRepetition";
+
+    /// The resource was added without a name.
+    ///
+    /// 添加资源时未命名。
+    pub const RESOURCE_UNTITLED: &'static str = "ResourceUntitled";
+
+    /// The resource could not be downcast to the requested type.
+    ///
+    /// 资源无法向下转换为请求的类型。
+    pub const RESOURCE_DOWNCAST_TYPE_MISMATCH: &'static str = "ResourceDowncastTypeMismatch";
+
+    /// The generic type of a variable resource does not match.
+    ///
+    /// 变量资源的泛型类型不匹配。
+    pub const RESOURCE_GENERIC_MISMATCH: &'static str = "ResourceGenericMismatch";
+
+    /// The requested resource is not in the render list.
+    ///
+    /// 请求的资源不在渲染列表中。
+    pub const RENDER_RESOURCE_NOT_FOUND: &'static str = "RenderResourceNotFound";
+
+    /// The index exceeds the length of the target list.
+    ///
+    /// 索引超出目标列表的长度。
+    pub const INDEX_OUT_OF_RANGE: &'static str = "IndexOutOfRange";
+
+    /// The amount of switch appearance configs does not match the amount of states.
+    ///
+    /// 开关外观配置数量与状态数量不匹配。
+    pub const SWITCH_APPEARANCE_CONFIG_MISMATCH: &'static str = "SwitchAppearanceConfigMismatch";
+
+    /// A font could not be loaded.
+    ///
+    /// 无法加载字体。
+    pub const FONT_LOAD_FAILED: &'static str = "FontLoadFailed";
+
+    /// Anchoring resources to each other would form a cycle.
+    ///
+    /// 资源之间的锚定会形成循环。
+    pub const ANCHOR_CYCLE: &'static str = "AnchorCycle";
+
+    /// The requested animation does not exist.
+    ///
+    /// 请求的动画不存在。
+    pub const ANIMATION_NOT_FOUND: &'static str = "AnimationNotFound";
+
+    /// A computed variable would depend on itself.
+    ///
+    /// 计算变量会依赖其自身。
+    pub const COMPUTED_VARIABLE_CYCLE: &'static str = "ComputedVariableCycle";
+
+    /// An image could not be read or decoded.
+    ///
+    /// 无法读取或解码图像。
+    pub const IMAGE_LOAD_FAILED: &'static str = "ImageLoadFailed";
+
+    /// The image has no texture yet.
+    ///
+    /// 图像尚未拥有纹理。
+    pub const TEXTURE_NOT_LOADED: &'static str = "TextureNotLoaded";
+
+    /// The image has no sprite sheet.
+    ///
+    /// 图像没有精灵图。
+    pub const SPRITE_SHEET_NOT_FOUND: &'static str = "SpriteSheetNotFound";
+
+    /// An SVG could not be parsed or rasterized.
+    ///
+    /// 无法解析或光栅化SVG。
+    pub const SVG_PARSE_FAILED: &'static str = "SvgParseFailed";
+
+    /// The image was not loaded from an SVG.
+    ///
+    /// 图像不是从SVG加载的。
+    pub const SVG_NOT_FOUND: &'static str = "SvgNotFound";
+
+    /// The image has no GIF animation.
+    ///
+    /// 图像没有GIF动画。
+    pub const GIF_NOT_FOUND: &'static str = "GifNotFound";
+
+    /// The resource type cannot be copied.
+    ///
+    /// 该资源类型无法复制。
+    pub const RESOURCE_NOT_CLONEABLE: &'static str = "ResourceNotCloneable";
+
+    /// Resources could not be written to a file.
+    ///
+    /// 无法将资源写入文件。
+    pub const RESOURCE_SAVE_FAILED: &'static str = "ResourceSaveFailed";
+
+    /// Resources could not be read or restored.
+    ///
+    /// 无法读取或还原资源。
+    pub const RESOURCE_LOAD_FAILED: &'static str = "ResourceLoadFailed";
+
+    /// The text is not valid JSON.
+    ///
+    /// 文本不是有效的JSON。
+    pub const JSON_PARSE_FAILED: &'static str = "JsonParseFailed";
+
+    /// The requested snapshot does not exist.
+    ///
+    /// 请求的快照不存在。
+    pub const SNAPSHOT_NOT_FOUND: &'static str = "SnapshotNotFound";
+
+    /// There is no page to go back to.
+    ///
+    /// 没有可以返回的页面。
+    pub const PAGE_STACK_EMPTY: &'static str = "PageStackEmpty";
+
+    /// The gamepad backend could not be initialized.
+    ///
+    /// 无法初始化手柄后端。
+    pub const GAMEPAD_INIT_FAILED: &'static str = "GamepadInitFailed";
+
+    /// The size of the monitor or window is unknown.
+    ///
+    /// 显示器或窗口的大小未知。
+    pub const MONITOR_SIZE_UNAVAILABLE: &'static str = "MonitorSizeUnavailable";
+
+    /// Adding groups to each other would form a cycle.
+    ///
+    /// 组之间的相互包含会形成循环。
+    pub const GROUP_CYCLE: &'static str = "GroupCycle";
+
+    /// Laying out resources relative to each other would form a cycle.
+    ///
+    /// 资源之间的相对布局会形成循环。
+    pub const RELATIVE_LAYOUT_CYCLE: &'static str = "RelativeLayoutCycle";
+
+    /// Creates an error with the given id and description.
+    ///
+    /// 使用给定的标识符和描述创建错误。
+    pub fn new(error_id: &str, description: &str) -> Self {
+        RustConstructorError {
+            error_id: error_id.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    /// Creates a `ResourceNotFound` error for the given resource.
+    ///
+    /// 为指定资源创建`ResourceNotFound`错误。
+    pub fn resource_not_found(id: &RustConstructorId) -> Self {
+        RustConstructorError {
+            error_id: Self::RESOURCE_NOT_FOUND.to_string(),
+            description: format!("Resource '{}({})' not found.", id.name, id.discern_type),
+        }
+    }
+
+    /// Checks whether the error has the given id, e.g. `RustConstructorError::RESOURCE_NOT_FOUND`.
+    ///
+    /// 检查错误是否具有给定的标识符，例如`RustConstructorError::RESOURCE_NOT_FOUND`。
+    #[inline]
+    pub fn is_kind(&self, error_id: &str) -> bool {
+        self.error_id == error_id
+    }
+}
+
 impl Display for RustConstructorError {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         Debug::fmt(self, f)
@@ -684,6 +882,7 @@ impl Error for RustConstructorError {}
 ///
 /// UI元素的水平对齐选项。
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HorizontalAlign {
     /// Align to the left.
     ///
@@ -704,6 +903,7 @@ pub enum HorizontalAlign {
 ///
 /// UI元素的垂直对齐选项。
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerticalAlign {
     /// Align to the top.
     ///
@@ -739,6 +939,11 @@ pub enum RenderConfig {
 ///
 /// 资源的显示配置，控制可见性和渲染行为。
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct DisplayInfo {
     /// Enables or disables the resource. If false, the resource is not processed.
     ///
@@ -754,6 +959,12 @@ pub struct DisplayInfo {
     ///
     /// 如果为true，资源忽略渲染层，不占用鼠标焦点。
     pub ignore_render_layer: bool,
+
+    /// If true, the resource is drawn even when render culling finds it offscreen, useful for
+    /// full-window backgrounds.
+    ///
+    /// 如果为true，即使渲染剔除判定资源在屏幕外也会绘制，适用于铺满窗口的背景。
+    pub always_draw: bool,
 }
 
 /// The lookup method for requesting resources to skip the rendering queue.
@@ -802,6 +1013,968 @@ pub enum ListInfoDescribeMethod {
     Simple,
 }
 
+/// Ready-made entrance animations played when a page is entered.
+///
+/// 进入页面时播放的预设入场动画。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EntrancePreset {
+    /// Fades the resource in from fully transparent.
+    ///
+    /// 资源从完全透明淡入。
+    #[default]
+    FadeIn,
+    /// Slides the resource up by 40 pixels while fading it in.
+    ///
+    /// 资源在淡入的同时向上滑动40像素。
+    SlideUp,
+    /// Grows the resource from half size around its center while fading it in.
+    ///
+    /// 资源在淡入的同时从一半大小围绕中心放大。
+    PopScale,
+}
+
+impl EntrancePreset {
+    /// Applies the preset at the given progress (0.0-1.0), returns the rect and the alpha factor.
+    ///
+    /// 按给定进度（0.0-1.0）应用预设，返回矩形和透明度系数。
+    pub fn apply(&self, rect: [[f32; 2]; 2], progress: f32) -> ([[f32; 2]; 2], f32) {
+        // 使用缓出曲线使动画结尾更柔和
+        let eased = 1_f32 - (1_f32 - progress.clamp(0_f32, 1_f32)).powi(3);
+        let [position, size] = rect;
+        match self {
+            EntrancePreset::FadeIn => (rect, eased),
+            EntrancePreset::SlideUp => (
+                [[position[0], position[1] + 40_f32 * (1_f32 - eased)], size],
+                eased,
+            ),
+            EntrancePreset::PopScale => {
+                let scale = 0.5 + 0.5 * eased;
+                let new_size = [size[0] * scale, size[1] * scale];
+                (
+                    [
+                        [
+                            position[0] + (size[0] - new_size[0]) / 2_f32,
+                            position[1] + (size[1] - new_size[1]) / 2_f32,
+                        ],
+                        new_size,
+                    ],
+                    eased,
+                )
+            }
+        }
+    }
+}
+
+/// End of a line resource.
+///
+/// 线条资源的端点。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum LineEndpoint {
+    /// A fixed point on the screen as [x, y].
+    ///
+    /// 屏幕上的固定点，格式为[x, y]。
+    Point([f32; 2]),
+    /// A resource, the line attaches to the edge facing the other end.
+    ///
+    /// 一个资源，线条连接在其朝向另一端的边上。
+    Resource(RustConstructorId),
+}
+
+impl Default for LineEndpoint {
+    fn default() -> Self {
+        LineEndpoint::Point([0_f32, 0_f32])
+    }
+}
+
+/// Path shape of a connector drawn between two resources.
+///
+/// 在两个资源之间绘制的连接线的路径形状。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ConnectorKind {
+    /// A straight line.
+    ///
+    /// 直线。
+    #[default]
+    Straight,
+    /// A right-angled line that turns halfway between the resources.
+    ///
+    /// 在两个资源中间转折的直角线。
+    Elbow,
+    /// A cubic bezier curve leaving and entering the edges perpendicularly.
+    ///
+    /// 垂直离开和进入边缘的三次贝塞尔曲线。
+    Bezier,
+}
+
+/// Appearance of a connector drawn between two resources.
+///
+/// 在两个资源之间绘制的连接线的外观。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ConnectorStyle {
+    /// Path shape of the connector.
+    ///
+    /// 连接线的路径形状。
+    pub kind: ConnectorKind,
+
+    /// Color of the line as [R, G, B, A].
+    ///
+    /// 线条颜色，格式为[R, G, B, A]。
+    pub color: [u8; 4],
+
+    /// Width of the line.
+    ///
+    /// 线条宽度。
+    pub width: f32,
+
+    /// Length of the arrowhead at the target end, None draws no arrowhead.
+    ///
+    /// 目标端箭头的长度，为None时不绘制箭头。
+    pub arrow_size: Option<f32>,
+}
+
+impl Default for ConnectorStyle {
+    fn default() -> Self {
+        ConnectorStyle {
+            kind: ConnectorKind::Straight,
+            color: [255, 255, 255, 255],
+            width: 2_f32,
+            arrow_size: Some(10_f32),
+        }
+    }
+}
+
+impl ConnectorStyle {
+    #[inline]
+    pub fn kind(mut self, kind: ConnectorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    #[inline]
+    pub fn color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    #[inline]
+    pub fn arrow_size(mut self, arrow_size: Option<f32>) -> Self {
+        self.arrow_size = arrow_size;
+        self
+    }
+}
+
+/// Easing curves used by tween animations.
+///
+/// 补间动画使用的缓动曲线。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Easing {
+    /// Constant speed.
+    ///
+    /// 匀速。
+    #[default]
+    Linear,
+    /// Cubic ease in and out, slow at both ends.
+    ///
+    /// 三次缓入缓出，两端较慢。
+    EaseInOut,
+    /// Overshoots the end value slightly before settling.
+    ///
+    /// 略微越过结束值后再回落。
+    EaseOutBack,
+    /// Cubic ease in, starts slowly and accelerates.
+    ///
+    /// 三次缓入，开始较慢然后加速。
+    EaseInCubic,
+}
+
+impl Easing {
+    /// Maps linear progress (0.0-1.0) to eased progress.
+    ///
+    /// 将线性进度（0.0-1.0）映射为缓动后的进度。
+    pub fn apply(&self, progress: f32) -> f32 {
+        let t = progress.clamp(0_f32, 1_f32);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4_f32 * t.powi(3)
+                } else {
+                    1_f32 - (-2_f32 * t + 2_f32).powi(3) / 2_f32
+                }
+            }
+            Easing::EaseOutBack => {
+                const C1: f32 = 1.70158;
+                const C3: f32 = C1 + 1_f32;
+                1_f32 + C3 * (t - 1_f32).powi(3) + C1 * (t - 1_f32).powi(2)
+            }
+            Easing::EaseInCubic => t.powi(3),
+        }
+    }
+}
+
+/// Property of a basic front resource changed by a tween animation.
+///
+/// 补间动画修改的基本前端资源属性。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum AnimatedProperty {
+    /// Tweens `origin_position` from the first value to the second.
+    ///
+    /// 将`origin_position`从第一个值补间到第二个值。
+    Position([f32; 2], [f32; 2]),
+    /// Tweens `origin_size` from the first value to the second.
+    ///
+    /// 将`origin_size`从第一个值补间到第二个值。
+    Size([f32; 2], [f32; 2]),
+    /// Tweens the alpha from the first value to the second.
+    ///
+    /// 将不透明度从第一个值补间到第二个值。
+    Alpha(u8, u8),
+}
+
+/// A tween animation driving one property of a basic front resource.
+///
+/// 驱动基本前端资源某个属性的补间动画。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Animation {
+    /// Resource being animated.
+    ///
+    /// 被播放动画的资源。
+    pub target: RustConstructorId,
+
+    /// Property being animated together with its start and end values.
+    ///
+    /// 被播放动画的属性及其起止值。
+    pub property: AnimatedProperty,
+
+    /// Easing curve of the animation.
+    ///
+    /// 动画的缓动曲线。
+    pub easing: Easing,
+
+    /// Total runtime at which the animation started, in milliseconds.
+    ///
+    /// 动画开始时的总运行时间（毫秒）。
+    pub start_time: u128,
+
+    /// Length of the animation, in milliseconds.
+    ///
+    /// 动画时长（毫秒）。
+    pub duration: u128,
+
+    /// Whether the animation reached its end or was cancelled.
+    ///
+    /// 动画是否已结束或被取消。
+    pub finished: bool,
+}
+
+/// Animation played while switching from one page to another.
+///
+/// 从一个页面切换到另一个页面时播放的动画。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Transition {
+    /// The outgoing page fades out, then the incoming page fades in.
+    ///
+    /// 离开的页面先淡出，随后进入的页面淡入。
+    Fade,
+    /// Both pages move to the left, the incoming page enters from the right edge.
+    ///
+    /// 两个页面向左移动，进入的页面从右边缘进入。
+    SlideLeft,
+    /// Both pages move to the right, the incoming page enters from the left edge.
+    ///
+    /// 两个页面向右移动，进入的页面从左边缘进入。
+    SlideRight,
+    /// The outgoing page fades out while the incoming page fades in at the same time.
+    ///
+    /// 离开的页面淡出的同时进入的页面淡入。
+    Crossfade,
+}
+
+/// A page transition in progress.
+///
+/// 正在进行的页面过渡。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PageTransition {
+    /// Animation being played.
+    ///
+    /// 正在播放的动画。
+    pub transition: Transition,
+
+    /// Page being left.
+    ///
+    /// 正在离开的页面。
+    pub from_page: String,
+
+    /// Page being entered.
+    ///
+    /// 正在进入的页面。
+    pub to_page: String,
+
+    /// Total runtime at which the transition started, in milliseconds.
+    ///
+    /// 过渡开始时的总运行时间（毫秒）。
+    pub start_time: u128,
+
+    /// Length of the transition, in milliseconds.
+    ///
+    /// 过渡时长（毫秒）。
+    pub duration: u128,
+
+    /// Render queue of the outgoing page when the transition started.
+    ///
+    /// 过渡开始时离开页面的渲染队列。
+    pub outgoing: Vec<(RustConstructorId, Option<RustConstructorId>)>,
+}
+
+/// Window corner toast notifications are anchored to.
+///
+/// 提示消息锚定的窗口角落。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ToastCorner {
+    /// Top-left corner, toasts stack downwards and slide in from the left.
+    ///
+    /// 左上角，提示消息向下堆叠并从左侧滑入。
+    TopLeft,
+    /// Top-right corner, toasts stack downwards and slide in from the right.
+    ///
+    /// 右上角，提示消息向下堆叠并从右侧滑入。
+    TopRight,
+    /// Bottom-right corner, toasts stack upwards and slide in from the right.
+    ///
+    /// 右下角，提示消息向上堆叠并从右侧滑入。
+    #[default]
+    BottomRight,
+    /// Bottom-left corner, toasts stack upwards and slide in from the left.
+    ///
+    /// 左下角，提示消息向上堆叠并从左侧滑入。
+    BottomLeft,
+}
+
+/// Appearance of toast notifications.
+///
+/// 提示消息的外观。
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ToastStyle {
+    /// Background color as [R, G, B, A].
+    ///
+    /// 背景颜色，格式为[R, G, B, A]。
+    pub background_color: [u8; 4],
+
+    /// Text color as [R, G, B, A].
+    ///
+    /// 文本颜色，格式为[R, G, B, A]。
+    pub text_color: [u8; 4],
+
+    /// Font size of the message.
+    ///
+    /// 消息的字体大小。
+    pub font_size: f32,
+
+    /// Corner rounding of the background.
+    ///
+    /// 背景的圆角。
+    pub rounding: f32,
+
+    /// Space between the message and the edge of the background.
+    ///
+    /// 消息与背景边缘之间的间距。
+    pub padding: f32,
+
+    /// Space between toasts and from the corner of the window.
+    ///
+    /// 提示消息之间以及与窗口角落之间的间距。
+    pub margin: f32,
+
+    /// Window corner the toast is shown at, toasts at the same corner stack together.
+    ///
+    /// 提示消息显示的窗口角落，同一角落的提示消息会堆叠在一起。
+    pub corner: ToastCorner,
+}
+
+impl Default for ToastStyle {
+    fn default() -> Self {
+        ToastStyle {
+            background_color: [40, 40, 40, 230],
+            text_color: [255, 255, 255, 255],
+            font_size: 16_f32,
+            rounding: 6_f32,
+            padding: 10_f32,
+            margin: 12_f32,
+            corner: ToastCorner::BottomRight,
+        }
+    }
+}
+
+impl ToastStyle {
+    #[inline]
+    pub fn background_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.background_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn text_color(mut self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.text_color = [r, g, b, a];
+        self
+    }
+
+    #[inline]
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[inline]
+    pub fn rounding(mut self, rounding: f32) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    #[inline]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    #[inline]
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    #[inline]
+    pub fn corner(mut self, corner: ToastCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+}
+
+/// A transient notification shown at the bottom-right corner of the window.
+///
+/// 显示在窗口右下角的临时通知。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Toast {
+    /// Message shown in the toast.
+    ///
+    /// 提示消息中显示的内容。
+    pub message: String,
+
+    /// Real time at which the toast was created, in milliseconds.
+    ///
+    /// 提示消息创建时的真实时间（毫秒）。
+    pub start_time: u128,
+
+    /// How long the toast stays, in milliseconds.
+    ///
+    /// 提示消息停留的时长（毫秒）。
+    pub duration: u128,
+
+    /// Appearance of the toast.
+    ///
+    /// 提示消息的外观。
+    pub style: ToastStyle,
+
+    /// Action buttons shown below the message as (label, action id).
+    ///
+    /// 显示在消息下方的操作按钮，为(标签, 操作id)。
+    pub actions: Vec<(String, String)>,
+}
+
+/// A tooltip shown while the pointer rests on a resource.
+///
+/// 指针停留在资源上时显示的工具提示。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Tooltip {
+    /// Resource the tooltip is attached to.
+    ///
+    /// 工具提示所附加的资源。
+    pub target: RustConstructorId,
+
+    /// Name of the text resource displaying the tooltip.
+    ///
+    /// 显示工具提示的文本资源名称。
+    pub text_name: String,
+
+    /// How long the pointer has to rest on the target before the tooltip appears, in milliseconds.
+    ///
+    /// 指针需要在目标上停留多久工具提示才会出现（毫秒）。
+    pub delay: u128,
+
+    /// Whether the target was hovered in the previous frame.
+    ///
+    /// 上一帧目标是否被悬停。
+    pub last_frame_hovered: bool,
+}
+
+/// Side or corner of a parent resource that a child resource is anchored to.
+///
+/// 子资源锚定到的父资源的边或角。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AnchorEdge {
+    /// Above the parent, centered horizontally.
+    ///
+    /// 父资源上方，水平居中。
+    Top,
+    /// Below the parent, centered horizontally.
+    ///
+    /// 父资源下方，水平居中。
+    #[default]
+    Bottom,
+    /// Left of the parent, centered vertically.
+    ///
+    /// 父资源左侧，垂直居中。
+    Left,
+    /// Right of the parent, centered vertically.
+    ///
+    /// 父资源右侧，垂直居中。
+    Right,
+    /// Outside the top-left corner of the parent.
+    ///
+    /// 父资源左上角外侧。
+    TopLeft,
+    /// Outside the top-right corner of the parent.
+    ///
+    /// 父资源右上角外侧。
+    TopRight,
+    /// Outside the bottom-left corner of the parent.
+    ///
+    /// 父资源左下角外侧。
+    BottomLeft,
+    /// Outside the bottom-right corner of the parent.
+    ///
+    /// 父资源右下角外侧。
+    BottomRight,
+    /// Centered on the parent.
+    ///
+    /// 父资源中心。
+    Center,
+}
+
+impl AnchorEdge {
+    /// Returns the position of a child with `size` placed next to the parent rect.
+    ///
+    /// 返回尺寸为`size`的子资源放在父资源矩形旁边时的位置。
+    ///
+    /// `gap` is the distance kept away from the parent, for `Center` it is a plain offset.
+    ///
+    /// `gap`是与父资源保持的距离，对于`Center`则是普通偏移。
+    pub fn place(&self, parent: [[f32; 2]; 2], size: [f32; 2], gap: [f32; 2]) -> [f32; 2] {
+        let [[x, y], [width, height]] = parent;
+        let before = [x - size[0] - gap[0], y - size[1] - gap[1]];
+        let after = [x + width + gap[0], y + height + gap[1]];
+        let center = [
+            x + (width - size[0]) / 2_f32,
+            y + (height - size[1]) / 2_f32,
+        ];
+        match self {
+            AnchorEdge::Top => [center[0] + gap[0], before[1]],
+            AnchorEdge::Bottom => [center[0] + gap[0], after[1]],
+            AnchorEdge::Left => [before[0], center[1] + gap[1]],
+            AnchorEdge::Right => [after[0], center[1] + gap[1]],
+            AnchorEdge::TopLeft => before,
+            AnchorEdge::TopRight => [after[0], before[1]],
+            AnchorEdge::BottomLeft => [before[0], after[1]],
+            AnchorEdge::BottomRight => after,
+            AnchorEdge::Center => [center[0] + gap[0], center[1] + gap[1]],
+        }
+    }
+}
+
+/// Keeps a resource positioned relative to another resource.
+///
+/// 使资源保持相对于另一个资源的位置。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Anchor {
+    /// Resource being positioned.
+    ///
+    /// 被定位的资源。
+    pub child: RustConstructorId,
+
+    /// Resource the child follows.
+    ///
+    /// 子资源跟随的资源。
+    pub parent: RustConstructorId,
+
+    /// Side or corner of the parent the child is placed at.
+    ///
+    /// 子资源放置在父资源的哪条边或哪个角。
+    pub edge: AnchorEdge,
+
+    /// Distance between the child and the parent.
+    ///
+    /// 子资源与父资源之间的距离。
+    pub gap: [f32; 2],
+}
+
+/// Alternative layout of a resource used when the window is at least `min_width` wide.
+///
+/// 窗口宽度不小于`min_width`时资源使用的替代布局。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Breakpoint {
+    /// Resource the breakpoint belongs to.
+    ///
+    /// 断点所属的资源。
+    pub target: RustConstructorId,
+
+    /// Minimum window width for the breakpoint to apply.
+    ///
+    /// 断点生效所需的最小窗口宽度。
+    pub min_width: f32,
+
+    /// Position and size config used while the breakpoint applies.
+    ///
+    /// 断点生效时使用的位置和尺寸配置。
+    pub position_size_config: PositionSizeConfig,
+}
+
+/// Navigation actions a gamepad button can be mapped to.
+///
+/// 手柄按钮可以映射到的导航操作。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GamepadAction {
+    /// Move focus to the nearest resource above.
+    ///
+    /// 将焦点移动到上方最近的资源。
+    Up,
+    /// Move focus to the nearest resource below.
+    ///
+    /// 将焦点移动到下方最近的资源。
+    Down,
+    /// Move focus to the nearest resource on the left.
+    ///
+    /// 将焦点移动到左侧最近的资源。
+    Left,
+    /// Move focus to the nearest resource on the right.
+    ///
+    /// 将焦点移动到右侧最近的资源。
+    Right,
+    /// Activate the focused resource, like Enter on a keyboard.
+    ///
+    /// 激活获得焦点的资源，与键盘上的回车键相同。
+    Activate,
+    /// Go back to the previous page of the page stack, or clear focus when it is empty.
+    ///
+    /// 返回页面栈中的上一个页面，页面栈为空时清除焦点。
+    Back,
+}
+
+/// A named keyboard shortcut checked once per frame.
+///
+/// 每帧检查一次的具名键盘快捷键。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    /// Name used to consume the shortcut.
+    ///
+    /// 用于消费快捷键的名称。
+    pub name: String,
+
+    /// Key that triggers the shortcut.
+    ///
+    /// 触发快捷键的按键。
+    pub key: Key,
+
+    /// Modifiers that have to be held exactly.
+    ///
+    /// 需要精确按住的修饰键。
+    pub modifiers: Modifiers,
+
+    /// Page the shortcut is limited to, None for every page.
+    ///
+    /// 快捷键所限定的页面，为None时对所有页面生效。
+    pub page: Option<String>,
+
+    /// Whether the shortcut was pressed this frame and not consumed yet.
+    ///
+    /// 快捷键是否在本帧被按下且尚未被消费。
+    pub triggered: bool,
+}
+
+/// Named colors that resources can reference instead of literal colors.
+///
+/// 资源可以引用以代替字面颜色的具名颜色。
+///
+/// Common keys are `background`, `surface`, `text`, `accent` and `border`, any other key can be
+/// added with `color`.
+///
+/// 常用的键为`background`、`surface`、`text`、`accent`和`border`，其他键可以通过`color`添加。
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Theme {
+    /// Colors of the theme as (key, [R, G, B]).
+    ///
+    /// 主题的颜色，为(键, [R, G, B])。
+    pub colors: Vec<(String, [u8; 3])>,
+}
+
+impl Theme {
+    /// Returns a dark theme with the common keys.
+    ///
+    /// 返回包含常用键的深色主题。
+    pub fn dark() -> Self {
+        Theme::default()
+            .color("background", 30, 30, 30)
+            .color("surface", 45, 45, 45)
+            .color("text", 235, 235, 235)
+            .color("accent", 90, 150, 250)
+            .color("border", 80, 80, 80)
+    }
+
+    /// Returns a light theme with the common keys.
+    ///
+    /// 返回包含常用键的浅色主题。
+    pub fn light() -> Self {
+        Theme::default()
+            .color("background", 245, 245, 245)
+            .color("surface", 255, 255, 255)
+            .color("text", 25, 25, 25)
+            .color("accent", 40, 110, 220)
+            .color("border", 200, 200, 200)
+    }
+
+    /// Returns the color of a key.
+    ///
+    /// 返回键对应的颜色。
+    pub fn get(&self, key: &str) -> Option<[u8; 3]> {
+        self.colors.iter().find(|x| x.0 == key).map(|x| x.1)
+    }
+
+    #[inline]
+    pub fn color(mut self, key: &str, r: u8, g: u8, b: u8) -> Self {
+        self.colors.retain(|x| x.0 != key);
+        self.colors.push((key.to_string(), [r, g, b]));
+        self
+    }
+}
+
+/// Color property of a basic front resource that can follow a theme key.
+///
+/// 基本前端资源中可以跟随主题键的颜色属性。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ThemeColorTarget {
+    /// Text color, custom rectangle color or polygon fill color.
+    ///
+    /// 文本颜色、自定义矩形颜色或多边形填充颜色。
+    Color,
+    /// Background color of a text or an image.
+    ///
+    /// 文本或图片的背景颜色。
+    Background,
+    /// Border color of a custom rectangle or a polygon.
+    ///
+    /// 自定义矩形或多边形的边框颜色。
+    Border,
+    /// Overlay color of an image or a custom rectangle.
+    ///
+    /// 图片或自定义矩形的叠加颜色。
+    Overlay,
+}
+
+/// Hover and pressed overlays of a clickable resource.
+///
+/// 可点击资源的悬停和按下叠加层。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClickableStyle {
+    /// Overlay color painted while hovered as [R, G, B, A].
+    ///
+    /// 悬停时绘制的叠加层颜色，为[R, G, B, A]。
+    pub hover_overlay: [u8; 4],
+
+    /// Overlay color painted while pressed as [R, G, B, A].
+    ///
+    /// 按下时绘制的叠加层颜色，为[R, G, B, A]。
+    pub pressed_overlay: [u8; 4],
+
+    /// Cursor shown while the pointer is on the resource, None keeps the current cursor.
+    ///
+    /// 指针位于资源上时显示的光标，为None时保持当前光标。
+    pub hover_cursor: Option<CursorIcon>,
+}
+
+impl Default for ClickableStyle {
+    fn default() -> Self {
+        ClickableStyle {
+            hover_overlay: [255, 255, 255, 30],
+            pressed_overlay: [0, 0, 0, 40],
+            hover_cursor: None,
+        }
+    }
+}
+
+impl ClickableStyle {
+    #[inline]
+    pub fn hover_overlay(mut self, hover_overlay: [u8; 4]) -> Self {
+        self.hover_overlay = hover_overlay;
+        self
+    }
+
+    #[inline]
+    pub fn pressed_overlay(mut self, pressed_overlay: [u8; 4]) -> Self {
+        self.pressed_overlay = pressed_overlay;
+        self
+    }
+
+    #[inline]
+    pub fn hover_cursor(mut self, hover_cursor: Option<CursorIcon>) -> Self {
+        self.hover_cursor = hover_cursor;
+        self
+    }
+}
+
+/// A basic front resource that behaves like a lightweight button.
+///
+/// 表现为轻量按钮的基本前端资源。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Clickable {
+    /// Resource made clickable.
+    ///
+    /// 被设为可点击的资源。
+    pub target: RustConstructorId,
+
+    /// Overlays painted over the resource.
+    ///
+    /// 绘制在资源上方的叠加层。
+    pub style: ClickableStyle,
+
+    /// Flag used to consume the click.
+    ///
+    /// 用于消费点击的标记。
+    pub on_click_flag: String,
+
+    /// Whether the pointer is on the resource.
+    ///
+    /// 指针是否位于资源上。
+    pub hovered: bool,
+
+    /// Whether a press started on the resource and is still held.
+    ///
+    /// 是否有在资源上开始且仍未松开的按压。
+    pub pressed: bool,
+
+    /// Whether the resource was clicked this frame and the click was not consumed yet.
+    ///
+    /// 资源是否在本帧被点击且点击尚未被消费。
+    pub clicked: bool,
+}
+
+/// Drag currently held on a draggable resource.
+///
+/// 当前按住在可拖动资源上的拖动。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct DragState {
+    /// Resource being dragged.
+    ///
+    /// 被拖动的资源。
+    pub source: RustConstructorId,
+
+    /// Pointer position where the press started as [x, y].
+    ///
+    /// 按压开始时的指针位置，坐标为[x, y]。
+    pub press_position: [f32; 2],
+
+    /// Offset of the dragged resource from its origin as [x, y].
+    ///
+    /// 被拖动资源相对原位置的偏移，为[x, y]。
+    pub offset: [f32; 2],
+
+    /// Whether the pointer moved far enough for the press to count as a drag.
+    ///
+    /// 指针是否移动了足够距离使按压被视为拖动。
+    pub dragging: bool,
+}
+
+/// Result of releasing a dragged resource.
+///
+/// 松开被拖动资源的结果。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropEvent {
+    /// Resource that was dragged.
+    ///
+    /// 被拖动的资源。
+    pub source: RustConstructorId,
+
+    /// Drop target under the pointer, None if the drop landed on empty space and the source
+    /// returned to its origin.
+    ///
+    /// 指针下方的放置目标，放置在空白处且源资源已回到原位置时为None。
+    pub target: Option<RustConstructorId>,
+}
+
+/// Secondary window opened by the application.
+///
+/// 应用程序打开的次级窗口。
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct SecondaryWindow {
+    /// Id of the window, matched by the `viewport` tag of resources.
+    ///
+    /// 窗口ID，与资源的`viewport`标签匹配。
+    pub id: String,
+
+    /// Title of the window.
+    ///
+    /// 窗口标题。
+    pub title: String,
+
+    /// Inner size of the window as [width, height].
+    ///
+    /// 窗口内部区域的大小，为[宽度, 高度]。
+    pub size: [f32; 2],
+
+    /// Whether the user closed the window and the close was not consumed yet.
+    ///
+    /// 用户是否关闭了窗口且关闭尚未被消费。
+    pub closed: bool,
+}
+
+/// Snapshot of the pointer and keyboard state for a single frame, used for debugging input.
+///
+/// 单帧内指针和键盘状态的快照，用于调试输入。
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+pub struct InputSummary {
+    /// Current pointer position as [x, y], None if the pointer is outside the window.
+    ///
+    /// 当前指针位置，坐标为[x, y]，指针在窗口外时为None。
+    pub pointer_position: Option<[f32; 2]>,
+
+    /// Pointer buttons currently held down: [primary, secondary, middle].
+    ///
+    /// 当前按下的指针按键：[主键, 次键, 中键]。
+    pub pointer_buttons_down: [bool; 3],
+
+    /// Modifier keys currently held down: [ctrl, shift, alt, command].
+    ///
+    /// 当前按下的修饰键：[ctrl, shift, alt, command]。
+    pub modifiers: [bool; 4],
+
+    /// Names of the keys currently held down.
+    ///
+    /// 当前按下的按键名称。
+    pub keys_down: Vec<String>,
+
+    /// Scroll delta of this frame as [x, y].
+    ///
+    /// 本帧的滚动增量，为[x, y]。
+    pub scroll_delta: [f32; 2],
+}
+
+impl Display for InputSummary {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "————————————————————————————————————\nInput Summary:\n\nPointer Position: {:?}\nPointer Buttons Down [primary, secondary, middle]: {:?}\nModifiers [ctrl, shift, alt, command]: {:?}\nKeys Down: {:?}\nScroll Delta: {:?}\n",
+            self.pointer_position,
+            self.pointer_buttons_down,
+            self.modifiers,
+            self.keys_down,
+            self.scroll_delta
+        )
+    }
+}
+
 /// Obtain the type name of the target resource.
 ///
 /// 获取目标资源的类型名称。
@@ -841,7 +2014,7 @@ pub fn downcast_resource<T: RustConstructorResource + 'static>(
         Ok(resource)
     } else {
         Err(RustConstructorError {
-            error_id: "ResourceDowncastTypeMismatch".to_string(),
+            error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
             description: format!(
                 "Failed to downcast resource to type '{}'.",
                 type_name::<T>()
@@ -865,7 +2038,7 @@ pub fn downcast_resource_mut<T: RustConstructorResource + 'static>(
         Ok(resource)
     } else {
         Err(RustConstructorError {
-            error_id: "ResourceDowncastTypeMismatch".to_string(),
+            error_id: RustConstructorError::RESOURCE_DOWNCAST_TYPE_MISMATCH.to_string(),
             description: format!(
                 "Failed to downcast resource to type '{}'.",
                 type_name::<T>()
@@ -883,12 +2056,35 @@ pub fn downcast_resource_mut<T: RustConstructorResource + 'static>(
 ///
 /// 此方法处理复杂的定位逻辑，包括基于网格的布局、对齐方式和UI资源的偏移计算。
 pub fn position_size_processor(position_size_config: PositionSizeConfig, ui: &Ui) -> [[f32; 2]; 2] {
+    let content_rect = ui.ctx().content_rect();
+    position_size_processor_in(
+        position_size_config,
+        [
+            [0_f32, 0_f32],
+            [content_rect.width(), content_rect.height()],
+        ],
+    )
+}
+
+/// Processes position and size calculations for resources inside a container rect given as
+/// [position, size].
+///
+/// 在以[位置, 尺寸]给出的容器矩形内处理资源的位置和尺寸计算。
+///
+/// Grids are fractions of the container size and positions are relative to the container
+/// position, `position_size_processor` uses the window as the container.
+///
+/// 网格为容器尺寸的比例，位置相对容器位置计算，`position_size_processor`以窗口作为容器。
+pub fn position_size_processor_in(
+    position_size_config: PositionSizeConfig,
+    [container_position, container_size]: [[f32; 2]; 2],
+) -> [[f32; 2]; 2] {
     let mut position = [0_f32, 0_f32];
     let mut size = [0_f32, 0_f32];
     size[0] = match position_size_config.x_size_grid[1] {
         0_f32 => position_size_config.origin_size[0],
         _ => {
-            (ui.ctx().content_rect().width() / position_size_config.x_size_grid[1]
+            (container_size[0] / position_size_config.x_size_grid[1]
                 * position_size_config.x_size_grid[0])
                 + position_size_config.origin_size[0]
         }
@@ -896,7 +2092,7 @@ pub fn position_size_processor(position_size_config: PositionSizeConfig, ui: &Ui
     size[1] = match position_size_config.y_size_grid[1] {
         0_f32 => position_size_config.origin_size[1],
         _ => {
-            (ui.ctx().content_rect().height() / position_size_config.y_size_grid[1]
+            (container_size[1] / position_size_config.y_size_grid[1]
                 * position_size_config.y_size_grid[0])
                 + position_size_config.origin_size[1]
         }
@@ -904,7 +2100,7 @@ pub fn position_size_processor(position_size_config: PositionSizeConfig, ui: &Ui
     position[0] = match position_size_config.x_location_grid[1] {
         0_f32 => position_size_config.origin_position[0],
         _ => {
-            (ui.ctx().content_rect().width() / position_size_config.x_location_grid[1]
+            (container_size[0] / position_size_config.x_location_grid[1]
                 * position_size_config.x_location_grid[0])
                 + position_size_config.origin_position[0]
         }
@@ -912,7 +2108,7 @@ pub fn position_size_processor(position_size_config: PositionSizeConfig, ui: &Ui
     position[1] = match position_size_config.y_location_grid[1] {
         0_f32 => position_size_config.origin_position[1],
         _ => {
-            (ui.ctx().content_rect().height() / position_size_config.y_location_grid[1]
+            (container_size[1] / position_size_config.y_location_grid[1]
                 * position_size_config.y_location_grid[0])
                 + position_size_config.origin_position[1]
         }
@@ -927,8 +2123,8 @@ pub fn position_size_processor(position_size_config: PositionSizeConfig, ui: &Ui
         VerticalAlign::Center => position[1] -= size[1] / 2.0,
         VerticalAlign::Bottom => position[1] -= size[1],
     };
-    position[0] += position_size_config.offset[0];
-    position[1] += position_size_config.offset[1];
+    position[0] += position_size_config.offset[0] + container_position[0];
+    position[1] += position_size_config.offset[1] + container_position[1];
     [position, size]
 }
 
@@ -953,3 +2149,47 @@ pub fn build_id(name: impl Into<String>, discern_type: impl Into<String>) -> Rus
         discern_type: discern_type.into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_is_kind_compares_the_error_id() {
+        let error = RustConstructorError::resource_not_found(&build_id("Title", "Text"));
+        assert!(error.is_kind(RustConstructorError::RESOURCE_NOT_FOUND));
+        assert!(!error.is_kind(RustConstructorError::RESOURCE_UNTITLED));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn position_size_config_survives_a_json_round_trip() {
+        let config = PositionSizeConfig::default()
+            .origin_position(12_f32, 34_f32)
+            .origin_size(56_f32, 78_f32)
+            .display_method(HorizontalAlign::Center, VerticalAlign::Bottom);
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PositionSizeConfig>(&json).unwrap(),
+            config
+        );
+        // 缺失的字段使用默认值
+        assert_eq!(
+            serde_json::from_str::<PositionSizeConfig>("{}").unwrap(),
+            PositionSizeConfig::default()
+        );
+    }
+
+    #[test]
+    fn position_size_processor_in_resolves_grids_against_the_container() {
+        let config = PositionSizeConfig::default()
+            .origin_size(10_f32, 0_f32)
+            .x_location_grid(1_f32, 2_f32)
+            .y_size_grid(1_f32, 4_f32)
+            .display_method(HorizontalAlign::Center, VerticalAlign::Top);
+        assert_eq!(
+            position_size_processor_in(config, [[100_f32, 50_f32], [200_f32, 400_f32]]),
+            [[195_f32, 50_f32], [10_f32, 100_f32]]
+        );
+    }
+}